@@ -32,6 +32,7 @@ fn main() {
     // Parse each rust file with syn and run the linting suite on it in parallel
     rust_files.par_iter().for_each_with(tx.clone(), |tx, file| {
         let is_test = file.display().to_string().contains("test");
+        let is_subtensor_pallet = file.starts_with(workspace_root.join("pallets/subtensor/src"));
         let Ok(content) = fs::read_to_string(file) else {
             return;
         };
@@ -67,6 +68,8 @@ fn main() {
 
         if is_test {
             track_lint(ForbidSaturatingMath::lint(&parsed_file));
+        } else if is_subtensor_pallet {
+            track_lint(ForbidRawUidArithmetic::lint(&parsed_file));
         }
     });
 
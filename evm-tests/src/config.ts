@@ -35,4 +35,17 @@ export const IBalanceTransferABI = [
         stateMutability: "payable",
         type: "function",
     },
+    {
+        inputs: [
+            {
+                internalType: "bytes32",
+                name: "data",
+                type: "bytes32",
+            },
+        ],
+        name: "transferKeepAlive",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function",
+    },
 ];
\ No newline at end of file
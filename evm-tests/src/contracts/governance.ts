@@ -0,0 +1,27 @@
+export const IGOVERNANCE_ADDRESS = "0x0000000000000000000000000000000000000807";
+
+export const IGovernanceABI = [
+    {
+        inputs: [
+            {
+                internalType: "bytes32",
+                name: "proposalHash",
+                type: "bytes32",
+            },
+            {
+                internalType: "uint32",
+                name: "index",
+                type: "uint32",
+            },
+            {
+                internalType: "bool",
+                name: "approve",
+                type: "bool",
+            },
+        ],
+        name: "voteProposal",
+        outputs: [],
+        stateMutability: "nonpayable",
+        type: "function",
+    },
+];
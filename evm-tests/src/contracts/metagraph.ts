@@ -57,6 +57,62 @@ export const IMetagraphABI = [
         stateMutability: "view",
         type: "function",
     },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32",
+            },
+        ],
+        name: "getAxon",
+        outputs: [
+            {
+                components: [
+                    {
+                        internalType: "uint64",
+                        name: "block",
+                        type: "uint64",
+                    },
+                    {
+                        internalType: "uint32",
+                        name: "version",
+                        type: "uint32",
+                    },
+                    {
+                        internalType: "uint128",
+                        name: "ip",
+                        type: "uint128",
+                    },
+                    {
+                        internalType: "uint16",
+                        name: "port",
+                        type: "uint16",
+                    },
+                    {
+                        internalType: "uint8",
+                        name: "ip_type",
+                        type: "uint8",
+                    },
+                    {
+                        internalType: "uint8",
+                        name: "protocol",
+                        type: "uint8",
+                    },
+                ],
+                internalType: "struct AxonInfo",
+                name: "",
+                type: "tuple",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
     {
         inputs: [
             {
@@ -249,6 +305,207 @@ export const IMetagraphABI = [
         stateMutability: "view",
         type: "function",
     },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                internalType: "uint16",
+                name: "uid",
+                type: "uint16",
+            },
+        ],
+        name: "getNeuron",
+        outputs: [
+            {
+                components: [
+                    {
+                        internalType: "bytes32",
+                        name: "hotkey",
+                        type: "bytes32",
+                    },
+                    {
+                        internalType: "bytes32",
+                        name: "coldkey",
+                        type: "bytes32",
+                    },
+                    {
+                        internalType: "uint64",
+                        name: "stake",
+                        type: "uint64",
+                    },
+                    {
+                        internalType: "uint16",
+                        name: "rank",
+                        type: "uint16",
+                    },
+                    {
+                        internalType: "uint16",
+                        name: "trust",
+                        type: "uint16",
+                    },
+                    {
+                        internalType: "uint16",
+                        name: "consensus",
+                        type: "uint16",
+                    },
+                    {
+                        internalType: "uint16",
+                        name: "incentive",
+                        type: "uint16",
+                    },
+                    {
+                        internalType: "uint16",
+                        name: "dividends",
+                        type: "uint16",
+                    },
+                    {
+                        internalType: "uint64",
+                        name: "emission",
+                        type: "uint64",
+                    },
+                    {
+                        internalType: "bool",
+                        name: "active",
+                        type: "bool",
+                    },
+                    {
+                        internalType: "bool",
+                        name: "validator_permit",
+                        type: "bool",
+                    },
+                    {
+                        internalType: "uint64",
+                        name: "last_update",
+                        type: "uint64",
+                    },
+                ],
+                internalType: "struct NeuronInfo",
+                name: "",
+                type: "tuple",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                internalType: "uint16",
+                name: "uid",
+                type: "uint16",
+            },
+        ],
+        name: "getNeuronStake",
+        outputs: [
+            {
+                internalType: "uint256",
+                name: "",
+                type: "uint256",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                internalType: "uint16",
+                name: "start",
+                type: "uint16",
+            },
+            {
+                internalType: "uint16",
+                name: "count",
+                type: "uint16",
+            },
+        ],
+        name: "getNeuronsPaged",
+        outputs: [
+            {
+                components: [
+                    {
+                        internalType: "bytes32",
+                        name: "hotkey",
+                        type: "bytes32",
+                    },
+                    {
+                        internalType: "bytes32",
+                        name: "coldkey",
+                        type: "bytes32",
+                    },
+                    {
+                        internalType: "uint64",
+                        name: "stake",
+                        type: "uint64",
+                    },
+                    {
+                        internalType: "uint16",
+                        name: "rank",
+                        type: "uint16",
+                    },
+                    {
+                        internalType: "uint16",
+                        name: "trust",
+                        type: "uint16",
+                    },
+                    {
+                        internalType: "uint16",
+                        name: "consensus",
+                        type: "uint16",
+                    },
+                    {
+                        internalType: "uint16",
+                        name: "incentive",
+                        type: "uint16",
+                    },
+                    {
+                        internalType: "uint16",
+                        name: "dividends",
+                        type: "uint16",
+                    },
+                    {
+                        internalType: "uint64",
+                        name: "emission",
+                        type: "uint64",
+                    },
+                    {
+                        internalType: "bool",
+                        name: "active",
+                        type: "bool",
+                    },
+                    {
+                        internalType: "bool",
+                        name: "validator_permit",
+                        type: "bool",
+                    },
+                    {
+                        internalType: "uint64",
+                        name: "last_update",
+                        type: "uint64",
+                    },
+                ],
+                internalType: "struct NeuronInfo[]",
+                name: "",
+                type: "tuple[]",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
     {
         inputs: [
             {
@@ -15,7 +15,13 @@ export const INeuronABI = [
             },
         ],
         name: "commitWeights",
-        outputs: [],
+        outputs: [
+            {
+                internalType: "uint64",
+                name: "firstRevealBlock",
+                type: "uint64",
+            },
+        ],
         stateMutability: "payable",
         type: "function",
     },
@@ -232,4 +238,65 @@ export const INeuronABI = [
         stateMutability: "payable",
         type: "function",
     },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+        ],
+        name: "isCommitRevealEnabled",
+        outputs: [
+            {
+                internalType: "bool",
+                name: "",
+                type: "bool",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32",
+            },
+        ],
+        name: "getNeuronCertificate",
+        outputs: [
+            {
+                internalType: "bytes",
+                name: "",
+                type: "bytes",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                internalType: "bytes",
+                name: "certificate",
+                type: "bytes",
+            },
+        ],
+        name: "setNeuronCertificate",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function",
+    },
 ];
\ No newline at end of file
@@ -834,7 +834,13 @@ export const ISubnetABI = [
             },
         ],
         name: "registerNetwork",
-        outputs: [],
+        outputs: [
+            {
+                internalType: "uint16",
+                name: "",
+                type: "uint16",
+            },
+        ],
         stateMutability: "payable",
         type: "function",
     },
@@ -886,4 +892,79 @@ export const ISubnetABI = [
         stateMutability: "payable",
         type: "function"
     },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+        ],
+        name: "dissolveNetwork",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+        ],
+        name: "getOwnerCut",
+        outputs: [
+            {
+                internalType: "uint16",
+                name: "",
+                type: "uint16",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+        ],
+        name: "getRecentVolume",
+        outputs: [
+            {
+                internalType: "uint256",
+                name: "",
+                type: "uint256",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+        ],
+        name: "getSubnetReserves",
+        outputs: [
+            {
+                internalType: "uint256",
+                name: "taoReserve",
+                type: "uint256",
+            },
+            {
+                internalType: "uint256",
+                name: "alphaReserve",
+                type: "uint256",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
 ];
\ No newline at end of file
@@ -292,6 +292,44 @@ export async function setMinDelegateTake(api: TypedApi<typeof devnet>, minDelega
     assert.equal(minDelegateTake, await api.query.SubtensorModule.MinDelegateTake.getValue())
 }
 
+export async function setMinStakeAmount(api: TypedApi<typeof devnet>, minStakeAmount: bigint) {
+    const value = await api.query.SubtensorModule.MinStakeAmount.getValue()
+    if (value === minStakeAmount) {
+        return;
+    }
+
+    const alice = getAliceSigner()
+
+    const internalCall = api.tx.AdminUtils.sudo_set_min_stake_amount({
+        min_stake_amount: minStakeAmount
+    })
+    const tx = api.tx.Sudo.sudo({ call: internalCall.decodedCall })
+
+    await waitForTransactionCompletion(api, tx, alice)
+        .then(() => { })
+        .catch((error) => { console.log(`transaction error ${error}`) });
+    assert.equal(minStakeAmount, await api.query.SubtensorModule.MinStakeAmount.getValue())
+}
+
+export async function setColdkeySwapScheduleDuration(api: TypedApi<typeof devnet>, duration: number) {
+    const value = await api.query.SubtensorModule.ColdkeySwapScheduleDuration.getValue()
+    if (value === duration) {
+        return;
+    }
+
+    const alice = getAliceSigner()
+
+    const internalCall = api.tx.AdminUtils.sudo_set_coldkey_swap_schedule_duration({
+        duration: duration
+    })
+    const tx = api.tx.Sudo.sudo({ call: internalCall.decodedCall })
+
+    await waitForTransactionCompletion(api, tx, alice)
+        .then(() => { })
+        .catch((error) => { console.log(`transaction error ${error}`) });
+    assert.equal(duration, await api.query.SubtensorModule.ColdkeySwapScheduleDuration.getValue())
+}
+
 export async function becomeDelegate(api: TypedApi<typeof devnet>, ss58Address: string, keypair: KeyPair) {
     const singer = getSignerFromKeypair(keypair)
 
@@ -342,4 +380,22 @@ export async function rootRegister(api: TypedApi<typeof devnet>, ss58Address: st
         .then(() => { })
         .catch((error) => { console.log(`transaction error ${error}`) });
 
-}
\ No newline at end of file
+}
+export async function setSafeMode(api: TypedApi<typeof devnet>, entered: boolean) {
+    const value = await api.query.SafeMode.EnteredUntil.getValue()
+    if ((value !== undefined) === entered) {
+        return;
+    }
+
+    const alice = getAliceSigner()
+
+    const internalCall = entered
+        ? api.tx.SafeMode.force_enter()
+        : api.tx.SafeMode.force_exit()
+    const tx = api.tx.Sudo.sudo({ call: internalCall.decodedCall })
+
+    await waitForTransactionCompletion(api, tx, alice)
+        .then(() => { })
+        .catch((error) => { console.log(`transaction error ${error}`) });
+    assert.equal(entered, (await api.query.SafeMode.EnteredUntil.getValue()) !== undefined)
+}
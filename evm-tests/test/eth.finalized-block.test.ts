@@ -0,0 +1,37 @@
+import * as assert from "assert";
+
+import { getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate"
+import { getPublicClient } from "../src/utils";
+import { convertPublicKeyToSs58 } from "../src/address-utils"
+import { ETH_LOCAL_URL } from "../src/config";
+import { devnet } from "@polkadot-api/descriptors"
+import { PublicClient } from "viem";
+import { TypedApi } from "polkadot-api";
+import { forceSetBalanceToSs58Address } from "../src/subtensor";
+
+// Only meaningful against a node started with `--sealing instant-finalized`, so it is skipped
+// unless SEALING=instant-finalized is set.
+describe("Test the EVM finalized block with instant-finalized sealing", () => {
+  const keyPair = getRandomSubstrateKeypair();
+  let ethClient: PublicClient;
+  let api: TypedApi<typeof devnet>;
+
+  before(async function () {
+    if (process.env.SEALING !== "instant-finalized") {
+      this.skip();
+    }
+    ethClient = await getPublicClient(ETH_LOCAL_URL);
+    api = await getDevnetApi()
+  });
+
+  it("The finalized block is the latest sealed block", async () => {
+    // Submitting a transaction makes the node seal a new block.
+    await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(keyPair.publicKey))
+
+    const latest = await ethClient.getBlock({ blockTag: "latest" });
+    const finalized = await ethClient.getBlock({ blockTag: "finalized" });
+
+    assert.equal(finalized.number, latest.number);
+    assert.equal(finalized.hash, latest.hash);
+  });
+});
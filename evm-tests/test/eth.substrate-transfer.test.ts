@@ -7,7 +7,7 @@ import { devnet, MultiAddress } from "@polkadot-api/descriptors"
 import { PublicClient } from "viem";
 import { TypedApi, Binary, FixedSizeBinary } from "polkadot-api";
 import { generateRandomEthersWallet } from "../src/utils";
-import { tao, raoToEth, bigintToRao, compareEthBalanceWithTxFee } from "../src/balance-math";
+import { tao, raoToEth, bigintToRao, compareEthBalanceWithTxFee, ETH_PER_RAO } from "../src/balance-math";
 import { toViemAddress, convertPublicKeyToSs58, convertH160ToSS58, ss58ToH160, ss58ToEthAddress, ethAddressToH160 } from "../src/address-utils"
 import { ethers } from "ethers"
 import { estimateTransactionCost, getContract } from "../src/eth"
@@ -21,6 +21,8 @@ describe("Balance transfers between substrate and EVM", () => {
     // init eth part
     const wallet = generateRandomEthersWallet();
     const wallet2 = generateRandomEthersWallet();
+    const wallet3 = generateRandomEthersWallet();
+    const wallet4 = generateRandomEthersWallet();
     let publicClient: PublicClient;
     const provider = new ethers.JsonRpcProvider(ETH_LOCAL_URL);
     // init substrate part
@@ -34,6 +36,8 @@ describe("Balance transfers between substrate and EVM", () => {
 
         await forceSetBalanceToEthAddress(api, wallet.address)
         await forceSetBalanceToEthAddress(api, wallet2.address)
+        await forceSetBalanceToEthAddress(api, wallet3.address)
+        await forceSetBalanceToEthAddress(api, wallet4.address)
         await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(signer.publicKey))
         await disableWhiteListCheck(api, true)
     });
@@ -95,6 +99,60 @@ describe("Balance transfers between substrate and EVM", () => {
         assert.equal(receiverBalance, receiverBalanceAfterTranser - tao(1))
     });
 
+    it("Can transfer token from EVM to Substrate with keep alive", async () => {
+        const contract = getContract(IBALANCETRANSFER_ADDRESS, IBalanceTransferABI, wallet)
+        const senderBalance = await publicClient.getBalance({ address: toViemAddress(wallet.address) })
+        const receiverBalance = (await api.query.System.Account.getValue(convertPublicKeyToSs58(signer.publicKey))).data.free
+        const transferBalance = raoToEth(tao(1))
+
+        const tx = await contract.transferKeepAlive(signer.publicKey, { value: transferBalance.toString() })
+        await tx.wait()
+
+        const senderBalanceAfterTransfer = await publicClient.getBalance({ address: toViemAddress(wallet.address) })
+        const receiverBalanceAfterTranser = (await api.query.System.Account.getValue(convertPublicKeyToSs58(signer.publicKey))).data.free
+
+        compareEthBalanceWithTxFee(senderBalanceAfterTransfer, senderBalance - transferBalance)
+        assert.equal(receiverBalance, receiverBalanceAfterTranser - tao(1))
+    });
+
+    it("Transfer keep alive fails instead of reaping the sender", async () => {
+        const contract = getContract(IBALANCETRANSFER_ADDRESS, IBalanceTransferABI, wallet3)
+        const receiverBalance = (await api.query.System.Account.getValue(convertPublicKeyToSs58(signer.publicKey))).data.free
+        const ethBalance = await publicClient.getBalance({ address: toViemAddress(wallet3.address) })
+        const gasLimit = BigInt(1000000)
+        const gasPrice = gwei * BigInt(10)
+        // leave less than the existential deposit behind once the gas is paid
+        const value = ethBalance - gasLimit * gasPrice - raoToEth(BigInt(1))
+
+        try {
+            const tx = await contract.transferKeepAlive(signer.publicKey, { value: value.toString(), gasLimit, gasPrice })
+            await tx.wait()
+            assert.fail("transferKeepAlive should not succeed")
+        } catch (error) {
+            if (error instanceof assert.AssertionError) {
+                throw error
+            }
+        }
+
+        const receiverBalanceAfterTranser = (await api.query.System.Account.getValue(convertPublicKeyToSs58(signer.publicKey))).data.free
+        assert.equal(receiverBalance, receiverBalanceAfterTranser)
+    });
+
+    it("Transfer can reap the sender", async () => {
+        const contract = getContract(IBALANCETRANSFER_ADDRESS, IBalanceTransferABI, wallet4)
+        const receiverBalance = (await api.query.System.Account.getValue(convertPublicKeyToSs58(signer.publicKey))).data.free
+        const ethBalance = await publicClient.getBalance({ address: toViemAddress(wallet4.address) })
+        const gasLimit = BigInt(1000000)
+        const gasPrice = gwei * BigInt(10)
+        const value = ethBalance - gasLimit * gasPrice - raoToEth(BigInt(1))
+
+        const tx = await contract.transfer(signer.publicKey, { value: value.toString(), gasLimit, gasPrice })
+        await tx.wait()
+
+        const receiverBalanceAfterTranser = (await api.query.System.Account.getValue(convertPublicKeyToSs58(signer.publicKey))).data.free
+        assert.equal(receiverBalanceAfterTranser, receiverBalance + value / ETH_PER_RAO)
+    });
+
     it("Transfer from EVM to substrate using evm::withdraw", async () => {
         const ss58Address = convertPublicKeyToSs58(signer.publicKey)
         const senderBalance = (await api.query.System.Account.getValue(ss58Address)).data.free
@@ -0,0 +1,99 @@
+import * as assert from "assert";
+
+import { getAliceSigner, getDevnetApi, getRandomSubstrateKeypair, waitForTransactionCompletion } from "../src/substrate"
+import { devnet, MultiAddress } from "@polkadot-api/descriptors"
+import { Binary, PolkadotSigner, TypedApi } from "polkadot-api";
+import { convertH160ToPublicKey, convertH160ToSS58, convertPublicKeyToSs58 } from "../src/address-utils"
+import { generateRandomEthersWallet } from "../src/utils";
+import { ethers } from "ethers"
+import { IGOVERNANCE_ADDRESS, IGovernanceABI } from "../src/contracts/governance"
+import { INEURON_ADDRESS, INeuronABI } from "../src/contracts/neuron"
+import { addNewSubnetwork, forceSetBalanceToEthAddress, forceSetBalanceToSs58Address } from "../src/subtensor"
+
+describe("Test the governance precompile", () => {
+    // init eth part
+    const wallet = generateRandomEthersWallet();
+    const wallet2 = generateRandomEthersWallet();
+
+    // init substrate part
+    const hotkey = getRandomSubstrateKeypair();
+    const coldkey = getRandomSubstrateKeypair();
+
+    let api: TypedApi<typeof devnet>
+
+    // sudo account alice as signer
+    let alice: PolkadotSigner;
+
+    let proposalHash: string;
+    let proposalIndex: number;
+
+    before(async () => {
+        api = await getDevnetApi()
+        alice = await getAliceSigner();
+
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(hotkey.publicKey))
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(coldkey.publicKey))
+        await forceSetBalanceToEthAddress(api, wallet.address)
+        await forceSetBalanceToEthAddress(api, wallet2.address)
+
+        // The voting account has to own itself as a hotkey
+        const netuid = await addNewSubnetwork(api, hotkey, coldkey)
+        const neuron = new ethers.Contract(INEURON_ADDRESS, INeuronABI, wallet);
+        const registerTx = await neuron.burnedRegister(netuid, convertH160ToPublicKey(wallet.address));
+        await registerTx.wait();
+
+        // Make the voting account a senate member
+        const addSenateMember = api.tx.SenateMembers.add_member({ who: MultiAddress.Id(convertH160ToSS58(wallet.address)) })
+        await waitForTransactionCompletion(api, api.tx.Sudo.sudo({ call: addSenateMember.decodedCall }), alice)
+            .then(() => { })
+            .catch((error) => { console.log(`transaction error ${error}`) });
+
+        // Make alice a triumvirate member so she can submit a proposal
+        const aliceSs58 = convertPublicKeyToSs58(alice.publicKey)
+        if (!(await api.query.Triumvirate.Members.getValue()).includes(aliceSs58)) {
+            const addTriumvirateMember = api.tx.TriumvirateMembers.add_member({ who: MultiAddress.Id(aliceSs58) })
+            await waitForTransactionCompletion(api, api.tx.Sudo.sudo({ call: addTriumvirateMember.decodedCall }), alice)
+                .then(() => { })
+                .catch((error) => { console.log(`transaction error ${error}`) });
+        }
+
+        const proposal = api.tx.System.remark({ remark: Binary.fromText("governance precompile test") })
+        const propose = api.tx.Triumvirate.propose({
+            proposal: proposal.decodedCall,
+            length_bound: 1000,
+            duration: await api.constants.Triumvirate.MotionDuration(),
+        })
+        await waitForTransactionCompletion(api, propose, alice)
+            .then(() => { })
+            .catch((error) => { console.log(`transaction error ${error}`) });
+
+        const proposals = await api.query.Triumvirate.Proposals.getValue()
+        proposalHash = proposals[proposals.length - 1].asHex()
+        proposalIndex = (await api.query.Triumvirate.ProposalCount.getValue()) - 1
+    })
+
+    it("Non-members can't vote", async () => {
+        const contract = new ethers.Contract(IGOVERNANCE_ADDRESS, IGovernanceABI, wallet2);
+        try {
+            const tx = await contract.voteProposal(proposalHash, proposalIndex, true);
+            await tx.wait();
+            assert.fail("voteProposal should not succeed for a non-member")
+        } catch (error) {
+            if (error instanceof assert.AssertionError) {
+                throw error
+            }
+        }
+
+        const voting = await api.query.Triumvirate.Voting.getValue(Binary.fromHex(proposalHash))
+        assert.ok(!voting?.ayes.includes(convertH160ToSS58(wallet2.address)))
+    });
+
+    it("Senate members can vote", async () => {
+        const contract = new ethers.Contract(IGOVERNANCE_ADDRESS, IGovernanceABI, wallet);
+        const tx = await contract.voteProposal(proposalHash, proposalIndex, true);
+        await tx.wait();
+
+        const voting = await api.query.Triumvirate.Voting.getValue(Binary.fromHex(proposalHash))
+        assert.ok(voting?.ayes.includes(convertH160ToSS58(wallet.address)))
+    });
+});
@@ -4,7 +4,7 @@ import { getAliceSigner, getClient, getDevnetApi, waitForTransactionCompletion,
 import { getPublicClient, } from "../src/utils";
 import { ETH_LOCAL_URL, SUB_LOCAL_URL, } from "../src/config";
 import { devnet } from "@polkadot-api/descriptors"
-import { PublicClient } from "viem";
+import { PublicClient, toHex } from "viem";
 import { PolkadotSigner, TypedApi } from "polkadot-api";
 import { toViemAddress, convertPublicKeyToSs58 } from "../src/address-utils"
 import { IMetagraphABI, IMETAGRAPH_ADDRESS } from "../src/contracts/metagraph"
@@ -144,4 +144,155 @@ describe("Test the EVM chain ID", () => {
             assert.ok(value != undefined);
         }
     });
-});
\ No newline at end of file
+
+    it("Metagraph getNeuron matches the per-field reads", async () => {
+        const uid = 0
+        const read = (functionName: string) => publicClient.readContract({
+            abi: IMetagraphABI,
+            address: toViemAddress(IMETAGRAPH_ADDRESS),
+            functionName,
+            args: [subnetId, uid]
+        })
+
+        const neuron = await read("getNeuron")
+        assert.ok(neuron instanceof Object)
+
+        const fields: [string, string][] = [
+            ["hotkey", "getHotkey"],
+            ["coldkey", "getColdkey"],
+            ["stake", "getStake"],
+            ["rank", "getRank"],
+            ["trust", "getTrust"],
+            ["consensus", "getConsensus"],
+            ["incentive", "getIncentive"],
+            ["dividends", "getDividends"],
+            ["emission", "getEmission"],
+            ["active", "getIsActive"],
+            ["validator_permit", "getValidatorStatus"],
+            ["last_update", "getLastUpdate"],
+        ]
+        for (const [field, method] of fields) {
+            assert.ok(field in neuron, `${field} not included in neuron`)
+            assert.equal((neuron as Record<string, unknown>)[field], await read(method), field)
+        }
+    });
+
+    it("Metagraph getNeuronsPaged pages through every neuron", async () => {
+        const uidCount = Number(await publicClient.readContract({
+            abi: IMetagraphABI,
+            address: toViemAddress(IMETAGRAPH_ADDRESS),
+            functionName: "getUidCount",
+            args: [subnetId]
+        }))
+
+        const count = 1
+        const hotkeys: string[] = []
+        for (let start = 0; start < uidCount + count; start += count) {
+            const page = await publicClient.readContract({
+                abi: IMetagraphABI,
+                address: toViemAddress(IMETAGRAPH_ADDRESS),
+                functionName: "getNeuronsPaged",
+                args: [subnetId, start, count]
+            }) as { hotkey: string }[]
+            assert.ok(page.length <= count)
+            hotkeys.push(...page.map((neuron) => neuron.hotkey))
+        }
+
+        assert.equal(hotkeys.length, uidCount)
+        for (let uid = 0; uid < uidCount; uid++) {
+            const hotkey = await publicClient.readContract({
+                abi: IMetagraphABI,
+                address: toViemAddress(IMETAGRAPH_ADDRESS),
+                functionName: "getHotkey",
+                args: [subnetId, uid]
+            })
+            assert.equal(hotkeys[uid], hotkey)
+        }
+    });
+
+    it("Metagraph getNeuronStake matches the hotkey alpha on the subnet", async () => {
+        const uid = 0
+        const neuronStake = await publicClient.readContract({
+            abi: IMetagraphABI,
+            address: toViemAddress(IMETAGRAPH_ADDRESS),
+            functionName: "getNeuronStake",
+            args: [subnetId, uid]
+        })
+        const alpha = await api.query.SubtensorModule.TotalHotkeyAlpha.getValue(convertPublicKeyToSs58(hotkey.publicKey), subnetId)
+        assert.equal(neuronStake, alpha)
+
+        // uids without a neuron have no stake
+        const uidCount = await api.query.SubtensorModule.SubnetworkN.getValue(subnetId)
+        const emptyStake = await publicClient.readContract({
+            abi: IMetagraphABI,
+            address: toViemAddress(IMETAGRAPH_ADDRESS),
+            functionName: "getNeuronStake",
+            args: [subnetId, uidCount]
+        })
+        assert.equal(emptyStake, BigInt(0))
+    });
+
+    it("Metagraph getAxon by hotkey returns the served axon", async () => {
+        const tx = api.tx.SubtensorModule.serve_axon({
+            netuid: subnetId,
+            version: 2,
+            ip: BigInt(1677722112),
+            port: 8091,
+            ip_type: 4,
+            protocol: 0,
+            placeholder1: 0,
+            placeholder2: 0,
+        })
+        await waitForTransactionCompletion(api, tx, getSignerFromKeypair(hotkey))
+            .then(() => { })
+            .catch((error) => { console.log(`transaction error ${error}`) });
+
+        const axon = await publicClient.readContract({
+            abi: IMetagraphABI,
+            address: toViemAddress(IMETAGRAPH_ADDRESS),
+            functionName: "getAxon",
+            args: [subnetId, toHex(hotkey.publicKey)]
+        }) as { version: number, ip: bigint, port: number, ip_type: number, protocol: number }
+        const stored = await api.query.SubtensorModule.Axons.getValue(subnetId, convertPublicKeyToSs58(hotkey.publicKey))
+        assert.ok(stored != undefined)
+        assert.equal(axon.version, stored.version)
+        assert.equal(axon.ip, stored.ip)
+        assert.equal(axon.port, stored.port)
+        assert.equal(axon.ip_type, stored.ip_type)
+        assert.equal(axon.protocol, stored.protocol)
+
+        // hotkeys not registered on the subnet have no axon
+        try {
+            await publicClient.readContract({
+                abi: IMetagraphABI,
+                address: toViemAddress(IMETAGRAPH_ADDRESS),
+                functionName: "getAxon",
+                args: [subnetId, toHex(coldkey.publicKey)]
+            })
+            assert.fail("getAxon should revert for an unregistered hotkey")
+        } catch (error) {
+            if (error instanceof assert.AssertionError) {
+                throw error
+            }
+        }
+    });
+
+    it("Metagraph view calls without gas for storage reads run out of gas", async () => {
+        try {
+            // only covers the intrinsic cost of the call, nothing is left for the storage read
+            await publicClient.readContract({
+                abi: IMetagraphABI,
+                address: toViemAddress(IMETAGRAPH_ADDRESS),
+                functionName: "getUidCount",
+                args: [subnetId],
+                gas: BigInt(21300),
+            })
+            assert.fail("getUidCount should run out of gas")
+        } catch (error) {
+            if (error instanceof assert.AssertionError) {
+                throw error
+            }
+            assert.equal(error instanceof Error && error.toString().includes("out of gas"), true)
+        }
+    });
+});
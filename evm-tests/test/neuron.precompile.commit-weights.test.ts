@@ -0,0 +1,92 @@
+import * as assert from "assert";
+import { getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate"
+import { devnet } from "@polkadot-api/descriptors"
+import { TypedApi } from "polkadot-api";
+import { convertPublicKeyToSs58, convertH160ToSS58 } from "../src/address-utils"
+import { ethers } from "ethers"
+import { INEURON_ADDRESS, INeuronABI } from "../src/contracts/neuron"
+import { generateRandomEthersWallet } from "../src/utils"
+import {
+    forceSetBalanceToEthAddress, forceSetBalanceToSs58Address, addNewSubnetwork, setCommitRevealWeightsEnabled, setWeightsSetRateLimit, burnedRegister,
+    setTempo
+} from "../src/subtensor"
+
+describe("Test neuron precompile commit weights", () => {
+    // init eth part
+    const wallet = generateRandomEthersWallet();
+
+    // init substrate part
+    const hotkey = getRandomSubstrateKeypair();
+    const coldkey = getRandomSubstrateKeypair();
+
+    let api: TypedApi<typeof devnet>
+    let netuid: number
+
+    before(async () => {
+        // init variables got from await and async
+        api = await getDevnetApi()
+
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(hotkey.publicKey))
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(coldkey.publicKey))
+        await forceSetBalanceToEthAddress(api, wallet.address)
+        netuid = await addNewSubnetwork(api, hotkey, coldkey)
+
+        console.log("test the case on subnet ", netuid)
+
+        await setCommitRevealWeightsEnabled(api, netuid, true)
+        // a long rate limit, so a second commit is always too fast
+        await setWeightsSetRateLimit(api, netuid, BigInt(1000))
+        // a long tempo, so the commit can't cross an epoch boundary during the test
+        await setTempo(api, netuid, 60000)
+
+        await burnedRegister(api, netuid, convertH160ToSS58(wallet.address), coldkey)
+    })
+
+    it("EVM neuron commit weights returns the first reveal block", async () => {
+        const contract = new ethers.Contract(INEURON_ADDRESS, INeuronABI, wallet);
+        const commitHash = ethers.randomBytes(32)
+
+        const firstRevealBlock = await contract.commitWeights.staticCall(netuid, commitHash)
+        const tx = await contract.commitWeights(netuid, commitHash)
+        await tx.wait()
+
+        const weightsCommit = await api.query.SubtensorModule.WeightCommits.getValue(
+            netuid,
+            convertH160ToSS58(wallet.address)
+        )
+        if (weightsCommit === undefined) {
+            throw new Error("submit weights failed")
+        }
+        assert.equal(weightsCommit.length, 1)
+        const [hash, commitBlock, storedFirstRevealBlock] = weightsCommit[0]
+        assert.equal(hash.asHex(), ethers.hexlify(commitHash))
+        assert.equal(BigInt(firstRevealBlock), storedFirstRevealBlock)
+        assert.ok(storedFirstRevealBlock > commitBlock)
+    })
+
+    it("EVM neuron commit weights within the rate limit reverts", async () => {
+        const contract = new ethers.Contract(INEURON_ADDRESS, INeuronABI, wallet);
+
+        await assert.rejects(async () => {
+            const tx = await contract.commitWeights(netuid, ethers.randomBytes(32))
+            await tx.wait()
+        })
+
+        const weightsCommit = await api.query.SubtensorModule.WeightCommits.getValue(
+            netuid,
+            convertH160ToSS58(wallet.address)
+        )
+        assert.equal(weightsCommit?.length, 1)
+    })
+
+    it("EVM neuron precompile reflects whether commit-reveal is enabled", async () => {
+        const contract = new ethers.Contract(INEURON_ADDRESS, INeuronABI, wallet);
+        assert.equal(await contract.isCommitRevealEnabled(netuid), true)
+
+        await setCommitRevealWeightsEnabled(api, netuid, false)
+        assert.equal(await contract.isCommitRevealEnabled(netuid), false)
+
+        await setCommitRevealWeightsEnabled(api, netuid, true)
+        assert.equal(await contract.isCommitRevealEnabled(netuid), true)
+    })
+});
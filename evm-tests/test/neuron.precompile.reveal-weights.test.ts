@@ -100,6 +100,21 @@ describe("Test neuron precompile reveal weights", () => {
         assert.ok(weightsCommit.length > 0)
     })
 
+    it("EVM neuron reveal weights rejects mismatched uids and values", async () => {
+        let totalNetworks = await api.query.SubtensorModule.TotalNetworks.getValue()
+        const netuid = totalNetworks - 1
+        const contract = new ethers.Contract(INEURON_ADDRESS, INeuronABI, wallet);
+
+        await assert.rejects(
+            contract.revealWeights.staticCall(netuid, uids, [...values, 1], salt, version_key)
+        )
+
+        // the commit is still there to be revealed
+        const ss58Address = convertH160ToSS58(wallet.address)
+        const weightsCommit = await api.query.SubtensorModule.WeightCommits.getValue(netuid, ss58Address)
+        assert.ok(weightsCommit !== undefined && weightsCommit.length > 0)
+    })
+
     it("EVM neuron reveal weights via call precompile", async () => {
         let totalNetworks = await api.query.SubtensorModule.TotalNetworks.getValue()
         const netuid = totalNetworks - 1
@@ -3,7 +3,7 @@ import { getAliceSigner, getClient, getDevnetApi, getRandomSubstrateKeypair } fr
 import { SUB_LOCAL_URL, } from "../src/config";
 import { devnet } from "@polkadot-api/descriptors"
 import { PolkadotSigner, TypedApi } from "polkadot-api";
-import { convertPublicKeyToSs58, convertH160ToSS58 } from "../src/address-utils"
+import { convertPublicKeyToSs58, convertH160ToSS58, convertH160ToPublicKey } from "../src/address-utils"
 import { ethers } from "ethers"
 import { INEURON_ADDRESS, INeuronABI } from "../src/contracts/neuron"
 import { generateRandomEthersWallet } from "../src/utils"
@@ -159,4 +159,26 @@ describe("Test neuron precompile Serve Axon Prometheus", () => {
         assert.equal(prometheus?.port, port)
         assert.equal(prometheus?.ip_type, ipType)
     });
+
+    it("Set Neuron Certificate", async () => {
+        let netuid = (await api.query.SubtensorModule.TotalNetworks.getValue()) - 1
+        const certificate = new Uint8Array([1, 2, 3, 4, 5, 6, 7, 8]);
+
+        const contract = new ethers.Contract(INEURON_ADDRESS, INeuronABI, wallet1);
+
+        const tx = await contract.setNeuronCertificate(netuid, certificate);
+        await tx.wait();
+
+        const stored = await contract.getNeuronCertificate(
+            netuid,
+            convertH160ToPublicKey(wallet1.address)
+        );
+        assert.equal(stored, ethers.hexlify(certificate))
+
+        // Certificates longer than 65 bytes are rejected.
+        await assert.rejects(async () => {
+            const tx = await contract.setNeuronCertificate(netuid, new Uint8Array(66))
+            await tx.wait()
+        })
+    });
 });
\ No newline at end of file
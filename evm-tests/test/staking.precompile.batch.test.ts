@@ -0,0 +1,86 @@
+import * as assert from "assert";
+import { getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate"
+import { devnet } from "@polkadot-api/descriptors"
+import { TypedApi } from "polkadot-api";
+import { convertPublicKeyToSs58, convertH160ToSS58 } from "../src/address-utils"
+import { tao } from "../src/balance-math"
+import { ethers } from "ethers"
+import { generateRandomEthersWallet } from "../src/utils"
+import { forceSetBalanceToEthAddress, forceSetBalanceToSs58Address, addNewSubnetwork } from "../src/subtensor"
+import { ISTAKING_V2_ADDRESS, IStakingV2ABI } from "../src/contracts/staking"
+
+describe("Test batched staking precompile calls", () => {
+    // init eth part
+    const wallet = generateRandomEthersWallet();
+    // init substrate part
+    const hotkeys = [getRandomSubstrateKeypair(), getRandomSubstrateKeypair(), getRandomSubstrateKeypair()];
+    const coldkey = getRandomSubstrateKeypair();
+    const hotkey = hotkeys[0]
+
+    let api: TypedApi<typeof devnet>
+    let netuids: number[] = []
+
+    before(async () => {
+        // init variables got from await and async
+        api = await getDevnetApi()
+
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(coldkey.publicKey))
+        await forceSetBalanceToEthAddress(api, wallet.address)
+        for (const subnetHotkey of hotkeys) {
+            await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(subnetHotkey.publicKey))
+            netuids.push(await addNewSubnetwork(api, subnetHotkey, coldkey))
+        }
+    })
+
+    it("Stakes into three subnets in one call", async () => {
+        const contract = new ethers.Contract(ISTAKING_V2_ADDRESS, IStakingV2ABI, wallet);
+        const amounts = [tao(10), tao(20), tao(30)]
+
+        const tx = await contract.batchStake(hotkey.publicKey, netuids, amounts.map((amount) => amount.toString()))
+        await tx.wait()
+
+        for (const netuid of netuids) {
+            const alpha = await api.query.SubtensorModule.Alpha.getValue(convertPublicKeyToSs58(hotkey.publicKey), convertH160ToSS58(wallet.address), netuid)
+            assert.ok(alpha > BigInt(0), `no stake on subnet ${netuid}`)
+        }
+    })
+
+    it("Unstakes from three subnets in one call", async () => {
+        const contract = new ethers.Contract(ISTAKING_V2_ADDRESS, IStakingV2ABI, wallet);
+        const before = []
+        for (const netuid of netuids) {
+            before.push(await api.query.SubtensorModule.Alpha.getValue(convertPublicKeyToSs58(hotkey.publicKey), convertH160ToSS58(wallet.address), netuid))
+        }
+
+        const tx = await contract.batchUnstake(hotkey.publicKey, netuids, before.map((alpha) => (alpha / BigInt(2)).toString()))
+        await tx.wait()
+
+        for (let i = 0; i < netuids.length; i++) {
+            const alpha = await api.query.SubtensorModule.Alpha.getValue(convertPublicKeyToSs58(hotkey.publicKey), convertH160ToSS58(wallet.address), netuids[i])
+            assert.ok(alpha < before[i], `stake on subnet ${netuids[i]} not reduced`)
+        }
+    })
+
+    it("Applies nothing when one of the stakes fails", async () => {
+        const contract = new ethers.Contract(ISTAKING_V2_ADDRESS, IStakingV2ABI, wallet);
+        const before = await api.query.SubtensorModule.Alpha.getValue(convertPublicKeyToSs58(hotkey.publicKey), convertH160ToSS58(wallet.address), netuids[0])
+
+        // The second subnet doesn't exist, so the first stake has to be rolled back.
+        await assert.rejects(async () => {
+            const tx = await contract.batchStake(hotkey.publicKey, [netuids[0], 65000], [tao(10).toString(), tao(10).toString()], { gasLimit: 1000000 })
+            await tx.wait()
+        })
+
+        const after = await api.query.SubtensorModule.Alpha.getValue(convertPublicKeyToSs58(hotkey.publicKey), convertH160ToSS58(wallet.address), netuids[0])
+        assert.equal(after, before)
+    })
+
+    it("Rejects netuids and amounts of different lengths", async () => {
+        const contract = new ethers.Contract(ISTAKING_V2_ADDRESS, IStakingV2ABI, wallet);
+
+        await assert.rejects(async () => {
+            const tx = await contract.batchStake(hotkey.publicKey, netuids, [tao(1).toString()], { gasLimit: 1000000 })
+            await tx.wait()
+        })
+    })
+})
@@ -0,0 +1,64 @@
+import * as assert from "assert";
+import { getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate"
+import { devnet } from "@polkadot-api/descriptors"
+import { TypedApi } from "polkadot-api";
+import { convertPublicKeyToSs58, convertH160ToSS58, convertH160ToPublicKey } from "../src/address-utils"
+import { tao } from "../src/balance-math"
+import { ethers } from "ethers"
+import { generateRandomEthersWallet } from "../src/utils"
+import { forceSetBalanceToEthAddress, forceSetBalanceToSs58Address, addNewSubnetwork, burnedRegister } from "../src/subtensor"
+import { ISTAKING_V2_ADDRESS, IStakingV2ABI } from "../src/contracts/staking"
+
+describe("Test staking precompile logs", () => {
+    // init eth part
+    const wallet = generateRandomEthersWallet();
+    // init substrate part
+    const hotkey = getRandomSubstrateKeypair();
+    const coldkey = getRandomSubstrateKeypair();
+
+    let api: TypedApi<typeof devnet>
+    let netuid: number
+
+    before(async () => {
+        // init variables got from await and async
+        api = await getDevnetApi()
+
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(hotkey.publicKey))
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(coldkey.publicKey))
+        await forceSetBalanceToEthAddress(api, wallet.address)
+        netuid = await addNewSubnetwork(api, hotkey, coldkey)
+        await burnedRegister(api, netuid, convertH160ToSS58(wallet.address), coldkey)
+    })
+
+    it("Emits StakeAdded and StakeRemoved logs", async () => {
+        const contract = new ethers.Contract(ISTAKING_V2_ADDRESS, IStakingV2ABI, wallet);
+        const hotkeyTopic = ethers.hexlify(hotkey.publicKey)
+        const coldkeyTopic = ethers.hexlify(convertH160ToPublicKey(wallet.address))
+        const stakeBalance = tao(20)
+
+        const addTx = await contract.addStake(hotkey.publicKey, stakeBalance.toString(), netuid)
+        const addReceipt = await addTx.wait()
+        const addLog = addReceipt.logs.find(
+            (log: ethers.Log) => log.topics[0] === ethers.id("StakeAdded(bytes32,bytes32,uint256,uint256)")
+        )
+        assert.ok(addLog, "StakeAdded log not emitted")
+        assert.equal(addLog.address.toLowerCase(), ISTAKING_V2_ADDRESS.toLowerCase())
+        assert.equal(addLog.topics[1], hotkeyTopic)
+        assert.equal(addLog.topics[2], coldkeyTopic)
+        const added = contract.interface.parseLog(addLog)
+        assert.equal(BigInt(added?.args.netuid), BigInt(netuid))
+        assert.equal(BigInt(added?.args.amount), stakeBalance)
+
+        const alpha = await api.query.SubtensorModule.Alpha.getValue(convertPublicKeyToSs58(hotkey.publicKey), convertH160ToSS58(wallet.address), netuid)
+        const removeTx = await contract.removeStake(hotkey.publicKey, (alpha / BigInt(2)).toString(), netuid)
+        const removeReceipt = await removeTx.wait()
+        const removeLog = removeReceipt.logs.find(
+            (log: ethers.Log) => log.topics[0] === ethers.id("StakeRemoved(bytes32,bytes32,uint256,uint256)")
+        )
+        assert.ok(removeLog, "StakeRemoved log not emitted")
+        assert.equal(removeLog.topics[1], hotkeyTopic)
+        assert.equal(removeLog.topics[2], coldkeyTopic)
+        const removed = contract.interface.parseLog(removeLog)
+        assert.equal(BigInt(removed?.args.amount), alpha / BigInt(2))
+    })
+})
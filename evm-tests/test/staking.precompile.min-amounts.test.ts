@@ -0,0 +1,47 @@
+import * as assert from "assert";
+import { getDevnetApi } from "../src/substrate"
+import { devnet } from "@polkadot-api/descriptors"
+import { TypedApi } from "polkadot-api";
+import { ethers } from "ethers"
+import { generateRandomEthersWallet } from "../src/utils"
+import { tao } from "../src/balance-math"
+import { setMinStakeAmount } from "../src/subtensor"
+import { ISTAKING_V2_ADDRESS, IStakingV2ABI } from "../src/contracts/staking"
+
+describe("Test staking precompile min amounts", () => {
+    // init eth part
+    const wallet = generateRandomEthersWallet();
+
+    let api: TypedApi<typeof devnet>
+
+    before(async () => {
+        // init variables got from await and async
+        api = await getDevnetApi()
+    })
+
+    after(async () => {
+        await setMinStakeAmount(api, BigInt(0))
+    })
+
+    it("Returns the configured min stake amount", async () => {
+        const contract = new ethers.Contract(ISTAKING_V2_ADDRESS, IStakingV2ABI, wallet);
+
+        // Well above the built-in minimums, so both values follow the configured one.
+        const minStakeAmount = tao(100)
+        await setMinStakeAmount(api, minStakeAmount)
+
+        const [minStake, minRemove] = await contract.getMinStakeAmounts()
+        assert.equal(BigInt(minStake), minStakeAmount)
+        assert.equal(BigInt(minRemove), minStakeAmount)
+    })
+
+    it("Returns the built-in minimums without a configured min stake amount", async () => {
+        const contract = new ethers.Contract(ISTAKING_V2_ADDRESS, IStakingV2ABI, wallet);
+        await setMinStakeAmount(api, BigInt(0))
+
+        const [minStake, minRemove] = await contract.getMinStakeAmounts()
+        assert.ok(BigInt(minStake) > BigInt(0))
+        assert.ok(BigInt(minRemove) > BigInt(0))
+        assert.ok(BigInt(minStake) < tao(100))
+    })
+})
@@ -0,0 +1,53 @@
+import * as assert from "assert";
+import { getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate"
+import { devnet } from "@polkadot-api/descriptors"
+import { TypedApi } from "polkadot-api";
+import { convertPublicKeyToSs58 } from "../src/address-utils"
+import { tao } from "../src/balance-math"
+import { ethers } from "ethers"
+import { generateRandomEthersWallet } from "../src/utils"
+import { forceSetBalanceToEthAddress, forceSetBalanceToSs58Address, addNewSubnetwork, setSafeMode } from "../src/subtensor"
+import { ISTAKING_V2_ADDRESS, IStakingV2ABI } from "../src/contracts/staking"
+
+describe("Test staking precompile in safe mode", () => {
+    // init eth part
+    const wallet = generateRandomEthersWallet();
+    // init substrate part
+    const hotkey = getRandomSubstrateKeypair();
+    const coldkey = getRandomSubstrateKeypair();
+
+    let api: TypedApi<typeof devnet>
+    let netuid: number
+
+    before(async () => {
+        // init variables got from await and async
+        api = await getDevnetApi()
+
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(hotkey.publicKey))
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(coldkey.publicKey))
+        await forceSetBalanceToEthAddress(api, wallet.address)
+        netuid = await addNewSubnetwork(api, hotkey, coldkey)
+    })
+
+    after(async () => {
+        await setSafeMode(api, false)
+    })
+
+    it("Can not add stake while safe mode is active", async () => {
+        const contract = new ethers.Contract(ISTAKING_V2_ADDRESS, IStakingV2ABI, wallet);
+        const stakeBalance = tao(20)
+
+        // The call goes through before safe mode is entered.
+        await contract.addStake.staticCall(hotkey.publicKey, stakeBalance.toString(), netuid)
+
+        // The safe mode call filter rejects the dispatch, while view methods keep working.
+        await setSafeMode(api, true)
+        await assert.rejects(
+            contract.addStake.staticCall(hotkey.publicKey, stakeBalance.toString(), netuid)
+        )
+        await contract.getStake(hotkey.publicKey, coldkey.publicKey, netuid)
+
+        await setSafeMode(api, false)
+        await contract.addStake.staticCall(hotkey.publicKey, stakeBalance.toString(), netuid)
+    })
+})
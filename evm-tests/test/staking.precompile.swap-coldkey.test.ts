@@ -0,0 +1,87 @@
+import * as assert from "assert";
+import { getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate"
+import { devnet } from "@polkadot-api/descriptors"
+import { TypedApi } from "polkadot-api";
+import { convertPublicKeyToSs58, convertH160ToSS58 } from "../src/address-utils"
+import { raoToEth, tao } from "../src/balance-math"
+import { ethers } from "ethers"
+import { generateRandomEthersWallet } from "../src/utils"
+import {
+    forceSetBalanceToEthAddress, forceSetBalanceToSs58Address, addNewSubnetwork,
+    setColdkeySwapScheduleDuration,
+} from "../src/subtensor"
+import { ISTAKING_V2_ADDRESS, IStakingV2ABI } from "../src/contracts/staking"
+
+describe("Test staking precompile swap coldkey", () => {
+    // init eth part
+    const wallet = generateRandomEthersWallet();
+    // init substrate part
+    const hotkey = getRandomSubstrateKeypair();
+    const coldkey = getRandomSubstrateKeypair();
+    const newColdkey = getRandomSubstrateKeypair();
+
+    let api: TypedApi<typeof devnet>
+
+    before(async () => {
+        // init variables got from await and async
+        api = await getDevnetApi()
+
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(hotkey.publicKey))
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(coldkey.publicKey))
+        await forceSetBalanceToEthAddress(api, wallet.address)
+        await addNewSubnetwork(api, hotkey, coldkey)
+        await setColdkeySwapScheduleDuration(api, 1)
+    })
+
+    it("Can not swap coldkey to a key in use", async () => {
+        const swapCost = await api.constants.SubtensorModule.KeySwapCost()
+        const contract = new ethers.Contract(ISTAKING_V2_ADDRESS, IStakingV2ABI, wallet);
+
+        try {
+            // the hotkey is registered, so it can't become a coldkey
+            const tx = await contract.swapColdkey(hotkey.publicKey, { value: raoToEth(swapCost).toString() })
+            await tx.wait()
+            assert.fail("Transaction should have failed");
+        } catch (error) {
+            // Transaction failed as expected
+        }
+
+        const scheduled = await api.query.SubtensorModule.ColdkeySwapScheduled.getValue(convertH160ToSS58(wallet.address))
+        assert.equal(scheduled, undefined)
+    })
+
+    it("Can swap coldkey", async () => {
+        let netuid = (await api.query.SubtensorModule.TotalNetworks.getValue()) - 1
+        const contract = new ethers.Contract(ISTAKING_V2_ADDRESS, IStakingV2ABI, wallet);
+        const tx = await contract.addStake(hotkey.publicKey, tao(20).toString(), netuid)
+        await tx.wait()
+
+        const stakeBefore = await api.query.SubtensorModule.Alpha.getValue(convertPublicKeyToSs58(hotkey.publicKey), convertH160ToSS58(wallet.address), netuid)
+        assert.ok(stakeBefore > 0)
+
+        const swapCost = await api.constants.SubtensorModule.KeySwapCost()
+        const swapTx = await contract.swapColdkey(newColdkey.publicKey, { value: raoToEth(swapCost).toString() })
+        await swapTx.wait()
+
+        // the swap is executed by the scheduler once the schedule duration has passed
+        let index = 0;
+        while (index < 60) {
+            const stake = await api.query.SubtensorModule.Alpha.getValue(convertPublicKeyToSs58(hotkey.publicKey), convertPublicKeyToSs58(newColdkey.publicKey), netuid)
+            if (stake > 0) {
+                break;
+            }
+
+            await new Promise((resolve) => setTimeout(resolve, 1000));
+            console.log("wait for the scheduled coldkey swap");
+            index += 1;
+        }
+
+        const stakeAfter = await api.query.SubtensorModule.Alpha.getValue(convertPublicKeyToSs58(hotkey.publicKey), convertPublicKeyToSs58(newColdkey.publicKey), netuid)
+        assert.equal(stakeAfter, stakeBefore)
+        const oldStake = await api.query.SubtensorModule.Alpha.getValue(convertPublicKeyToSs58(hotkey.publicKey), convertH160ToSS58(wallet.address), netuid)
+        assert.equal(oldStake, BigInt(0))
+
+        const stakingHotkeys = await api.query.SubtensorModule.StakingHotkeys.getValue(convertPublicKeyToSs58(newColdkey.publicKey))
+        assert.ok(stakingHotkeys.includes(convertPublicKeyToSs58(hotkey.publicKey)))
+    })
+})
@@ -0,0 +1,70 @@
+import * as assert from "assert";
+import { getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate"
+import { devnet } from "@polkadot-api/descriptors"
+import { TypedApi } from "polkadot-api";
+import { convertPublicKeyToSs58 } from "../src/address-utils"
+import { ethers } from "ethers"
+import { generateRandomEthersWallet } from "../src/utils"
+import {
+    forceSetBalanceToEthAddress, forceSetBalanceToSs58Address, addNewSubnetwork,
+} from "../src/subtensor"
+import { ISTAKING_V2_ADDRESS, IStakingV2ABI } from "../src/contracts/staking"
+import { INEURON_ADDRESS, INeuronABI } from "../src/contracts/neuron"
+
+describe("Test staking precompile take", () => {
+    // init eth part
+    const wallet = generateRandomEthersWallet();
+    // init substrate part
+    const hotkey = getRandomSubstrateKeypair();
+    const coldkey = getRandomSubstrateKeypair();
+    // hotkey owned by the eth wallet
+    const delegateHotkey = getRandomSubstrateKeypair();
+
+    let api: TypedApi<typeof devnet>
+
+    before(async () => {
+        // init variables got from await and async
+        api = await getDevnetApi()
+
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(hotkey.publicKey))
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(coldkey.publicKey))
+        await forceSetBalanceToEthAddress(api, wallet.address)
+        const netuid = await addNewSubnetwork(api, hotkey, coldkey)
+
+        const neuronContract = new ethers.Contract(INEURON_ADDRESS, INeuronABI, wallet);
+        const tx = await neuronContract.burnedRegister(netuid, delegateHotkey.publicKey)
+        await tx.wait()
+    })
+
+    it("Can not set take above the max take", async () => {
+        const contract = new ethers.Contract(ISTAKING_V2_ADDRESS, IStakingV2ABI, wallet);
+        const takeBefore = Number(await contract.getTake(delegateHotkey.publicKey))
+        const maxTake = await api.query.SubtensorModule.MaxDelegateTake.getValue()
+
+        try {
+            const tx = await contract.setTake(delegateHotkey.publicKey, maxTake + 1)
+            await tx.wait()
+            assert.fail("Transaction should have failed");
+        } catch (error) {
+            // Transaction failed as expected
+        }
+
+        const takeAfter = await api.query.SubtensorModule.Delegates.getValue(convertPublicKeyToSs58(delegateHotkey.publicKey))
+        assert.equal(takeAfter, takeBefore)
+    })
+
+    it("Can set take", async () => {
+        const contract = new ethers.Contract(ISTAKING_V2_ADDRESS, IStakingV2ABI, wallet);
+        const minTake = await api.query.SubtensorModule.MinDelegateTake.getValue()
+        const takeBefore = Number(await contract.getTake(delegateHotkey.publicKey))
+        const newTake = Math.max(minTake, Math.floor(takeBefore / 2))
+        assert.ok(newTake < takeBefore)
+
+        const tx = await contract.setTake(delegateHotkey.publicKey, newTake)
+        await tx.wait()
+
+        const takeOnChain = await api.query.SubtensorModule.Delegates.getValue(convertPublicKeyToSs58(delegateHotkey.publicKey))
+        assert.equal(takeOnChain, newTake)
+        assert.equal(Number(await contract.getTake(delegateHotkey.publicKey)), newTake)
+    })
+})
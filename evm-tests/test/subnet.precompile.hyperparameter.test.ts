@@ -8,14 +8,17 @@ import { generateRandomEthersWallet } from "../src/utils";
 import { ISubnetABI, ISUBNET_ADDRESS } from "../src/contracts/subnet"
 import { ethers } from "ethers"
 import { forceSetBalanceToEthAddress, forceSetBalanceToSs58Address } from "../src/subtensor"
+import { raoToEth } from "../src/balance-math"
 
 describe("Test the Subnet precompile contract", () => {
     // init eth part
     const wallet = generateRandomEthersWallet();
+    const wallet2 = generateRandomEthersWallet();
     // init substrate part
 
     const hotkey1 = getRandomSubstrateKeypair();
     const hotkey2 = getRandomSubstrateKeypair();
+    const hotkey3 = getRandomSubstrateKeypair();
     let api: TypedApi<typeof devnet>
 
     before(async () => {
@@ -24,18 +27,47 @@ describe("Test the Subnet precompile contract", () => {
 
         await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(hotkey1.publicKey))
         await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(hotkey2.publicKey))
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(hotkey3.publicKey))
         await forceSetBalanceToEthAddress(api, wallet.address)
+        await forceSetBalanceToEthAddress(api, wallet2.address)
     })
 
     it("Can register network without identity info", async () => {
         const totalNetwork = await api.query.SubtensorModule.TotalNetworks.getValue()
+        const lockCost = await api.apis.SubnetRegistrationRuntimeApi.get_network_registration_cost()
 
         const contract = new ethers.Contract(ISUBNET_ADDRESS, ISubnetABI, wallet);
-        const tx = await contract.registerNetwork(hotkey1.publicKey);
+        const value = raoToEth(lockCost).toString()
+        const netuid = await contract.registerNetwork.staticCall(hotkey1.publicKey, { value });
+        const tx = await contract.registerNetwork(hotkey1.publicKey, { value });
         await tx.wait();
 
         const totalNetworkAfterAdd = await api.query.SubtensorModule.TotalNetworks.getValue()
         assert.ok(totalNetwork + 1 === totalNetworkAfterAdd)
+        assert.equal(Number(netuid), totalNetwork)
+        assert.equal(
+            await api.query.SubtensorModule.SubnetOwnerHotkey.getValue(Number(netuid)),
+            convertPublicKeyToSs58(hotkey1.publicKey)
+        )
+    });
+
+    it("Can't register network if the attached value is less than the lock cost", async () => {
+        const totalNetwork = await api.query.SubtensorModule.TotalNetworks.getValue()
+        const lockCost = await api.apis.SubnetRegistrationRuntimeApi.get_network_registration_cost()
+
+        const contract = new ethers.Contract(ISUBNET_ADDRESS, ISubnetABI, wallet);
+        try {
+            const tx = await contract.registerNetwork(hotkey1.publicKey, { value: raoToEth(lockCost - BigInt(1)).toString() });
+            await tx.wait();
+            assert.fail("registerNetwork should not succeed")
+        } catch (error) {
+            if (error instanceof assert.AssertionError) {
+                throw error
+            }
+        }
+
+        const totalNetworkAfterAdd = await api.query.SubtensorModule.TotalNetworks.getValue()
+        assert.equal(totalNetwork, totalNetworkAfterAdd)
     });
 
     it("Can register network with identity info", async () => {
@@ -439,4 +471,46 @@ describe("Test the Subnet precompile contract", () => {
             assert.equal(valueFromContract, onchainValue);
         }
     })
+
+    it("Can get the subnet owner cut", async () => {
+        const totalNetwork = await api.query.SubtensorModule.TotalNetworks.getValue()
+        const contract = new ethers.Contract(ISUBNET_ADDRESS, ISubnetABI, wallet);
+        const netuid = totalNetwork - 1;
+
+        const onchainValue = await api.query.SubtensorModule.SubnetOwnerCut.getValue()
+        const valueFromContract = Number(await contract.getOwnerCut(netuid));
+
+        assert.equal(valueFromContract, onchainValue);
+    })
+
+    it("Only the subnet owner can dissolve the network", async () => {
+        const lockCost = await api.apis.SubnetRegistrationRuntimeApi.get_network_registration_cost()
+
+        const contract = new ethers.Contract(ISUBNET_ADDRESS, ISubnetABI, wallet);
+        const value = raoToEth(lockCost).toString()
+        const netuid = Number(await contract.registerNetwork.staticCall(hotkey3.publicKey, { value }));
+        const tx = await contract.registerNetwork(hotkey3.publicKey, { value });
+        await tx.wait();
+        assert.ok(await api.query.SubtensorModule.NetworksAdded.getValue(netuid))
+
+        // a non-owner can't dissolve the network
+        const contract2 = new ethers.Contract(ISUBNET_ADDRESS, ISubnetABI, wallet2);
+        try {
+            const tx = await contract2.dissolveNetwork(netuid);
+            await tx.wait();
+            assert.fail("dissolveNetwork should not succeed for a non-owner")
+        } catch (error) {
+            if (error instanceof assert.AssertionError) {
+                throw error
+            }
+        }
+        assert.ok(await api.query.SubtensorModule.NetworksAdded.getValue(netuid))
+
+        // the owner can
+        const dissolveTx = await contract.dissolveNetwork(netuid);
+        await dissolveTx.wait();
+
+        assert.ok(!(await api.query.SubtensorModule.NetworksAdded.getValue(netuid)))
+        assert.equal(await api.query.SubtensorModule.SubnetworkN.getValue(netuid), 0)
+    })
 });
\ No newline at end of file
@@ -31,6 +31,26 @@ pub struct Extensions {
     pub fork_blocks: sc_client_api::ForkBlocks<Block>,
     /// Known bad block hashes.
     pub bad_blocks: sc_client_api::BadBlocks<Block>,
+    /// Forced changes of the GRANDPA authority set, which warp sync can't prove on its own.
+    #[serde(default)]
+    pub authority_set_hard_forks: Vec<AuthoritySetHardForkSpec>,
+}
+
+/// A forced change of the GRANDPA authority set, as listed in the chain spec.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthoritySetHardForkSpec {
+    /// The id of the authority set the change starts.
+    pub set_id: u64,
+    /// The hash of the block the change is enacted at.
+    pub block_hash: H256,
+    /// The number of the block the change is enacted at.
+    pub block_number: u32,
+    /// The authorities of the new set, with their weights.
+    pub authorities: Vec<(GrandpaId, u64)>,
+    /// The last block finalized before the change, if the change was forced without waiting for
+    /// the block above to be finalized.
+    pub last_finalized: Option<u32>,
 }
 
 /// Specialized `ChainSpec`. This is a specialization of the general Substrate ChainSpec type.
@@ -62,4 +62,6 @@ pub enum Sealing {
     Manual,
     /// Seal when transaction is executed.
     Instant,
+    /// Seal when transaction is executed and finalize the sealed block right away.
+    InstantFinalized,
 }
@@ -0,0 +1,99 @@
+//! Development-only RPC methods.
+//!
+//! These are only merged into the RPC module when the node runs with a manual seal command sink,
+//! so they're never available on production nodes.
+
+use futures::{
+    SinkExt,
+    channel::{mpsc, oneshot},
+};
+use jsonrpsee::{
+    core::{RpcResult, async_trait},
+    proc_macros::rpc,
+    types::{ErrorObjectOwned, error::ErrorObject},
+};
+use sc_consensus_manual_seal::EngineCommand;
+use subtensor_runtime_common::Hash;
+
+/// Maximum number of blocks that can be produced by a single `subtensor_devAdvanceBlocks` call.
+pub const MAX_ADVANCE_BLOCKS: u32 = 10_000;
+
+#[rpc(client, server)]
+pub trait SubtensorDevApi {
+    /// Seals `n` empty blocks one after another and returns their hashes.
+    ///
+    /// Blocks are sealed with `create_empty` and without waiting for the transaction pool, so
+    /// transaction inclusion is not guaranteed; use `engine_createBlock` when a block has to
+    /// include pending transactions. Blocks are only produced under manual sealing, as instant
+    /// sealing doesn't listen to seal commands.
+    #[method(name = "subtensor_devAdvanceBlocks")]
+    async fn advance_blocks(&self, n: u32) -> RpcResult<Vec<Hash>>;
+}
+
+/// Errors returned by the dev RPC.
+pub enum Error {
+    /// The requested number of blocks is zero or above [`MAX_ADVANCE_BLOCKS`].
+    InvalidBlockCount(u32),
+    /// Sealing a block failed.
+    SealFailed(String),
+}
+
+impl From<Error> for ErrorObjectOwned {
+    fn from(e: Error) -> Self {
+        match e {
+            Error::InvalidBlockCount(n) => ErrorObject::owned(
+                1,
+                format!(
+                    "Invalid block count {}, expected 1 to {}",
+                    n, MAX_ADVANCE_BLOCKS
+                ),
+                None::<()>,
+            ),
+            Error::SealFailed(e) => ErrorObject::owned(2, e, None::<()>),
+        }
+    }
+}
+
+/// Dev RPC handler holding the manual seal command sink.
+pub struct SubtensorDev {
+    command_sink: mpsc::Sender<EngineCommand<Hash>>,
+}
+
+impl SubtensorDev {
+    /// Creates a new instance of the dev RPC handler.
+    pub fn new(command_sink: mpsc::Sender<EngineCommand<Hash>>) -> Self {
+        Self { command_sink }
+    }
+}
+
+#[async_trait]
+impl SubtensorDevApiServer for SubtensorDev {
+    async fn advance_blocks(&self, n: u32) -> RpcResult<Vec<Hash>> {
+        if n == 0 || n > MAX_ADVANCE_BLOCKS {
+            return Err(Error::InvalidBlockCount(n).into());
+        }
+
+        let mut sink = self.command_sink.clone();
+        let mut hashes = Vec::with_capacity(n as usize);
+        for _ in 0..n {
+            // Wait for each block to be imported so the next one is built on top of it.
+            let (sender, receiver) = oneshot::channel();
+            sink.send(EngineCommand::SealNewBlock {
+                create_empty: true,
+                finalize: true,
+                parent_hash: None,
+                sender: Some(sender),
+            })
+            .await
+            .map_err(|e| Error::SealFailed(format!("Unable to send seal command: {:?}", e)))?;
+
+            let created = receiver
+                .await
+                .map_err(|e| Error::SealFailed(format!("Seal command dropped: {:?}", e)))?
+                .map_err(|e| Error::SealFailed(format!("Unable to seal block: {:?}", e)))?;
+            hashes.push(created.hash);
+        }
+
+        Ok(hashes)
+    }
+}
@@ -18,6 +18,7 @@ use sc_rpc::SubscriptionTaskExecutor;
 use sc_service::{Configuration, TaskManager, error::Error as ServiceError};
 use sc_transaction_pool::ChainApi;
 use sc_transaction_pool_api::TransactionPool;
+use sp_blockchain::HeaderBackend;
 use sp_inherents::CreateInherentDataProviders;
 use sp_runtime::traits::Block as BlockT;
 use std::path::PathBuf;
@@ -89,6 +90,18 @@ pub struct EthConfiguration {
     /// Default value is 200MB.
     #[arg(long, default_value = "209715200")]
     pub frontier_sql_backend_cache_size: u64,
+
+    /// Number of recent blocks whose transaction and log mappings the SQL backend keeps.
+    /// Older mappings are pruned periodically. By default nothing is pruned.
+    ///
+    /// Pruning is incompatible with serving `eth_getLogs` or transaction lookups for blocks
+    /// older than the window.
+    #[arg(long)]
+    pub frontier_sql_backend_prune_window: Option<u64>,
+
+    /// Interval in seconds between two prunings of the SQL backend.
+    #[arg(long, default_value = "600")]
+    pub frontier_sql_backend_prune_interval: u64,
 }
 
 pub fn db_config_dir(config: &Configuration) -> PathBuf {
@@ -127,6 +140,8 @@ pub async fn spawn_frontier_tasks(
             fc_mapping_sync::EthereumBlockNotification<Block>,
         >,
     >,
+    sql_prune_window: Option<u64>,
+    sql_prune_interval: Duration,
 ) {
     // Spawn main mapping sync worker background task.
     match &*frontier_backend {
@@ -168,6 +183,15 @@ pub async fn spawn_frontier_tasks(
                     pubsub_notification_sinks,
                 ),
             );
+
+            // Spawn the SQL backend pruning task, if a retention window is configured.
+            if let Some(prune_window) = sql_prune_window {
+                task_manager.spawn_handle().spawn(
+                    "frontier-sql-pruning",
+                    Some("frontier"),
+                    sql_pruning_task(client.clone(), b.clone(), prune_window, sql_prune_interval),
+                );
+            }
         }
     }
 
@@ -195,6 +219,56 @@ pub async fn spawn_frontier_tasks(
     );
 }
 
+/// Periodically prunes the SQL backend's mappings older than `prune_window` blocks.
+async fn sql_pruning_task(
+    client: Arc<FullClient>,
+    backend: Arc<fc_db::sql::Backend<Block>>,
+    prune_window: u64,
+    interval: Duration,
+) {
+    loop {
+        futures_timer::Delay::new(interval).await;
+
+        let best_number = u64::from(client.info().best_number);
+        match prune_sql_mappings(backend.pool(), best_number, prune_window).await {
+            Ok(pruned) => log::debug!("Pruned {} frontier SQL mappings", pruned),
+            Err(e) => log::warn!("Failed to prune frontier SQL mappings: {:?}", e),
+        }
+    }
+}
+
+/// Deletes the transaction and log mappings of blocks more than `prune_window` blocks behind
+/// `best_number`, returning the number of deleted rows.
+///
+/// Block rows are kept: the SQL sync worker looks for gaps in them to find blocks it still has to
+/// index, so deleting them would make it index the pruned blocks again.
+pub async fn prune_sql_mappings(
+    pool: &sqlx::SqlitePool,
+    best_number: u64,
+    prune_window: u64,
+) -> Result<u64, sqlx::Error> {
+    let Some(prune_below) = best_number.checked_sub(prune_window) else {
+        return Ok(0);
+    };
+    let prune_below = i64::try_from(prune_below).unwrap_or(i64::MAX);
+
+    let mut tx = pool.begin().await?;
+    let mut pruned: u64 = 0;
+    for table in ["logs", "transactions"] {
+        let result = sqlx::query(&format!(
+            "DELETE FROM {table} WHERE substrate_block_hash IN \
+             (SELECT substrate_block_hash FROM blocks WHERE block_number < ?)"
+        ))
+        .bind(prune_below)
+        .execute(&mut *tx)
+        .await?;
+        pruned = pruned.saturating_add(result.rows_affected());
+    }
+    tx.commit().await?;
+
+    Ok(pruned)
+}
+
 fn extend_rpc_aet_api<P, A, CT, CIDP, EC>(
     io: &mut RpcModule<()>,
     deps: &EthDeps<P, A, CT, CIDP>,
@@ -1,6 +1,7 @@
 pub mod chain_spec;
 pub mod cli;
 pub mod client;
+pub mod dev_rpc;
 pub mod ethereum;
 pub mod rpc;
 pub mod service;
@@ -9,6 +9,7 @@ mod benchmarking;
 mod cli;
 mod client;
 mod command;
+mod dev_rpc;
 mod ethereum;
 mod rpc;
 
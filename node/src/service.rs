@@ -8,7 +8,7 @@ use sc_client_api::{Backend as BackendT, BlockBackend};
 use sc_consensus::{
     BasicQueue, BlockCheckParams, BlockImport, BlockImportParams, BoxBlockImport, ImportResult,
 };
-use sc_consensus_grandpa::BlockNumberOps;
+use sc_consensus_grandpa::{AuthoritySetHardFork, BlockNumberOps};
 use sc_consensus_slots::BackoffAuthoringOnFinalizedHeadLagging;
 use sc_network_sync::strategy::warp::{WarpSyncConfig, WarpSyncProvider};
 use sc_service::{Configuration, PartialComponents, TaskManager, error::Error as ServiceError};
@@ -24,6 +24,7 @@ use std::{cell::RefCell, path::Path};
 use std::{marker::PhantomData, sync::Arc, time::Duration};
 use substrate_prometheus_endpoint::Registry;
 
+use crate::chain_spec::Extensions;
 use crate::cli::Sealing;
 use crate::client::{FullBackend, FullClient, HostFunctions, RuntimeExecutor};
 use crate::ethereum::{
@@ -345,6 +346,27 @@ pub fn build_manual_seal_import_queue(
     ))
 }
 
+/// Returns the GRANDPA authority set hard forks listed in the chain spec, for warp sync to sync
+/// past them. Chain specs without any yield none.
+pub fn authority_set_hard_forks(
+    chain_spec: &dyn sc_service::ChainSpec,
+) -> Vec<AuthoritySetHardFork<Block>> {
+    Extensions::try_get(chain_spec)
+        .map(|extensions| {
+            extensions
+                .authority_set_hard_forks
+                .iter()
+                .map(|hard_fork| AuthoritySetHardFork {
+                    set_id: hard_fork.set_id,
+                    block: (hard_fork.block_hash, hard_fork.block_number),
+                    authorities: hard_fork.authorities.clone(),
+                    last_finalized: hard_fork.last_finalized,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 /// Builds a new service for a full client.
 pub async fn new_full<NB>(
     mut config: Configuration,
@@ -406,7 +428,7 @@ where
             Arc::new(sc_consensus_grandpa::warp_proof::NetworkProvider::new(
                 backend.clone(),
                 grandpa_link.shared_authority_set().clone(),
-                Vec::new(),
+                authority_set_hard_forks(&*config.chain_spec),
             ));
         Some(WarpSyncConfig::WithProvider(warp_sync))
     };
@@ -601,6 +623,8 @@ where
         fee_history_cache_limit,
         sync_service.clone(),
         pubsub_notification_sinks,
+        eth_config.frontier_sql_backend_prune_window,
+        Duration::from_secs(eth_config.frontier_sql_backend_prune_interval),
     )
     .await;
 
@@ -827,16 +851,33 @@ fn run_manual_seal_authorship(
                 create_inherent_data_providers,
             },
         )),
-        Sealing::Instant => future::Either::Right(sc_consensus_manual_seal::run_instant_seal(
-            sc_consensus_manual_seal::InstantSealParams {
-                block_import,
-                env: proposer_factory,
-                client,
-                pool: transaction_pool,
-                select_chain,
-                consensus_data_provider: None,
-                create_inherent_data_providers,
-            },
+        Sealing::Instant => future::Either::Right(future::Either::Left(
+            sc_consensus_manual_seal::run_instant_seal(
+                sc_consensus_manual_seal::InstantSealParams {
+                    block_import,
+                    env: proposer_factory,
+                    client,
+                    pool: transaction_pool,
+                    select_chain,
+                    consensus_data_provider: None,
+                    create_inherent_data_providers,
+                },
+            ),
+        )),
+        // Finalizes every sealed block so the finalized head, which frontier's RPC relies on,
+        // follows the chain without running the GRANDPA voter.
+        Sealing::InstantFinalized => future::Either::Right(future::Either::Right(
+            sc_consensus_manual_seal::run_instant_seal_and_finalize(
+                sc_consensus_manual_seal::InstantSealParams {
+                    block_import,
+                    env: proposer_factory,
+                    client,
+                    pool: transaction_pool,
+                    select_chain,
+                    consensus_data_provider: None,
+                    create_inherent_data_providers,
+                },
+            ),
         )),
     };
 
@@ -1,8 +1,12 @@
-use sp_core::sr25519;
+#![allow(clippy::indexing_slicing, clippy::unwrap_used)]
+
+use sc_service::ChainType;
+use sp_core::{H256, sr25519};
 // use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 // use sp_consensus_grandpa::AuthorityId as GrandpaId;
 
 use node_subtensor::chain_spec::*;
+use node_subtensor::service::authority_set_hard_forks;
 
 #[test]
 fn test_get_from_seed() {
@@ -52,3 +56,53 @@ fn test_authority_keys_from_seed_panics() {
     let bad_seed = "";
     authority_keys_from_seed(bad_seed);
 }
+
+#[test]
+fn test_authority_set_hard_forks_from_chain_spec() {
+    let (_, grandpa_id) = authority_keys_from_seed("Alice");
+    let hard_fork = AuthoritySetHardForkSpec {
+        set_id: 3,
+        block_hash: H256::repeat_byte(1),
+        block_number: 100,
+        authorities: vec![(grandpa_id, 1)],
+        last_finalized: Some(90),
+    };
+    let chain_spec = ChainSpec::builder(
+        &[],
+        Extensions {
+            authority_set_hard_forks: vec![hard_fork.clone()],
+            ..Default::default()
+        },
+    )
+    .with_name("Test")
+    .with_id("test")
+    .with_chain_type(ChainType::Development)
+    .with_genesis_config_patch(serde_json::json!({}))
+    .build();
+
+    // The hard forks survive a round trip through the chain spec JSON.
+    let json = chain_spec.as_json(false).unwrap();
+    let chain_spec = ChainSpec::from_json_bytes(json.into_bytes()).unwrap();
+
+    let hard_forks = authority_set_hard_forks(&chain_spec);
+    assert_eq!(hard_forks.len(), 1);
+    assert_eq!(hard_forks[0].set_id, hard_fork.set_id);
+    assert_eq!(
+        hard_forks[0].block,
+        (hard_fork.block_hash, hard_fork.block_number)
+    );
+    assert_eq!(hard_forks[0].authorities, hard_fork.authorities);
+    assert_eq!(hard_forks[0].last_finalized, hard_fork.last_finalized);
+}
+
+#[test]
+fn test_authority_set_hard_forks_default_to_none() {
+    let chain_spec = ChainSpec::builder(&[], Extensions::default())
+        .with_name("Test")
+        .with_id("test")
+        .with_chain_type(ChainType::Development)
+        .with_genesis_config_patch(serde_json::json!({}))
+        .build();
+
+    assert!(authority_set_hard_forks(&chain_spec).is_empty());
+}
@@ -0,0 +1,146 @@
+#![allow(clippy::unwrap_used)]
+
+use std::sync::Arc;
+
+use fc_storage::StorageOverride;
+use fp_rpc::TransactionStatus;
+use node_subtensor::ethereum::prune_sql_mappings;
+use node_subtensor_runtime::opaque::Block;
+use sp_core::{H160, H256, U256};
+use sp_runtime::Permill;
+use sqlx::{Row, SqlitePool};
+
+/// The pruning only touches the SQL tables, so the backend never reads runtime storage.
+struct NoStorageOverride;
+
+impl StorageOverride<Block> for NoStorageOverride {
+    fn account_code_at(&self, _at: H256, _address: H160) -> Option<Vec<u8>> {
+        None
+    }
+    fn account_storage_at(&self, _at: H256, _address: H160, _index: U256) -> Option<H256> {
+        None
+    }
+    fn current_block(&self, _at: H256) -> Option<pallet_ethereum::Block> {
+        None
+    }
+    fn current_receipts(&self, _at: H256) -> Option<Vec<pallet_ethereum::Receipt>> {
+        None
+    }
+    fn current_transaction_statuses(&self, _at: H256) -> Option<Vec<TransactionStatus>> {
+        None
+    }
+    fn elasticity(&self, _at: H256) -> Option<Permill> {
+        None
+    }
+    fn is_eip1559(&self, _at: H256) -> bool {
+        false
+    }
+}
+
+/// Creates a frontier SQL backend, so the tables have the schema fc-db creates, and fills it with
+/// one block, transaction and log per block number.
+async fn setup_backend(name: &str, blocks: u64) -> fc_db::sql::Backend<Block> {
+    let path = std::env::temp_dir().join(format!(
+        "frontier_sql_pruning_{}_{}.db3",
+        name,
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&path);
+    let url = format!("sqlite://{}", path.display());
+    let backend = fc_db::sql::Backend::new(
+        fc_db::sql::BackendConfig::Sqlite(fc_db::sql::SqliteBackendConfig {
+            path: &url,
+            create_if_missing: true,
+            thread_count: 1,
+            cache_size: 1024,
+        }),
+        1,
+        None,
+        Arc::new(NoStorageOverride),
+    )
+    .await
+    .unwrap();
+
+    let pool = backend.pool();
+    for number in 0..blocks {
+        let substrate_hash = H256::from_low_u64_be(number).as_bytes().to_vec();
+        let ethereum_hash = H256::from_low_u64_be(number + 1_000).as_bytes().to_vec();
+        sqlx::query(
+            "INSERT INTO blocks (block_number, ethereum_block_hash, substrate_block_hash, \
+             ethereum_storage_schema, is_canon) VALUES (?, ?, ?, ?, 1)",
+        )
+        .bind(number as i64)
+        .bind(ethereum_hash.clone())
+        .bind(substrate_hash.clone())
+        .bind(Vec::<u8>::new())
+        .execute(pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO transactions (ethereum_transaction_hash, substrate_block_hash, \
+             ethereum_block_hash, ethereum_transaction_index) VALUES (?, ?, ?, 0)",
+        )
+        .bind(H256::from_low_u64_be(number + 2_000).as_bytes().to_vec())
+        .bind(substrate_hash.clone())
+        .bind(ethereum_hash)
+        .execute(pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO logs (address, log_index, transaction_index, substrate_block_hash) \
+             VALUES (?, 0, 0, ?)",
+        )
+        .bind(H160::zero().as_bytes().to_vec())
+        .bind(substrate_hash)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+    backend
+}
+
+async fn block_numbers(pool: &SqlitePool, table: &str) -> Vec<i64> {
+    sqlx::query(&format!(
+        "SELECT b.block_number FROM {table} t JOIN blocks b \
+         ON b.substrate_block_hash = t.substrate_block_hash ORDER BY b.block_number"
+    ))
+    .fetch_all(pool)
+    .await
+    .unwrap()
+    .iter()
+    .map(|row| row.get::<i64, _>(0))
+    .collect()
+}
+
+#[tokio::test]
+async fn test_prune_sql_mappings_removes_rows_outside_the_window() {
+    let backend = setup_backend("outside_window", 10).await;
+    let pool = backend.pool();
+
+    // Best block 9 with a window of 3 keeps the mappings of blocks 6 to 9.
+    let pruned = prune_sql_mappings(pool, 9, 3).await.unwrap();
+    assert_eq!(pruned, 12);
+
+    assert_eq!(block_numbers(pool, "transactions").await, vec![6, 7, 8, 9]);
+    assert_eq!(block_numbers(pool, "logs").await, vec![6, 7, 8, 9]);
+    // Block rows are kept so the sync worker doesn't index the pruned blocks again.
+    assert_eq!(
+        block_numbers(pool, "blocks").await,
+        (0..10).collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test]
+async fn test_prune_sql_mappings_keeps_everything_within_the_window() {
+    let backend = setup_backend("within_window", 5).await;
+    let pool = backend.pool();
+
+    assert_eq!(prune_sql_mappings(pool, 4, 10).await.unwrap(), 0);
+    assert_eq!(prune_sql_mappings(pool, 4, 4).await.unwrap(), 0);
+
+    assert_eq!(
+        block_numbers(pool, "transactions").await,
+        vec![0, 1, 2, 3, 4]
+    );
+    assert_eq!(block_numbers(pool, "logs").await, vec![0, 1, 2, 3, 4]);
+}
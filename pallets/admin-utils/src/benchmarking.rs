@@ -247,7 +247,7 @@ mod benchmarks {
         pallet_subtensor::Pallet::<T>::init_new_network(1u16 /*netuid*/, 1u16 /*tempo*/);
 
         #[extrinsic_call]
-		_(RawOrigin::Root, 1u16/*netuid*/, 1u16/*tempo*/)/*sudo_set_tempo*/;
+		_(RawOrigin::Root, 1u16/*netuid*/, 100u16/*tempo*/)/*sudo_set_tempo*/;
     }
 
     #[benchmark]
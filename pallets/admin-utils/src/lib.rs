@@ -94,6 +94,22 @@ pub mod pallet {
         MaxValidatorsLargerThanMaxUIds,
         /// The maximum number of subnet validators must be more than the current number of UIDs already in the subnet.
         MaxAllowedUIdsLessThanCurrentUIds,
+        /// The difficulty adjustment algorithm is unknown.
+        InvalidDifficultyAlgo,
+        /// The tempo is below the minimum tempo.
+        TempoBelowMinimum,
+        /// The subnet owner cut is outside of the owner cut bounds.
+        OwnerCutOutOfBounds,
+        /// The staking fee volume tiers are unsorted, too many, or raise the fee.
+        InvalidStakingFeeVolumeTiers,
+        /// The maximum allowed UIDs can't be 0, as the subnet owner hotkey is never deregistered.
+        MaxAllowedUidsIsZero,
+        /// The epoch offset is larger than the subnet's tempo.
+        EpochOffsetOutOfBounds,
+        /// The maximum weight age is shorter than one epoch of the subnet.
+        MaxWeightAgeTooLow,
+        /// The minimum owner cut is above the maximum owner cut.
+        InvalidOwnerCutBounds,
     }
     /// Enum for specifying the type of precompile operation.
     #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug, Copy)]
@@ -108,6 +124,8 @@ pub mod pallet {
         Metagraph,
         /// Enum for neuron precompile
         Neuron,
+        /// Enum for governance precompile
+        Governance,
     }
 
     #[pallet::type_value]
@@ -452,8 +470,16 @@ pub mod pallet {
         /// The extrinsic sets the maximum allowed UIDs for a subnet.
         /// It is only callable by the root account.
         /// The extrinsic will call the Subtensor pallet to set the maximum allowed UIDs for a subnet.
+        /// Lowering it below the current number of UIDs deregisters the excess neurons in pruning
+        /// order and compacts the remaining UIDs, which is refused while weight commits are pending.
+        /// Their stake is recycled afterwards through `recycle_deregistered_stake`.
+        /// It can't be set to 0, since the subnet owner hotkey is never deregistered.
         #[pallet::call_index(15)]
-        #[pallet::weight(<T as Config>::WeightInfo::sudo_set_max_allowed_uids())]
+        #[pallet::weight(<T as Config>::WeightInfo::sudo_set_max_allowed_uids()
+            .saturating_add(T::DbWeight::get().reads_writes(
+                u64::from(pallet_subtensor::SubnetworkN::<T>::get(netuid)).saturating_mul(7).saturating_add(2),
+                u64::from(pallet_subtensor::SubnetworkN::<T>::get(netuid)).saturating_mul(8),
+            )))]
         pub fn sudo_set_max_allowed_uids(
             origin: OriginFor<T>,
             netuid: u16,
@@ -464,15 +490,15 @@ pub mod pallet {
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
-            ensure!(
-                pallet_subtensor::Pallet::<T>::get_subnetwork_n(netuid) < max_allowed_uids,
-                Error::<T>::MaxAllowedUIdsLessThanCurrentUIds
-            );
+            ensure!(max_allowed_uids > 0, Error::<T>::MaxAllowedUidsIsZero);
+            let trimmed =
+                pallet_subtensor::Pallet::<T>::trim_to_max_allowed_uids(netuid, max_allowed_uids)?;
             pallet_subtensor::Pallet::<T>::set_max_allowed_uids(netuid, max_allowed_uids);
             log::debug!(
-                "MaxAllowedUidsSet( netuid: {:?} max_allowed_uids: {:?} ) ",
+                "MaxAllowedUidsSet( netuid: {:?} max_allowed_uids: {:?} trimmed: {:?} ) ",
                 netuid,
-                max_allowed_uids
+                max_allowed_uids,
+                trimmed
             );
             Ok(())
         }
@@ -827,6 +853,11 @@ pub mod pallet {
             subnet_owner_cut: u16,
         ) -> DispatchResult {
             ensure_root(origin)?;
+            ensure!(
+                subnet_owner_cut >= pallet_subtensor::Pallet::<T>::get_min_owner_cut()
+                    && subnet_owner_cut <= pallet_subtensor::Pallet::<T>::get_max_owner_cut(),
+                Error::<T>::OwnerCutOutOfBounds
+            );
             pallet_subtensor::Pallet::<T>::set_subnet_owner_cut(subnet_owner_cut);
             log::debug!(
                 "SubnetOwnerCut( subnet_owner_cut: {:?} ) ",
@@ -859,13 +890,18 @@ pub mod pallet {
         /// It is only callable by the root account.
         /// The extrinsic will call the Subtensor pallet to set the tempo.
         #[pallet::call_index(30)]
-        #[pallet::weight(<T as Config>::WeightInfo::sudo_set_tempo())]
+        #[pallet::weight(<T as Config>::WeightInfo::sudo_set_tempo()
+            .saturating_add(T::DbWeight::get().reads_writes(1, 1)))]
         pub fn sudo_set_tempo(origin: OriginFor<T>, netuid: u16, tempo: u16) -> DispatchResult {
             ensure_root(origin)?;
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
+            ensure!(
+                pallet_subtensor::Pallet::<T>::is_tempo_allowed(tempo),
+                Error::<T>::TempoBelowMinimum
+            );
             pallet_subtensor::Pallet::<T>::set_tempo(netuid, tempo);
             log::debug!("TempoSet( netuid: {:?} tempo: {:?} ) ", netuid, tempo);
             Ok(())
@@ -1476,6 +1512,499 @@ pub mod pallet {
             );
             Ok(())
         }
+
+        /// Sets the minimum stake a non-senate proposer needs to submit a governance proposal.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `min_stake` - The minimum total stake, in RAO, held by the proposer as a hotkey.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        ///
+        /// # Weight
+        /// Weight is handled by the `#[pallet::weight]` attribute.
+        #[pallet::call_index(66)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_proposal_min_stake(origin: OriginFor<T>, min_stake: u64) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_proposal_min_stake(min_stake);
+
+            log::debug!("ProposalMinStakeSet( min_stake: {:?} )", min_stake);
+            Ok(())
+        }
+
+        /// The extrinsic sets the difficulty adjustment algorithm for a subnet.
+        /// It is only callable by the root account or subnet owner.
+        /// The extrinsic will call the Subtensor pallet to set the difficulty adjustment algorithm.
+        #[pallet::call_index(67)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_difficulty_algo(
+            origin: OriginFor<T>,
+            netuid: u16,
+            difficulty_algo: u8,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                difficulty_algo == pallet_subtensor::DIFFICULTY_ALGO_LINEAR
+                    || difficulty_algo == pallet_subtensor::DIFFICULTY_ALGO_EXPONENTIAL,
+                Error::<T>::InvalidDifficultyAlgo
+            );
+            pallet_subtensor::Pallet::<T>::set_difficulty_algo(netuid, difficulty_algo);
+            log::debug!(
+                "DifficultyAlgoSet( netuid: {:?} difficulty_algo: {:?} ) ",
+                netuid,
+                difficulty_algo
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the minimum tempo a subnet can be set to.
+        /// It is only callable by the root account.
+        /// The extrinsic will call the Subtensor pallet to set the minimum tempo.
+        #[pallet::call_index(69)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_min_tempo(origin: OriginFor<T>, min_tempo: u16) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_min_tempo(min_tempo);
+            log::debug!("MinTempoSet( min_tempo: {:?} ) ", min_tempo);
+            Ok(())
+        }
+
+        /// The extrinsic sets the minimum subnet owner cut.
+        /// It is only callable by the root account.
+        /// The extrinsic will call the Subtensor pallet to set the minimum owner cut.
+        /// A current owner cut below the new minimum is raised to it.
+        #[pallet::call_index(70)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_min_owner_cut(origin: OriginFor<T>, min_owner_cut: u16) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                min_owner_cut <= pallet_subtensor::Pallet::<T>::get_max_owner_cut(),
+                Error::<T>::InvalidOwnerCutBounds
+            );
+            pallet_subtensor::Pallet::<T>::set_min_owner_cut(min_owner_cut);
+            if pallet_subtensor::Pallet::<T>::get_subnet_owner_cut() < min_owner_cut {
+                pallet_subtensor::Pallet::<T>::set_subnet_owner_cut(min_owner_cut);
+            }
+            log::debug!("MinOwnerCutSet( min_owner_cut: {:?} ) ", min_owner_cut);
+            Ok(())
+        }
+
+        /// The extrinsic sets the maximum subnet owner cut.
+        /// It is only callable by the root account.
+        /// The extrinsic will call the Subtensor pallet to set the maximum owner cut.
+        /// A current owner cut above the new maximum is lowered to it.
+        #[pallet::call_index(71)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_owner_cut(origin: OriginFor<T>, max_owner_cut: u16) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                max_owner_cut >= pallet_subtensor::Pallet::<T>::get_min_owner_cut(),
+                Error::<T>::InvalidOwnerCutBounds
+            );
+            pallet_subtensor::Pallet::<T>::set_max_owner_cut(max_owner_cut);
+            if pallet_subtensor::Pallet::<T>::get_subnet_owner_cut() > max_owner_cut {
+                pallet_subtensor::Pallet::<T>::set_subnet_owner_cut(max_owner_cut);
+            }
+            log::debug!("MaxOwnerCutSet( max_owner_cut: {:?} ) ", max_owner_cut);
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks a subnet's drained emission is paid out over.
+        /// It is only callable by the root account.
+        /// The extrinsic will call the Subtensor pallet to set the emission smoothing blocks.
+        #[pallet::call_index(72)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(1))
+                .saturating_add(T::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_emission_smoothing_blocks(
+            origin: OriginFor<T>,
+            netuid: u16,
+            smoothing_blocks: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_emission_smoothing_blocks(netuid, smoothing_blocks);
+            log::debug!(
+                "EmissionSmoothingBlocksSet( netuid: {:?} smoothing_blocks: {:?} ) ",
+                netuid,
+                smoothing_blocks
+            );
+            Ok(())
+        }
+
+        /// The extrinsic overwrites the cumulative TAO volume of a subnet.
+        /// It is only callable by the root account.
+        /// Volume is accumulated on every swap and no trade history is kept on chain, so a
+        /// drifted accumulator is repaired with a value recomputed off chain from the swap events.
+        #[pallet::call_index(73)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads(1))
+                .saturating_add(T::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_subnet_volume(
+            origin: OriginFor<T>,
+            netuid: u16,
+            volume: u128,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_subnet_volume(netuid, volume);
+            log::debug!(
+                "SubnetVolumeSet( netuid: {:?} volume: {:?} ) ",
+                netuid,
+                volume
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the volume based staking fee tiers.
+        /// It is only callable by the root account.
+        /// Each tier is a (volume threshold, staking fee) pair. A subnet whose cumulative volume
+        /// reached a threshold pays that tier's fee instead of the default staking fee. Thresholds
+        /// must be strictly increasing and fees must never go up. An empty list keeps the fee flat.
+        #[pallet::call_index(74)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(T::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_staking_fee_volume_tiers(
+            origin: OriginFor<T>,
+            tiers: Vec<(u128, u64)>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::are_staking_fee_volume_tiers_valid(&tiers),
+                Error::<T>::InvalidStakingFeeVolumeTiers
+            );
+            log::debug!("StakingFeeVolumeTiersSet( tiers: {:?} ) ", tiers);
+            pallet_subtensor::Pallet::<T>::set_staking_fee_volume_tiers(tiers);
+            Ok(())
+        }
+
+        /// The extrinsic sets the maximum total issuance.
+        /// It is only callable by the root account.
+        /// The coinbase stops emitting TAO once the total issuance reaches this value.
+        #[pallet::call_index(75)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_total_issuance(
+            origin: OriginFor<T>,
+            max_total_issuance: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_max_total_issuance(max_total_issuance);
+            log::debug!(
+                "MaxTotalIssuanceSet( max_total_issuance: {:?} ) ",
+                max_total_issuance
+            );
+            Ok(())
+        }
+
+        /// Sets whether ties for the lowest pruning score on a subnet evict the most recently
+        /// registered uid instead of the earliest registered one.
+        ///
+        /// # Parameters
+        /// - `origin`: The origin of the call, which must be the root account or subnet owner.
+        /// - `netuid`: The unique identifier for the subnet.
+        /// - `enabled`: Whether the most recently registered uid is pruned on ties.
+        #[pallet::call_index(76)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_prune_newest_on_tie(
+            origin: OriginFor<T>,
+            netuid: u16,
+            enabled: bool,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_prune_newest_on_tie(netuid, enabled);
+            log::debug!(
+                "PruneNewestOnTieSet( netuid: {:?}, enabled: {:?} ) ",
+                netuid,
+                enabled
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the minimum amount of TAO a single stake or unstake may move.
+        /// It is only callable by the root account.
+        /// Unstakes are measured by the TAO their alpha would swap for.
+        #[pallet::call_index(77)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_min_stake_amount(
+            origin: OriginFor<T>,
+            min_stake_amount: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_min_stake_amount(min_stake_amount);
+            log::debug!(
+                "MinStakeAmountSet( min_stake_amount: {:?} ) ",
+                min_stake_amount
+            );
+            Ok(())
+        }
+
+        /// Turns the stake whitelist of a subnet on or off.
+        ///
+        /// While it is on, only coldkeys added with `sudo_set_stake_allowed_coldkey` can add or
+        /// move stake into the subnet.
+        ///
+        /// # Parameters
+        /// - `origin`: The origin of the call, which must be the root account or subnet owner.
+        /// - `netuid`: The unique identifier for the subnet.
+        /// - `enabled`: Whether the stake whitelist is on.
+        #[pallet::call_index(78)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_stake_whitelist(
+            origin: OriginFor<T>,
+            netuid: u16,
+            enabled: bool,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_stake_whitelist(netuid, enabled);
+            log::debug!(
+                "StakeWhitelistSet( netuid: {:?}, enabled: {:?} ) ",
+                netuid,
+                enabled
+            );
+            Ok(())
+        }
+
+        /// Adds a coldkey to or removes it from the stake whitelist of a subnet.
+        ///
+        /// # Parameters
+        /// - `origin`: The origin of the call, which must be the root account or subnet owner.
+        /// - `netuid`: The unique identifier for the subnet.
+        /// - `coldkey`: The coldkey to add or remove.
+        /// - `allowed`: Whether the coldkey may stake while the whitelist is on.
+        #[pallet::call_index(79)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_stake_allowed_coldkey(
+            origin: OriginFor<T>,
+            netuid: u16,
+            coldkey: T::AccountId,
+            allowed: bool,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            log::debug!(
+                "StakeWhitelistUpdated( netuid: {:?}, coldkey: {:?}, allowed: {:?} ) ",
+                netuid,
+                coldkey,
+                allowed
+            );
+            pallet_subtensor::Pallet::<T>::set_stake_allowed_coldkey(netuid, coldkey, allowed);
+            Ok(())
+        }
+
+        /// Sets the maximum age, in blocks, of weights that are used in consensus for a subnet.
+        ///
+        /// Weights set longer ago than this are left out of the epoch until the validator sets
+        /// them again. Unlike the activity cutoff, this doesn't mark the validator inactive. It
+        /// must cover at least one full epoch of the subnet, `tempo + 1` blocks, so weights set
+        /// during the last epoch are never stale.
+        ///
+        /// # Parameters
+        /// - `origin`: The origin of the call, which must be the root account or subnet owner.
+        /// - `netuid`: The unique identifier for the subnet.
+        /// - `max_weight_age`: The maximum weight age in blocks.
+        #[pallet::call_index(80)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_weight_age(
+            origin: OriginFor<T>,
+            netuid: u16,
+            max_weight_age: u64,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                max_weight_age > u64::from(pallet_subtensor::Pallet::<T>::get_tempo(netuid)),
+                Error::<T>::MaxWeightAgeTooLow
+            );
+            pallet_subtensor::Pallet::<T>::set_max_weight_age(netuid, max_weight_age);
+            log::debug!(
+                "MaxWeightAgeSet( netuid: {:?}, max_weight_age: {:?} ) ",
+                netuid,
+                max_weight_age
+            );
+            Ok(())
+        }
+
+        /// Sets the number of blocks a subnet's epoch boundary is shifted by.
+        ///
+        /// Shifting the boundaries of subnets that share a tempo staggers their epochs over
+        /// different blocks. It is only callable by the root account, since it moves the
+        /// commit-reveal epochs of the subnet as well. The offset must be at most the tempo, and it
+        /// can't be changed while the subnet has weight commits waiting to be revealed.
+        ///
+        /// # Parameters
+        /// - `origin`: The origin of the call, which must be the root account.
+        /// - `netuid`: The unique identifier for the subnet.
+        /// - `epoch_offset`: The offset in blocks.
+        #[pallet::call_index(81)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_epoch_offset(
+            origin: OriginFor<T>,
+            netuid: u16,
+            epoch_offset: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                epoch_offset <= u64::from(pallet_subtensor::Pallet::<T>::get_tempo(netuid)),
+                Error::<T>::EpochOffsetOutOfBounds
+            );
+            ensure!(
+                !pallet_subtensor::Pallet::<T>::has_pending_weight_commits(netuid),
+                pallet_subtensor::Error::<T>::PendingWeightCommits
+            );
+            pallet_subtensor::Pallet::<T>::set_epoch_offset(netuid, epoch_offset);
+            log::debug!(
+                "EpochOffsetSet( netuid: {:?}, epoch_offset: {:?} ) ",
+                netuid,
+                epoch_offset
+            );
+            Ok(())
+        }
+
+        /// Sets the share of a subnet's emission paid to validators as dividends, the rest going
+        /// to miners as incentive.
+        ///
+        /// The ratio is a fraction of `u16::MAX`, so `0` pays all emission to miners and
+        /// `u16::MAX` pays all of it to validators. Subnets without a ratio weigh incentive and
+        /// dividends the same.
+        ///
+        /// # Parameters
+        /// - `origin`: The origin of the call, which must be the root account or subnet owner.
+        /// - `netuid`: The unique identifier for the subnet.
+        /// - `ratio`: The validator share of the emission.
+        #[pallet::call_index(82)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_validator_emission_ratio(
+            origin: OriginFor<T>,
+            netuid: u16,
+            ratio: u16,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_validator_emission_ratio(netuid, ratio);
+            log::debug!(
+                "ValidatorEmissionRatioSet( netuid: {:?}, ratio: {:?} ) ",
+                netuid,
+                ratio
+            );
+            Ok(())
+        }
+
+        /// Sets the maximum number of registrations allowed in a single block across all subnets.
+        ///
+        /// The cap applies on top of each subnet's own max registrations per block. It is only
+        /// callable by the root account.
+        ///
+        /// # Parameters
+        /// - `origin`: The origin of the call, which must be the root account.
+        /// - `max_total_registrations_per_block`: The chain-wide registration limit per block.
+        #[pallet::call_index(83)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_total_registrations_per_block(
+            origin: OriginFor<T>,
+            max_total_registrations_per_block: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_max_total_registrations_per_block(
+                max_total_registrations_per_block,
+            );
+            log::debug!(
+                "MaxTotalRegistrationsPerBlockSet( max_total_registrations_per_block: {:?} ) ",
+                max_total_registrations_per_block
+            );
+            Ok(())
+        }
+
+        /// Sets the minimum TAO emission each subnet receives per block.
+        ///
+        /// Subnets whose share of the block emission falls below the floor are lifted to it, with
+        /// the difference taken from the larger subnets. It is only callable by the root account.
+        ///
+        /// # Parameters
+        /// - `origin`: The origin of the call, which must be the root account.
+        /// - `min_subnet_emission`: The per-block TAO floor in RAO, or 0 to disable it.
+        #[pallet::call_index(84)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_min_subnet_emission(
+            origin: OriginFor<T>,
+            min_subnet_emission: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_min_subnet_emission(min_subnet_emission);
+            log::debug!(
+                "MinSubnetEmissionSet( min_subnet_emission: {:?} ) ",
+                min_subnet_emission
+            );
+            Ok(())
+        }
+
+        /// Sets the maximum TAO emission a single subnet receives per block.
+        ///
+        /// The excess of subnets above the cap is redistributed to the others in proportion to
+        /// their share. It is only callable by the root account.
+        ///
+        /// # Parameters
+        /// - `origin`: The origin of the call, which must be the root account.
+        /// - `max_subnet_emission`: The per-block TAO cap in RAO, or `u64::MAX` to disable it.
+        #[pallet::call_index(85)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_subnet_emission(
+            origin: OriginFor<T>,
+            max_subnet_emission: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_max_subnet_emission(max_subnet_emission);
+            log::debug!(
+                "MaxSubnetEmissionSet( max_subnet_emission: {:?} ) ",
+                max_subnet_emission
+            );
+            Ok(())
+        }
     }
 }
 
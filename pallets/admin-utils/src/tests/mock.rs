@@ -82,6 +82,7 @@ parameter_types! {
     pub const InitialRho: u16 = 30;
     pub const InitialKappa: u16 = 32_767;
     pub const InitialTempo: u16 = 0;
+    pub const InitialMinTempo: u16 = 10;
     pub const SelfOwnership: u64 = 2;
     pub const InitialImmunityPeriod: u16 = 2;
     pub const InitialMaxAllowedUids: u16 = 2;
@@ -151,6 +152,7 @@ impl pallet_subtensor::Config for Test {
     type InitialEmissionValue = InitialEmissionValue;
     type InitialMaxWeightsLimit = InitialMaxWeightsLimit;
     type InitialTempo = InitialTempo;
+    type InitialMinTempo = InitialMinTempo;
     type InitialDifficulty = InitialDifficulty;
     type InitialAdjustmentInterval = InitialAdjustmentInterval;
     type InitialAdjustmentAlpha = InitialAdjustmentAlpha;
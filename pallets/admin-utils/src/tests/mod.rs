@@ -2,10 +2,12 @@ use frame_support::sp_runtime::DispatchError;
 use frame_support::{
     assert_err, assert_noop, assert_ok,
     dispatch::{DispatchClass, GetDispatchInfo, Pays},
-    traits::Hooks,
+    traits::{Get, Hooks},
 };
 use frame_system::Config;
-use pallet_subtensor::{Error as SubtensorError, SubnetOwner, Tempo, WeightsVersionKeyRateLimit};
+use pallet_subtensor::{
+    Error as SubtensorError, SubnetOwner, SubnetVolume, Tempo, WeightsVersionKeyRateLimit,
+};
 // use pallet_subtensor::{migrations, Event};
 use pallet_subtensor::Event;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
@@ -577,6 +579,220 @@ fn test_sudo_set_and_decrease_max_allowed_uids() {
     });
 }
 
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_max_allowed_uids_trims_excess_neurons --exact --show-output
+#[test]
+fn test_sudo_set_max_allowed_uids_trims_excess_neurons() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        SubtensorModule::set_max_allowed_uids(netuid, 10);
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+
+        // Fill the subnet, the odd uids get the lowest pruning scores.
+        let pruning_scores: [u16; 10] = [5, 0, 6, 1, 7, 2, 8, 3, 9, 4];
+        for uid in 0..10u16 {
+            register_ok_neuron(
+                netuid,
+                U256::from(100 + uid),
+                U256::from(200 + uid),
+                u64::from(uid) * 10_000,
+            );
+        }
+        for (uid, score) in pruning_scores.iter().enumerate() {
+            SubtensorModule::set_pruning_score_for_uid(netuid, uid as u16, *score);
+        }
+        pallet_subtensor::Weights::<Test>::insert(netuid, 0, vec![(2, 100), (3, 200)]);
+        pallet_subtensor::Weights::<Test>::insert(netuid, 8, vec![(0, 300)]);
+
+        assert_ok!(AdminUtils::sudo_set_max_allowed_uids(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            5
+        ));
+        assert_eq!(SubtensorModule::get_max_allowed_uids(netuid), 5);
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 5);
+
+        // Exactly the five lowest scoring neurons are gone, the rest keep their order.
+        for uid in [1u16, 3, 5, 7, 9] {
+            assert!(!SubtensorModule::is_hotkey_registered_on_network(
+                netuid,
+                &U256::from(100 + uid)
+            ));
+        }
+        for (new_uid, old_uid) in [0u16, 2, 4, 6, 8].into_iter().enumerate() {
+            let hotkey = U256::from(100 + old_uid);
+            assert_eq!(
+                SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey),
+                Ok(new_uid as u16)
+            );
+            assert_eq!(
+                SubtensorModule::get_hotkey_for_net_and_uid(netuid, new_uid as u16),
+                Ok(hotkey)
+            );
+        }
+        assert!(!SubtensorModule::is_uid_exist_on_network(netuid, 5));
+        assert_eq!(
+            pallet_subtensor::PruningScores::<Test>::get(netuid),
+            vec![5, 6, 7, 8, 9]
+        );
+
+        // Weights follow their neurons and drop removed targets.
+        assert_eq!(
+            pallet_subtensor::Weights::<Test>::get(netuid, 0),
+            vec![(1, 100)]
+        );
+        assert_eq!(
+            pallet_subtensor::Weights::<Test>::get(netuid, 4),
+            vec![(0, 300)]
+        );
+        assert!(pallet_subtensor::Weights::<Test>::get(netuid, 8).is_empty());
+
+        // Every per-uid vector is as long as the subnet.
+        assert_eq!(pallet_subtensor::Rank::<Test>::get(netuid).len(), 5);
+        assert_eq!(pallet_subtensor::Trust::<Test>::get(netuid).len(), 5);
+        assert_eq!(pallet_subtensor::Active::<Test>::get(netuid).len(), 5);
+        assert_eq!(pallet_subtensor::Emission::<Test>::get(netuid).len(), 5);
+        assert_eq!(pallet_subtensor::Consensus::<Test>::get(netuid).len(), 5);
+        assert_eq!(pallet_subtensor::Incentive::<Test>::get(netuid).len(), 5);
+        assert_eq!(pallet_subtensor::Dividends::<Test>::get(netuid).len(), 5);
+        assert_eq!(pallet_subtensor::LastUpdate::<Test>::get(netuid).len(), 5);
+        assert_eq!(
+            pallet_subtensor::ValidatorTrust::<Test>::get(netuid).len(),
+            5
+        );
+        assert_eq!(
+            pallet_subtensor::ValidatorPermit::<Test>::get(netuid).len(),
+            5
+        );
+    });
+}
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_max_allowed_uids_rejects_trim_with_pending_commits --exact --show-output
+#[test]
+fn test_sudo_set_max_allowed_uids_rejects_trim_with_pending_commits() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        SubtensorModule::set_max_allowed_uids(netuid, 4);
+        SubtensorModule::set_max_registrations_per_block(netuid, 4);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 4);
+        for uid in 0..4u16 {
+            register_ok_neuron(netuid, U256::from(100 + uid), U256::from(200 + uid), 0);
+        }
+        pallet_subtensor::WeightCommits::<Test>::insert(
+            netuid,
+            U256::from(100),
+            std::collections::VecDeque::from(vec![(sp_core::H256::zero(), 0, 1, 2)]),
+        );
+
+        // The commit hashes the current uids, so they can't be renumbered under it.
+        assert_noop!(
+            AdminUtils::sudo_set_max_allowed_uids(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                2
+            ),
+            SubtensorError::<Test>::PendingWeightCommits
+        );
+
+        // Raising the limit doesn't trim, so it is still allowed.
+        assert_ok!(AdminUtils::sudo_set_max_allowed_uids(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            8
+        ));
+
+        pallet_subtensor::WeightCommits::<Test>::remove(netuid, U256::from(100));
+        assert_ok!(AdminUtils::sudo_set_max_allowed_uids(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            2
+        ));
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 2);
+    });
+}
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_max_allowed_uids_large_trim_defers_recycling --exact --show-output
+#[test]
+fn test_sudo_set_max_allowed_uids_large_trim_defers_recycling() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let nominator = U256::from(5000);
+        add_network(netuid, 10);
+        SubtensorModule::set_max_allowed_uids(netuid, 256);
+        for uid in 0..256u16 {
+            SubtensorModule::append_neuron(netuid, &U256::from(1000 + uid), 0);
+            SubtensorModule::set_pruning_score_for_uid(netuid, uid, uid);
+        }
+        let staked_hotkey = U256::from(1000);
+        let unstaked_hotkey = U256::from(1001);
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &staked_hotkey,
+            &nominator,
+            netuid,
+            1_000_000,
+        );
+
+        // The weight doesn't grow with the stake of the removed neurons.
+        let call = RuntimeCall::AdminUtils(crate::Call::sudo_set_max_allowed_uids {
+            netuid,
+            max_allowed_uids: 64,
+        });
+        assert!(
+            call.get_dispatch_info().weight.ref_time()
+                < pallet_subtensor::Pallet::<Test>::recycle_nominations_weight().ref_time()
+        );
+
+        assert_ok!(AdminUtils::sudo_set_max_allowed_uids(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            64
+        ));
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 64);
+        assert!(!SubtensorModule::is_hotkey_registered_on_network(
+            netuid,
+            &staked_hotkey
+        ));
+
+        // The stake stays on the removed hotkey until it is recycled.
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &staked_hotkey,
+                &nominator,
+                netuid
+            ),
+            1_000_000
+        );
+        assert!(pallet_subtensor::PrunedHotkeys::<Test>::contains_key(
+            netuid,
+            staked_hotkey
+        ));
+        assert!(!pallet_subtensor::PrunedHotkeys::<Test>::contains_key(
+            netuid,
+            unstaked_hotkey
+        ));
+    });
+}
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_max_allowed_uids_rejects_zero --exact --show-output
+#[test]
+fn test_sudo_set_max_allowed_uids_rejects_zero() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+
+        assert_noop!(
+            AdminUtils::sudo_set_max_allowed_uids(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                0
+            ),
+            Error::<Test>::MaxAllowedUidsIsZero
+        );
+    });
+}
+
 #[test]
 fn test_sudo_set_kappa() {
     new_test_ext().execute_with(|| {
@@ -1711,3 +1927,803 @@ fn test_sudo_set_ema_halving() {
         assert_eq!(value_after_2, to_be_set);
     });
 }
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_proposal_min_stake --exact --show-output
+#[test]
+fn test_sudo_set_proposal_min_stake() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 1_000_000_000;
+
+        let value_before: u64 = SubtensorModule::get_proposal_min_stake();
+        assert_eq!(
+            AdminUtils::sudo_set_proposal_min_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_proposal_min_stake(), value_before);
+
+        assert_ok!(AdminUtils::sudo_set_proposal_min_stake(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_proposal_min_stake(), to_be_set);
+    });
+}
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_difficulty_algo --exact --show-output
+#[test]
+fn test_sudo_set_difficulty_algo() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let to_be_set: u8 = pallet_subtensor::DIFFICULTY_ALGO_EXPONENTIAL;
+        add_network(netuid, 10);
+        let init_value: u8 = SubtensorModule::get_difficulty_algo(netuid);
+        assert_eq!(init_value, pallet_subtensor::DIFFICULTY_ALGO_LINEAR);
+        assert_eq!(
+            AdminUtils::sudo_set_difficulty_algo(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_difficulty_algo(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                to_be_set
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_difficulty_algo(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                u8::MAX
+            ),
+            Err(Error::<Test>::InvalidDifficultyAlgo.into())
+        );
+        assert_eq!(SubtensorModule::get_difficulty_algo(netuid), init_value);
+        assert_ok!(AdminUtils::sudo_set_difficulty_algo(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_difficulty_algo(netuid), to_be_set);
+    });
+}
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_min_tempo --exact --show-output
+#[test]
+fn test_sudo_set_min_tempo() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u16 = 20;
+
+        let value_before: u16 = SubtensorModule::get_min_tempo();
+        assert_eq!(
+            AdminUtils::sudo_set_min_tempo(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_min_tempo(), value_before);
+
+        assert_ok!(AdminUtils::sudo_set_min_tempo(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_min_tempo(), to_be_set);
+    });
+}
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_tempo_respects_min_tempo --exact --show-output
+#[test]
+fn test_sudo_set_tempo_respects_min_tempo() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let min_tempo: u16 = 20;
+        add_network(netuid, 100);
+
+        // The floor applies from genesis.
+        assert_eq!(SubtensorModule::get_min_tempo(), InitialMinTempo::get());
+        assert_eq!(
+            AdminUtils::sudo_set_tempo(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                SubtensorModule::get_min_tempo() - 1
+            ),
+            Err(Error::<Test>::TempoBelowMinimum.into())
+        );
+
+        // Tempo 0, which never runs epochs, can still be set.
+        assert_ok!(AdminUtils::sudo_set_tempo(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            0
+        ));
+        assert_eq!(SubtensorModule::get_tempo(netuid), 0);
+        assert_ok!(AdminUtils::sudo_set_tempo(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            100
+        ));
+
+        assert_ok!(AdminUtils::sudo_set_min_tempo(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            min_tempo
+        ));
+
+        assert_eq!(
+            AdminUtils::sudo_set_tempo(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                min_tempo - 1
+            ),
+            Err(Error::<Test>::TempoBelowMinimum.into())
+        );
+        assert_eq!(SubtensorModule::get_tempo(netuid), 100);
+
+        assert_ok!(AdminUtils::sudo_set_tempo(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            min_tempo
+        ));
+        assert_eq!(SubtensorModule::get_tempo(netuid), min_tempo);
+    });
+}
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_subnet_owner_cut_respects_min_owner_cut --exact --show-output
+#[test]
+fn test_sudo_set_subnet_owner_cut_respects_min_owner_cut() {
+    new_test_ext().execute_with(|| {
+        let min_owner_cut: u16 = 1_000;
+        assert_eq!(
+            AdminUtils::sudo_set_min_owner_cut(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                min_owner_cut
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_min_owner_cut(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            min_owner_cut
+        ));
+        assert_eq!(SubtensorModule::get_min_owner_cut(), min_owner_cut);
+
+        let value_before: u16 = SubtensorModule::get_subnet_owner_cut();
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_owner_cut(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                min_owner_cut - 1
+            ),
+            Err(Error::<Test>::OwnerCutOutOfBounds.into())
+        );
+        assert_eq!(SubtensorModule::get_subnet_owner_cut(), value_before);
+
+        assert_ok!(AdminUtils::sudo_set_subnet_owner_cut(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            min_owner_cut
+        ));
+        assert_eq!(SubtensorModule::get_subnet_owner_cut(), min_owner_cut);
+    });
+}
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_subnet_owner_cut_respects_max_owner_cut --exact --show-output
+#[test]
+fn test_sudo_set_subnet_owner_cut_respects_max_owner_cut() {
+    new_test_ext().execute_with(|| {
+        let max_owner_cut: u16 = 20_000;
+        assert_eq!(
+            AdminUtils::sudo_set_max_owner_cut(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                max_owner_cut
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_max_owner_cut(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            max_owner_cut
+        ));
+        assert_eq!(SubtensorModule::get_max_owner_cut(), max_owner_cut);
+
+        // Owners can no longer be given the whole emission.
+        let value_before: u16 = SubtensorModule::get_subnet_owner_cut();
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_owner_cut(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                u16::MAX
+            ),
+            Err(Error::<Test>::OwnerCutOutOfBounds.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_owner_cut(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                max_owner_cut + 1
+            ),
+            Err(Error::<Test>::OwnerCutOutOfBounds.into())
+        );
+        assert_eq!(SubtensorModule::get_subnet_owner_cut(), value_before);
+
+        assert_ok!(AdminUtils::sudo_set_subnet_owner_cut(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            max_owner_cut
+        ));
+        assert_eq!(SubtensorModule::get_subnet_owner_cut(), max_owner_cut);
+    });
+}
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_owner_cut_bounds_stay_ordered_and_clamp --exact --show-output
+#[test]
+fn test_sudo_set_owner_cut_bounds_stay_ordered_and_clamp() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(AdminUtils::sudo_set_subnet_owner_cut(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            10_000
+        ));
+        assert_ok!(AdminUtils::sudo_set_max_owner_cut(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            20_000
+        ));
+
+        // The minimum can't cross the maximum, and vice versa.
+        assert_noop!(
+            AdminUtils::sudo_set_min_owner_cut(<<Test as Config>::RuntimeOrigin>::root(), 20_001),
+            Error::<Test>::InvalidOwnerCutBounds
+        );
+        assert_ok!(AdminUtils::sudo_set_min_owner_cut(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            5_000
+        ));
+        assert_noop!(
+            AdminUtils::sudo_set_max_owner_cut(<<Test as Config>::RuntimeOrigin>::root(), 4_999),
+            Error::<Test>::InvalidOwnerCutBounds
+        );
+
+        // The current owner cut is pulled inside the new bounds.
+        assert_ok!(AdminUtils::sudo_set_max_owner_cut(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            8_000
+        ));
+        assert_eq!(SubtensorModule::get_subnet_owner_cut(), 8_000);
+        assert_ok!(AdminUtils::sudo_set_min_owner_cut(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            8_000
+        ));
+        assert_eq!(SubtensorModule::get_subnet_owner_cut(), 8_000);
+        assert_ok!(AdminUtils::sudo_set_max_owner_cut(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            u16::MAX
+        ));
+        assert_ok!(AdminUtils::sudo_set_min_owner_cut(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            9_000
+        ));
+        assert_eq!(SubtensorModule::get_subnet_owner_cut(), 9_000);
+    });
+}
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_subnet_volume_repairs_drift --exact --show-output
+#[test]
+fn test_sudo_set_subnet_volume_repairs_drift() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let traded_volume: u128 = 5_000_000_000;
+        add_network(netuid, 10);
+
+        // Seed a drifted accumulator.
+        SubnetVolume::<Test>::insert(netuid, traded_volume + 123_456);
+
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_volume(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                traded_volume
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_volume(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                traded_volume
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(SubnetVolume::<Test>::get(netuid), traded_volume + 123_456);
+
+        assert_ok!(AdminUtils::sudo_set_subnet_volume(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            traded_volume
+        ));
+        assert_eq!(SubnetVolume::<Test>::get(netuid), traded_volume);
+        System::assert_last_event(Event::SubnetVolumeSet(netuid, traded_volume).into());
+    });
+}
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_staking_fee_volume_tiers --exact --show-output
+#[test]
+fn test_sudo_set_staking_fee_volume_tiers() {
+    new_test_ext().execute_with(|| {
+        let tiers: Vec<(u128, u64)> = vec![(1_000_000_000, 40_000), (10_000_000_000, 20_000)];
+        assert_eq!(
+            AdminUtils::sudo_set_staking_fee_volume_tiers(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                tiers.clone()
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        // Fees going up with volume are rejected.
+        assert_eq!(
+            AdminUtils::sudo_set_staking_fee_volume_tiers(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                vec![(1_000_000_000, 20_000), (10_000_000_000, 40_000)]
+            ),
+            Err(Error::<Test>::InvalidStakingFeeVolumeTiers.into())
+        );
+        assert!(SubtensorModule::get_staking_fee_volume_tiers().is_empty());
+
+        assert_ok!(AdminUtils::sudo_set_staking_fee_volume_tiers(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            tiers.clone()
+        ));
+        assert_eq!(SubtensorModule::get_staking_fee_volume_tiers(), tiers);
+
+        // Clearing the tiers restores the flat fee.
+        assert_ok!(AdminUtils::sudo_set_staking_fee_volume_tiers(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            vec![]
+        ));
+        assert!(SubtensorModule::get_staking_fee_volume_tiers().is_empty());
+    });
+}
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_max_total_issuance --exact --show-output
+#[test]
+fn test_sudo_set_max_total_issuance() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 10_000_000_000_000_000;
+        let init_value: u64 = SubtensorModule::get_max_total_issuance();
+        assert_eq!(
+            AdminUtils::sudo_set_max_total_issuance(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_max_total_issuance(), init_value);
+        assert_ok!(AdminUtils::sudo_set_max_total_issuance(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_max_total_issuance(), to_be_set);
+    });
+}
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_prune_newest_on_tie --exact --show-output
+#[test]
+fn test_sudo_set_prune_newest_on_tie() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        assert_eq!(
+            AdminUtils::sudo_set_prune_newest_on_tie(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_prune_newest_on_tie(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                true
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert!(!SubtensorModule::get_prune_newest_on_tie(netuid));
+        assert_ok!(AdminUtils::sudo_set_prune_newest_on_tie(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            true
+        ));
+        assert!(SubtensorModule::get_prune_newest_on_tie(netuid));
+    });
+}
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_min_stake_amount --exact --show-output
+#[test]
+fn test_sudo_set_min_stake_amount() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 1_000_000_000;
+        let init_value: u64 = SubtensorModule::get_min_stake_amount();
+        assert_eq!(init_value, 0);
+        assert_eq!(
+            AdminUtils::sudo_set_min_stake_amount(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_min_stake_amount(), init_value);
+        assert_ok!(AdminUtils::sudo_set_min_stake_amount(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_min_stake_amount(), to_be_set);
+    });
+}
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_stake_whitelist --exact --show-output
+#[test]
+fn test_sudo_set_stake_whitelist() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(2);
+        add_network(netuid, 10);
+        assert_eq!(
+            AdminUtils::sudo_set_stake_whitelist(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_stake_allowed_coldkey(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                coldkey,
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_stake_whitelist(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                true
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert!(SubtensorModule::is_coldkey_allowed_to_stake(
+            netuid, &coldkey
+        ));
+
+        assert_ok!(AdminUtils::sudo_set_stake_whitelist(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            true
+        ));
+        assert!(!SubtensorModule::is_coldkey_allowed_to_stake(
+            netuid, &coldkey
+        ));
+
+        assert_ok!(AdminUtils::sudo_set_stake_allowed_coldkey(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            coldkey,
+            true
+        ));
+        assert!(SubtensorModule::is_coldkey_allowed_to_stake(
+            netuid, &coldkey
+        ));
+
+        assert_ok!(AdminUtils::sudo_set_stake_allowed_coldkey(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            coldkey,
+            false
+        ));
+        assert!(!SubtensorModule::is_coldkey_allowed_to_stake(
+            netuid, &coldkey
+        ));
+    });
+}
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_stake_allowed_coldkey_emits_event --exact --show-output
+#[test]
+fn test_sudo_set_stake_allowed_coldkey_emits_event() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(2);
+        add_network(netuid, 10);
+
+        assert_ok!(AdminUtils::sudo_set_stake_allowed_coldkey(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            coldkey,
+            true
+        ));
+        System::assert_last_event(RuntimeEvent::SubtensorModule(
+            Event::StakeWhitelistUpdated {
+                netuid,
+                coldkey,
+                allowed: true,
+            },
+        ));
+
+        assert_ok!(AdminUtils::sudo_set_stake_allowed_coldkey(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            coldkey,
+            false
+        ));
+        System::assert_last_event(RuntimeEvent::SubtensorModule(
+            Event::StakeWhitelistUpdated {
+                netuid,
+                coldkey,
+                allowed: false,
+            },
+        ));
+    });
+}
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_max_weight_age --exact --show-output
+#[test]
+fn test_sudo_set_max_weight_age() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let to_be_set: u64 = 360;
+        add_network(netuid, 10);
+        let init_value: u64 = SubtensorModule::get_max_weight_age(netuid);
+        assert_eq!(init_value, u64::MAX);
+        assert_eq!(
+            AdminUtils::sudo_set_max_weight_age(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_max_weight_age(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                to_be_set
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(SubtensorModule::get_max_weight_age(netuid), init_value);
+        assert_ok!(AdminUtils::sudo_set_max_weight_age(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_max_weight_age(netuid), to_be_set);
+
+        // Weights set during the last epoch must still count.
+        assert_noop!(
+            AdminUtils::sudo_set_max_weight_age(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                10
+            ),
+            Error::<Test>::MaxWeightAgeTooLow
+        );
+        assert_ok!(AdminUtils::sudo_set_max_weight_age(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            11
+        ));
+    });
+}
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_epoch_offset --exact --show-output
+#[test]
+fn test_sudo_set_epoch_offset() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let to_be_set: u64 = 5;
+        add_network(netuid, 10);
+        let init_value: u64 = SubtensorModule::get_epoch_offset(netuid);
+        assert_eq!(
+            AdminUtils::sudo_set_epoch_offset(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_epoch_offset(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                to_be_set
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(SubtensorModule::get_epoch_offset(netuid), init_value);
+        assert_ok!(AdminUtils::sudo_set_epoch_offset(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_epoch_offset(netuid), to_be_set);
+
+        // The offset can shift the epoch by at most one full tempo.
+        assert_noop!(
+            AdminUtils::sudo_set_epoch_offset(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                11
+            ),
+            Error::<Test>::EpochOffsetOutOfBounds
+        );
+        assert_ok!(AdminUtils::sudo_set_epoch_offset(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            10
+        ));
+
+        // Pending commits were made against the current reveal windows.
+        pallet_subtensor::WeightCommits::<Test>::insert(
+            netuid,
+            U256::from(1),
+            std::collections::VecDeque::from(vec![(sp_core::H256::zero(), 0, 1, 2)]),
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_epoch_offset(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                to_be_set
+            ),
+            SubtensorError::<Test>::PendingWeightCommits
+        );
+    });
+}
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_tempo_clamps_epoch_offset --exact --show-output
+#[test]
+fn test_sudo_set_tempo_clamps_epoch_offset() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 100);
+        SubtensorModule::set_epoch_offset(netuid, 50);
+
+        // An offset within the new tempo is kept.
+        assert_ok!(AdminUtils::sudo_set_tempo(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            60
+        ));
+        assert_eq!(SubtensorModule::get_epoch_offset(netuid), 50);
+
+        // Otherwise it is clamped to the new tempo.
+        assert_ok!(AdminUtils::sudo_set_tempo(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            20
+        ));
+        assert_eq!(SubtensorModule::get_epoch_offset(netuid), 20);
+    });
+}
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_validator_emission_ratio --exact --show-output
+#[test]
+fn test_sudo_set_validator_emission_ratio() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let to_be_set: u16 = u16::MAX / 4;
+        add_network(netuid, 10);
+        assert_eq!(SubtensorModule::get_validator_emission_ratio(netuid), None);
+        assert_eq!(
+            AdminUtils::sudo_set_validator_emission_ratio(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_validator_emission_ratio(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                to_be_set
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(SubtensorModule::get_validator_emission_ratio(netuid), None);
+
+        // The subnet owner can tune the split.
+        let owner = U256::from(10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        assert_ok!(AdminUtils::sudo_set_validator_emission_ratio(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(
+            SubtensorModule::get_validator_emission_ratio(netuid),
+            Some(to_be_set)
+        );
+        assert_ok!(AdminUtils::sudo_set_validator_emission_ratio(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            u16::MAX
+        ));
+        assert_eq!(
+            SubtensorModule::get_validator_emission_ratio(netuid),
+            Some(u16::MAX)
+        );
+    });
+}
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_max_total_registrations_per_block --exact --show-output
+#[test]
+fn test_sudo_set_max_total_registrations_per_block() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u16 = 8;
+        let init_value: u16 = SubtensorModule::get_max_total_registrations_per_block();
+        assert_eq!(init_value, u16::MAX);
+        assert_eq!(
+            AdminUtils::sudo_set_max_total_registrations_per_block(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            SubtensorModule::get_max_total_registrations_per_block(),
+            init_value
+        );
+        assert_ok!(AdminUtils::sudo_set_max_total_registrations_per_block(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(
+            SubtensorModule::get_max_total_registrations_per_block(),
+            to_be_set
+        );
+    });
+}
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_min_subnet_emission --exact --show-output
+#[test]
+fn test_sudo_set_min_subnet_emission() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 1_000_000;
+        let init_value: u64 = SubtensorModule::get_min_subnet_emission();
+        assert_eq!(init_value, 0);
+        assert_eq!(
+            AdminUtils::sudo_set_min_subnet_emission(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_min_subnet_emission(), init_value);
+        assert_ok!(AdminUtils::sudo_set_min_subnet_emission(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_min_subnet_emission(), to_be_set);
+    });
+}
+
+// cargo test --package pallet-admin-utils --lib -- tests::test_sudo_set_max_subnet_emission --exact --show-output
+#[test]
+fn test_sudo_set_max_subnet_emission() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 1_000_000;
+        let init_value: u64 = SubtensorModule::get_max_subnet_emission();
+        assert_eq!(init_value, u64::MAX);
+        assert_eq!(
+            AdminUtils::sudo_set_max_subnet_emission(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_max_subnet_emission(), init_value);
+        assert_ok!(AdminUtils::sudo_set_max_subnet_emission(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_max_subnet_emission(), to_be_set);
+    });
+}
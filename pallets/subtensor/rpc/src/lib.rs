@@ -7,14 +7,16 @@ use jsonrpsee::{
     types::{ErrorObjectOwned, error::ErrorObject},
 };
 use sp_blockchain::HeaderBackend;
+use sp_core::H160;
 use sp_runtime::{AccountId32, traits::Block as BlockT};
 use std::sync::Arc;
 
 use sp_api::ProvideRuntimeApi;
 
 pub use subtensor_custom_rpc_runtime_api::{
-    DelegateInfoRuntimeApi, NeuronInfoRuntimeApi, SubnetInfoRuntimeApi,
-    SubnetRegistrationRuntimeApi,
+    DelegateInfoRuntimeApi, EvmInfoRuntimeApi, NeuronInfoRuntimeApi, RateLimitInfoRuntimeApi,
+    SenateInfoRuntimeApi, StakeInfoRuntimeApi, SubnetInfoRuntimeApi, SubnetRegistrationRuntimeApi,
+    SupplyInfoRuntimeApi,
 };
 
 #[rpc(client, server)]
@@ -62,8 +64,116 @@ pub trait SubtensorCustomApi<BlockHash> {
     fn get_metagraph(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
     #[method(name = "subnetInfo_getSubnetState")]
     fn get_subnet_state(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subtensor_getSubnetNeuronState")]
+    fn get_subnet_neuron_state(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subtensor_getFullMetagraph")]
+    fn get_full_metagraph(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getDifficultyInfo")]
+    fn get_difficulty_info(&self, netuid: u16, at: Option<BlockHash>)
+    -> RpcResult<(u64, u64, i64)>;
+    #[method(name = "subtensor_getRegistrationCost")]
+    fn get_registration_cost(
+        &self,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<(u64, u64, bool)>;
+    #[method(name = "subnetInfo_getAvailableSlots")]
+    fn get_available_slots(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<u16>;
+    #[method(name = "subnetInfo_isSlotAvailable")]
+    fn is_slot_available(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<bool>;
+    #[method(name = "subnetInfo_getPendingOwnerCut")]
+    fn get_pending_owner_cut(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<u64>;
+    #[method(name = "subnetInfo_getSubnetVolume")]
+    fn get_subnet_volume(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<u128>;
+    #[method(name = "subnetInfo_getSubnetPrice")]
+    fn get_subnet_price(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<u64>;
+    #[method(name = "subnetInfo_getWeightAges")]
+    fn get_weight_ages(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<(u16, u64)>>;
+    #[method(name = "subnetInfo_isEpochBlock")]
+    fn is_epoch_block(&self, netuid: u16, block: u64, at: Option<BlockHash>) -> RpcResult<bool>;
+    #[method(name = "subtensor_getLastWeightSetBlock")]
+    fn get_last_weight_set_block(
+        &self,
+        netuid: u16,
+        hotkey: AccountId32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<u64>>;
+    #[method(name = "subnetInfo_getUpcomingEpochs")]
+    fn get_upcoming_epochs(&self, blocks: u64, at: Option<BlockHash>)
+    -> RpcResult<Vec<(u16, u64)>>;
+    #[method(name = "subnetInfo_getRevealPeriod")]
+    fn get_reveal_period(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<u64>;
     #[method(name = "subnetInfo_getLockCost")]
     fn get_network_lock_cost(&self, at: Option<BlockHash>) -> RpcResult<u64>;
+    #[method(name = "subnetInfo_getNextNetuid")]
+    fn get_next_netuid(&self, at: Option<BlockHash>) -> RpcResult<u16>;
+    #[method(name = "subtensor_getSupplyInfo")]
+    fn get_supply_info(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subtensor_getRemainingIssuance")]
+    fn get_remaining_issuance(&self, at: Option<BlockHash>) -> RpcResult<u64>;
+    #[method(name = "subtensor_getMaxStakeAmount")]
+    fn get_max_stake_amount(
+        &self,
+        netuid: u16,
+        limit_price: u64,
+        is_add: bool,
+        at: Option<BlockHash>,
+    ) -> RpcResult<u64>;
+    #[method(name = "subtensor_getStakeBreakdown")]
+    fn get_stake_breakdown(
+        &self,
+        coldkey: AccountId32,
+        hotkey: AccountId32,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<(u64, u64)>;
+    #[method(name = "subtensor_checkStakeConsistency")]
+    fn check_stake_consistency(
+        &self,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(AccountId32, AccountId32, i64)>>;
+    #[method(name = "subtensor_getTotalDelegatedStakeForColdkey")]
+    fn get_total_delegated_stake_for_coldkey(
+        &self,
+        coldkey: AccountId32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<u64>;
+    #[method(name = "subtensor_canStake")]
+    fn can_stake(
+        &self,
+        netuid: u16,
+        coldkey: AccountId32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<bool>;
+    #[method(name = "subtensor_previewNominatorEmission")]
+    fn preview_nominator_emission(
+        &self,
+        hotkey: AccountId32,
+        coldkey: AccountId32,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<u64>;
+    #[method(name = "subtensor_getHotkeyStakeBreakdown")]
+    fn get_hotkey_stake_breakdown(
+        &self,
+        hotkey: AccountId32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(u16, u64, u64)>>;
+    #[method(name = "subtensor_evmToSubstrate")]
+    fn evm_to_substrate(&self, address: H160, at: Option<BlockHash>) -> RpcResult<AccountId32>;
+    #[method(name = "subtensor_ss58ToEvm")]
+    fn ss58_to_evm(&self, account: AccountId32, at: Option<BlockHash>) -> RpcResult<Option<H160>>;
+    #[method(name = "subtensor_getSenateMembers")]
+    fn get_senate_members(&self, at: Option<BlockHash>) -> RpcResult<Vec<AccountId32>>;
+    #[method(name = "subtensor_getRateLimitInfo")]
+    fn get_rate_limit_info(
+        &self,
+        hotkey: AccountId32,
+        netuid: u16,
+        operation: u8,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<(u64, u64, u64)>>;
 }
 
 pub struct SubtensorCustom<C, P> {
@@ -112,6 +222,11 @@ where
     C::Api: NeuronInfoRuntimeApi<Block>,
     C::Api: SubnetInfoRuntimeApi<Block>,
     C::Api: SubnetRegistrationRuntimeApi<Block>,
+    C::Api: SupplyInfoRuntimeApi<Block>,
+    C::Api: StakeInfoRuntimeApi<Block>,
+    C::Api: EvmInfoRuntimeApi<Block>,
+    C::Api: SenateInfoRuntimeApi<Block>,
+    C::Api: RateLimitInfoRuntimeApi<Block>,
 {
     fn get_delegates(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
         let api = self.client.runtime_api();
@@ -342,6 +457,193 @@ where
         }
     }
 
+    fn get_subnet_neuron_state(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_subnet_neuron_state(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get subnet neuron state: {:?}",
+                e
+            ))
+            .into()),
+        }
+    }
+
+    fn get_full_metagraph(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_full_metagraph(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get full metagraph: {:?}", e)).into())
+            }
+        }
+    }
+
+    fn get_difficulty_info(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<(u64, u64, i64)> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_difficulty_info(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get difficulty info: {:?}", e)).into()
+        })
+    }
+
+    fn get_registration_cost(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<(u64, u64, bool)> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_registration_cost(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get registration cost: {:?}", e)).into()
+        })
+    }
+
+    fn get_available_slots(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<u16> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_available_slots(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get available slots: {:?}", e)).into()
+        })
+    }
+
+    fn is_slot_available(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<bool> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.is_slot_available(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to check slot availability: {:?}", e)).into()
+        })
+    }
+
+    fn get_pending_owner_cut(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<u64> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_pending_owner_cut(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get pending owner cut: {:?}", e)).into()
+        })
+    }
+
+    fn get_subnet_volume(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<u128> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_subnet_volume(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get subnet volume: {:?}", e)).into()
+        })
+    }
+
+    fn get_subnet_price(&self, netuid: u16, at: Option<<Block as BlockT>::Hash>) -> RpcResult<u64> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_subnet_price(at, netuid)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get subnet price: {:?}", e)).into())
+    }
+
+    fn get_weight_ages(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(u16, u64)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_weight_ages(at, netuid)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get weight ages: {:?}", e)).into())
+    }
+
+    fn is_epoch_block(
+        &self,
+        netuid: u16,
+        block: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<bool> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.is_epoch_block(at, netuid, block).map_err(|e| {
+            Error::RuntimeError(format!("Unable to check epoch block: {:?}", e)).into()
+        })
+    }
+
+    fn get_last_weight_set_block(
+        &self,
+        netuid: u16,
+        hotkey: AccountId32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<u64>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_last_weight_set_block(at, netuid, hotkey)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get last weight set block: {:?}", e)).into()
+            })
+    }
+
+    fn get_upcoming_epochs(
+        &self,
+        blocks: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(u16, u64)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_upcoming_epochs(at, blocks).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get upcoming epochs: {:?}", e)).into()
+        })
+    }
+
+    fn get_reveal_period(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<u64> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_reveal_period(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get reveal period: {:?}", e)).into()
+        })
+    }
+
     fn get_subnets_info(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
         let api = self.client.runtime_api();
         let at = at.unwrap_or_else(|| self.client.info().best_hash);
@@ -390,4 +692,183 @@ where
             Error::RuntimeError(format!("Unable to get subnet lock cost: {:?}", e)).into()
         })
     }
+
+    fn get_next_netuid(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<u16> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_next_netuid(at)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get next netuid: {:?}", e)).into())
+    }
+
+    fn get_supply_info(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_supply_info(at) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get supply info: {:?}", e)).into())
+            }
+        }
+    }
+
+    fn get_remaining_issuance(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<u64> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_remaining_issuance(at).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get remaining issuance: {:?}", e)).into()
+        })
+    }
+
+    fn get_max_stake_amount(
+        &self,
+        netuid: u16,
+        limit_price: u64,
+        is_add: bool,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<u64> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_max_stake_amount(at, netuid, limit_price, is_add)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get max stake amount: {:?}", e)).into()
+            })
+    }
+
+    fn get_stake_breakdown(
+        &self,
+        coldkey: AccountId32,
+        hotkey: AccountId32,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<(u64, u64)> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_stake_breakdown(at, coldkey, hotkey, netuid)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get stake breakdown: {:?}", e)).into()
+            })
+    }
+
+    fn check_stake_consistency(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(AccountId32, AccountId32, i64)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.check_stake_consistency(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to check stake consistency: {:?}", e)).into()
+        })
+    }
+    fn get_total_delegated_stake_for_coldkey(
+        &self,
+        coldkey: AccountId32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<u64> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_total_delegated_stake_for_coldkey(at, coldkey)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get total delegated stake: {:?}", e)).into()
+            })
+    }
+    fn can_stake(
+        &self,
+        netuid: u16,
+        coldkey: AccountId32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<bool> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.can_stake(at, netuid, coldkey).map_err(|e| {
+            Error::RuntimeError(format!("Unable to check if coldkey can stake: {:?}", e)).into()
+        })
+    }
+    fn preview_nominator_emission(
+        &self,
+        hotkey: AccountId32,
+        coldkey: AccountId32,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<u64> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.preview_nominator_emission(at, hotkey, coldkey, netuid)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to preview nominator emission: {:?}", e)).into()
+            })
+    }
+
+    fn get_hotkey_stake_breakdown(
+        &self,
+        hotkey: AccountId32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(u16, u64, u64)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_hotkey_stake_breakdown(at, hotkey).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get hotkey stake breakdown: {:?}", e)).into()
+        })
+    }
+    fn evm_to_substrate(
+        &self,
+        address: H160,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<AccountId32> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.evm_to_substrate(at, address)
+            .map_err(|e| Error::RuntimeError(format!("Unable to map EVM address: {:?}", e)).into())
+    }
+
+    fn ss58_to_evm(
+        &self,
+        account: AccountId32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<H160>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.ss58_to_evm(at, account).map_err(|e| {
+            Error::RuntimeError(format!("Unable to map substrate account: {:?}", e)).into()
+        })
+    }
+    fn get_senate_members(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<AccountId32>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_senate_members(at).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get senate members: {:?}", e)).into()
+        })
+    }
+
+    fn get_rate_limit_info(
+        &self,
+        hotkey: AccountId32,
+        netuid: u16,
+        operation: u8,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<(u64, u64, u64)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_rate_limit_info(at, hotkey, netuid, operation)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get rate limit info: {:?}", e)).into()
+            })
+    }
 }
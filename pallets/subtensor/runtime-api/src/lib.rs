@@ -5,13 +5,15 @@ use codec::Compact;
 use pallet_subtensor::rpc_info::{
     delegate_info::DelegateInfo,
     dynamic_info::DynamicInfo,
-    metagraph::Metagraph,
+    metagraph::{FullMetagraph, Metagraph},
     neuron_info::{NeuronInfo, NeuronInfoLite},
-    show_subnet::SubnetState,
+    show_subnet::{SubnetNeuronState, SubnetState},
     stake_info::StakeInfo,
     subnet_info::{SubnetHyperparams, SubnetInfo, SubnetInfov2},
+    supply_info::SupplyInfo,
 };
-use sp_runtime::AccountId32;
+use sp_core::H160;
+use sp_runtime::{AccountId32, DispatchError};
 
 // Here we declare the runtime API. It is implemented it the `impl` block in
 // src/neuron_info.rs, src/subnet_info.rs, and src/delegate_info.rs
@@ -40,6 +42,20 @@ sp_api::decl_runtime_apis! {
         fn get_metagraph(netuid: u16) -> Option<Metagraph<AccountId32>>;
         fn get_dynamic_info(netuid: u16) -> Option<DynamicInfo<AccountId32>>;
         fn get_subnet_state(netuid: u16) -> Option<SubnetState<AccountId32>>;
+        fn get_subnet_neuron_state(netuid: u16) -> Option<SubnetNeuronState>;
+        fn get_full_metagraph(netuid: u16) -> Option<FullMetagraph<AccountId32>>;
+        fn get_difficulty_info(netuid: u16) -> (u64, u64, i64);
+        fn get_registration_cost(netuid: u16) -> (u64, u64, bool);
+        fn get_available_slots(netuid: u16) -> u16;
+        fn is_slot_available(netuid: u16) -> bool;
+        fn get_pending_owner_cut(netuid: u16) -> u64;
+        fn get_subnet_volume(netuid: u16) -> u128;
+        fn get_subnet_price(netuid: u16) -> u64;
+        fn get_weight_ages(netuid: u16) -> Vec<(u16, u64)>;
+        fn is_epoch_block(netuid: u16, block: u64) -> bool;
+        fn get_last_weight_set_block(netuid: u16, hotkey: AccountId32) -> Option<u64>;
+        fn get_upcoming_epochs(blocks: u64) -> Vec<(u16, u64)>;
+        fn get_reveal_period(netuid: u16) -> u64;
     }
 
     pub trait StakeInfoRuntimeApi {
@@ -47,9 +63,40 @@ sp_api::decl_runtime_apis! {
         fn get_stake_info_for_coldkeys( coldkey_accounts: Vec<AccountId32> ) -> Vec<(AccountId32, Vec<StakeInfo<AccountId32>>)>;
         fn get_stake_info_for_hotkey_coldkey_netuid( hotkey_account: AccountId32, coldkey_account: AccountId32, netuid: u16 ) -> Option<StakeInfo<AccountId32>>;
         fn get_stake_fee( origin: Option<(AccountId32, u16)>, origin_coldkey_account: AccountId32, destination: Option<(AccountId32, u16)>, destination_coldkey_account: AccountId32, amount: u64 ) -> u64;
+        fn get_max_stake_amount( netuid: u16, limit_price: u64, is_add: bool ) -> u64;
+        fn get_stake_breakdown( coldkey_account: AccountId32, hotkey_account: AccountId32, netuid: u16 ) -> (u64, u64);
+        fn check_stake_consistency( netuid: u16 ) -> Vec<(AccountId32, AccountId32, i64)>;
+        fn get_total_delegated_stake_for_coldkey( coldkey_account: AccountId32 ) -> u64;
+        fn can_stake( netuid: u16, coldkey_account: AccountId32 ) -> bool;
+        fn preview_nominator_emission( hotkey_account: AccountId32, coldkey_account: AccountId32, netuid: u16 ) -> u64;
+        fn get_hotkey_stake_breakdown( hotkey_account: AccountId32 ) -> Vec<(u16, u64, u64)>;
     }
 
     pub trait SubnetRegistrationRuntimeApi {
         fn get_network_registration_cost() -> u64;
+        fn get_next_netuid() -> u16;
+    }
+
+    pub trait SupplyInfoRuntimeApi {
+        fn get_supply_info() -> SupplyInfo;
+        fn get_remaining_issuance() -> u64;
+    }
+
+    pub trait EvmInfoRuntimeApi {
+        fn get_evm_chain_id() -> u64;
+        fn evm_to_substrate(address: H160) -> AccountId32;
+        fn ss58_to_evm(account: AccountId32) -> Option<H160>;
+    }
+
+    pub trait SenateInfoRuntimeApi {
+        fn get_senate_members() -> Vec<AccountId32>;
+    }
+
+    pub trait RateLimitInfoRuntimeApi {
+        fn get_rate_limit_info(hotkey: AccountId32, netuid: u16, operation: u8) -> Option<(u64, u64, u64)>;
+    }
+
+    pub trait BatchRuntimeApi<RuntimeCall> where RuntimeCall: codec::Codec {
+        fn simulate_batch(account: AccountId32, calls: Vec<RuntimeCall>) -> Vec<Result<(), DispatchError>>;
     }
 }
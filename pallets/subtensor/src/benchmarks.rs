@@ -75,6 +75,35 @@ benchmarks! {
 
   }: set_weights(RawOrigin::Signed( signer.clone() ), netuid, dests, weights, version_key)
 
+  benchmark_epoch {
+    // The subnet size, the epoch is weighed per neuron.
+    let n in 1 .. 4096;
+    let netuid: u16 = 1;
+    let tempo: u16 = 1;
+
+    Subtensor::<T>::init_new_network(netuid, tempo);
+    Subtensor::<T>::set_max_allowed_uids( netuid, 4096 );
+    Subtensor::<T>::set_max_allowed_validators( netuid, 4096 );
+
+    for uid in 0..n as u16 {
+      let hotkey: T::AccountId = account("Alice", 0, u32::from(uid));
+      let coldkey: T::AccountId = account("Test", 0, u32::from(uid));
+
+      Subtensor::<T>::append_neuron(netuid, &hotkey, 0);
+      Subtensor::<T>::create_account_if_non_existent(&coldkey, &hotkey);
+      Subtensor::<T>::increase_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid, 1_000_000_000);
+      Subtensor::<T>::set_validator_permit_for_uid(netuid, uid, true);
+    }
+
+    // Worst case: every neuron weighs every neuron.
+    let row: Vec<(u16, u16)> = (0..n as u16).map(|uid| (uid, u16::MAX)).collect();
+    for uid in 0..n as u16 {
+      Weights::<T>::insert(netuid, uid, row.clone());
+    }
+  }: {
+    Subtensor::<T>::epoch(netuid, 1_000_000_000);
+  }
+
 
   benchmark_become_delegate {
     // This is a whitelisted caller who can make transaction without weights.
@@ -1,7 +1,9 @@
 use super::*;
+use crate::epoch::math::exp_safe;
 use frame_support::storage::IterableStorageMap;
+use frame_support::weights::Weight;
 use safe_math::*;
-use substrate_fixed::types::{I96F32, I110F18};
+use substrate_fixed::types::{I32F32, I96F32, I110F18};
 
 impl<T: Config + pallet_drand::Config> Pallet<T> {
     /// Executes the necessary operations for each block.
@@ -22,6 +24,13 @@ impl<T: Config + pallet_drand::Config> Pallet<T> {
         Ok(())
     }
 
+    /// Returns the benchmarked weight of a block step, which covers the epochs it runs.
+    pub fn get_block_step_weight() -> Weight {
+        Weight::from_parts(110_634_229_000_u64, 0)
+            .saturating_add(T::DbWeight::get().reads(8304_u64))
+            .saturating_add(T::DbWeight::get().writes(110_u64))
+    }
+
     fn try_set_pending_children(block_number: u64) {
         let subnets: Vec<u16> = Self::get_all_subnet_netuids();
         for &netuid in subnets.iter() {
@@ -176,7 +185,16 @@ impl<T: Config + pallet_drand::Config> Pallet<T> {
                     }
                 }
 
-                // --- 6. Drain all counters for this network for this interval.
+                // --- 6. Record how much the difficulty moved at this adjustment.
+                let new_difficulty: u64 = Self::get_difficulty_as_u64(netuid);
+                let difficulty_delta: i128 =
+                    i128::from(new_difficulty).saturating_sub(i128::from(current_difficulty));
+                LastDifficultyDelta::<T>::insert(
+                    netuid,
+                    difficulty_delta.clamp(i64::MIN.into(), i64::MAX.into()) as i64,
+                );
+
+                // --- 7. Drain all counters for this network for this interval.
                 Self::set_last_adjustment_block(netuid, current_block);
                 Self::set_registrations_this_interval(netuid, 0);
                 Self::set_pow_registrations_this_interval(netuid, 0);
@@ -185,12 +203,19 @@ impl<T: Config + pallet_drand::Config> Pallet<T> {
                 log::debug!("interval not reached.");
             }
 
-            // --- 7. Drain block registrations for each network. Needed for registration rate limits.
+            // --- 8. Drain block registrations for each network. Needed for registration rate limits.
             Self::set_registrations_this_block(netuid, 0);
         }
+
+        // --- 9. Drain block registrations across all networks.
+        Self::set_total_registrations_this_block(0);
     }
 
-    /// Calculates the upgraded difficulty by multiplying the current difficulty by the ratio ( reg_actual + reg_target / reg_target + reg_target )
+    /// Calculates the upgraded difficulty by multiplying the current difficulty by a ratio depending on the
+    /// subnet's difficulty algorithm:
+    /// * linear: ( reg_actual + reg_target / reg_target + reg_target )
+    /// * exponential: exp( ( reg_actual - reg_target ) / reg_target )
+    ///
     /// We use I110F18 to avoid any overflows on u64. Also min_difficulty and max_difficulty bound the range.
     ///
     pub fn upgraded_difficulty(
@@ -199,13 +224,27 @@ impl<T: Config + pallet_drand::Config> Pallet<T> {
         registrations_this_interval: u16,
         target_registrations_per_interval: u16,
     ) -> u64 {
-        let updated_difficulty: I110F18 = I110F18::saturating_from_num(current_difficulty)
-            .saturating_mul(I110F18::saturating_from_num(
-                registrations_this_interval.saturating_add(target_registrations_per_interval),
-            ))
-            .safe_div(I110F18::saturating_from_num(
-                target_registrations_per_interval.saturating_add(target_registrations_per_interval),
-            ));
+        let updated_difficulty: I110F18 = match Self::get_difficulty_algo(netuid) {
+            DIFFICULTY_ALGO_EXPONENTIAL => {
+                let exponent: I32F32 = I32F32::saturating_from_num(registrations_this_interval)
+                    .saturating_sub(I32F32::saturating_from_num(
+                        target_registrations_per_interval,
+                    ))
+                    .safe_div(I32F32::saturating_from_num(
+                        target_registrations_per_interval,
+                    ));
+                I110F18::saturating_from_num(current_difficulty)
+                    .saturating_mul(I110F18::saturating_from_num(exp_safe(exponent)))
+            }
+            _ => I110F18::saturating_from_num(current_difficulty)
+                .saturating_mul(I110F18::saturating_from_num(
+                    registrations_this_interval.saturating_add(target_registrations_per_interval),
+                ))
+                .safe_div(I110F18::saturating_from_num(
+                    target_registrations_per_interval
+                        .saturating_add(target_registrations_per_interval),
+                )),
+        };
         let alpha: I110F18 = I110F18::saturating_from_num(Self::get_adjustment_alpha(netuid))
             .safe_div(I110F18::saturating_from_num(u64::MAX));
         let next_value: I110F18 = alpha
@@ -3,3 +3,4 @@ pub mod block_emission;
 pub mod block_step;
 pub mod root;
 pub mod run_coinbase;
+pub mod subnet_emission;
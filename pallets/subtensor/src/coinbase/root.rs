@@ -16,6 +16,7 @@
 // DEALINGS IN THE SOFTWARE.
 
 use super::*;
+use alloc::collections::BTreeSet;
 use frame_support::dispatch::Pays;
 use frame_support::storage::IterableStorageDoubleMap;
 use frame_support::weights::Weight;
@@ -257,6 +258,11 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Returns the current members of the Senate.
+    pub fn get_senate_members() -> Vec<T::AccountId> {
+        T::SenateMembers::members()
+    }
+
     // Checks if a hotkey should be a member of the Senate, and if so, adds them.
     //
     // This function is responsible for adding a hotkey to the Senate if they meet the requirements.
@@ -505,7 +511,10 @@ impl<T: Config> Pallet<T> {
         let _ = Uids::<T>::clear_prefix(netuid, u32::MAX, None);
         let keys = Keys::<T>::iter_prefix(netuid).collect::<Vec<_>>();
         let _ = Keys::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = PrunedHotkeys::<T>::clear_prefix(netuid, u32::MAX, None);
         let _ = Bonds::<T>::clear_prefix(netuid, u32::MAX, None);
+        PendingSmoothedEmission::<T>::remove(netuid);
+        SubnetRecentVolume::<T>::remove(netuid);
 
         // --- 8. Removes the weights for this subnet (do not remove).
         let _ = Weights::<T>::clear_prefix(netuid, u32::MAX, None);
@@ -541,22 +550,39 @@ impl<T: Config> Pallet<T> {
         ValidatorPermit::<T>::remove(netuid);
         ValidatorTrust::<T>::remove(netuid);
 
-        for (_uid, key) in keys {
+        for (_uid, key) in keys.iter() {
             IsNetworkMember::<T>::remove(key, netuid);
+            // Drop the stake locks on the neuron, so they don't carry over to a reused netuid.
+            let locked_coldkeys: Vec<T::AccountId> = LockedStake::<T>::iter_prefix((key,))
+                .filter(|((_, lock_netuid), _)| *lock_netuid == netuid)
+                .map(|((coldkey, _), _)| coldkey)
+                .collect();
+            for coldkey in locked_coldkeys {
+                LockedStake::<T>::remove((key, coldkey, netuid));
+            }
         }
 
         // --- 11. Erase network parameters.
         Tempo::<T>::remove(netuid);
+        EpochOffset::<T>::remove(netuid);
         Kappa::<T>::remove(netuid);
         Difficulty::<T>::remove(netuid);
+        DifficultyAlgo::<T>::remove(netuid);
+        LastDifficultyDelta::<T>::remove(netuid);
         MaxAllowedUids::<T>::remove(netuid);
         ImmunityPeriod::<T>::remove(netuid);
         ActivityCutoff::<T>::remove(netuid);
         MaxWeightsLimit::<T>::remove(netuid);
+        ValidatorEmissionRatio::<T>::remove(netuid);
         MinAllowedWeights::<T>::remove(netuid);
         RegistrationsThisInterval::<T>::remove(netuid);
         POWRegistrationsThisInterval::<T>::remove(netuid);
         BurnRegistrationsThisInterval::<T>::remove(netuid);
+        StakeWhitelist::<T>::remove(netuid);
+        EmissionSmoothingBlocks::<T>::remove(netuid);
+        MaxWeightAge::<T>::remove(netuid);
+        PruneNewestOnTie::<T>::remove(netuid);
+        let _ = StakeAllowedColdkeys::<T>::clear_prefix(netuid, u32::MAX, None);
 
         // --- 12. Add the balance back to the owner.
         Self::add_balance_to_coldkey_account(&owner_coldkey, reserved_amount);
@@ -568,6 +594,20 @@ impl<T: Config> Pallet<T> {
             SubnetIdentitiesV2::<T>::remove(netuid);
             Self::deposit_event(Event::SubnetIdentityRemoved(netuid));
         }
+
+        // --- 14. Release the stake providers of coldkeys staked on the subnet's neurons that have
+        // no stake left on any other subnet.
+        let mut staked_coldkeys: BTreeSet<T::AccountId> = BTreeSet::new();
+        for (_uid, hotkey) in keys {
+            for ((coldkey, alpha_netuid), shares) in Alpha::<T>::iter_prefix((&hotkey,)) {
+                if alpha_netuid == netuid && shares != 0 {
+                    staked_coldkeys.insert(coldkey);
+                }
+            }
+        }
+        for coldkey in staked_coldkeys {
+            Self::maybe_release_stake_provider(&coldkey);
+        }
     }
 
     #[allow(clippy::arithmetic_side_effects)]
@@ -1,4 +1,5 @@
 use super::*;
+use crate::system::ensure_root;
 use alloc::collections::BTreeMap;
 use safe_math::*;
 use substrate_fixed::types::I96F32;
@@ -37,6 +38,10 @@ impl<T: Config> Pallet<T> {
         let current_block: u64 = Self::get_current_block_as_u64();
         log::debug!("Current block: {:?}", current_block);
 
+        // --- 0.1 Never emit TAO past the maximum total issuance.
+        let block_emission: I96F32 = block_emission.min(asfloat!(Self::get_remaining_issuance()));
+        log::debug!("Capped block emission: {:?}", block_emission);
+
         // --- 1. Get all netuids (filter out root.)
         let subnets: Vec<u16> = Self::get_all_subnet_netuids()
             .into_iter()
@@ -59,18 +64,43 @@ impl<T: Config> Pallet<T> {
         let mut alpha_in: BTreeMap<u16, I96F32> = BTreeMap::new();
         let mut alpha_out: BTreeMap<u16, I96F32> = BTreeMap::new();
         for netuid_i in subnets.iter() {
-            // Get subnet price.
-            let price_i: I96F32 = Self::get_alpha_price(*netuid_i);
-            log::debug!("price_i: {:?}", price_i);
             // Get subnet TAO.
             let moving_price_i: I96F32 = Self::get_moving_alpha_price(*netuid_i);
             log::debug!("moving_price_i: {:?}", moving_price_i);
             // Emission is price over total.
-            let mut tao_in_i: I96F32 = block_emission
+            let tao_in_i: I96F32 = block_emission
                 .saturating_mul(moving_price_i)
                 .checked_div(total_moving_prices)
                 .unwrap_or(asfloat!(0.0));
             log::debug!("tao_in_i: {:?}", tao_in_i);
+            tao_in.insert(*netuid_i, tao_in_i);
+        }
+
+        // --- 3.1 Bound the subnets that emit TAO by the minimum and maximum subnet emission.
+        // Only subnets which allow registration emit TAO. The cap is applied last, so it wins
+        // if it is set below the floor.
+        let emitting: Vec<u16> = subnets
+            .iter()
+            .copied()
+            .filter(|netuid_i| {
+                Self::get_network_registration_allowed(*netuid_i)
+                    || Self::get_network_pow_registration_allowed(*netuid_i)
+            })
+            .collect();
+        let mut emitting_tao_in: BTreeMap<u16, I96F32> = tao_in
+            .iter()
+            .filter(|(netuid_i, _)| emitting.contains(netuid_i))
+            .map(|(netuid_i, tao_in_i)| (*netuid_i, *tao_in_i))
+            .collect();
+        Self::apply_subnet_emission_floor(&mut emitting_tao_in);
+        Self::apply_subnet_emission_cap(&mut emitting_tao_in);
+        tao_in.extend(emitting_tao_in);
+
+        for netuid_i in subnets.iter() {
+            // Get subnet price.
+            let price_i: I96F32 = Self::get_alpha_price(*netuid_i);
+            log::debug!("price_i: {:?}", price_i);
+            let tao_in_i: I96F32 = *tao_in.get(netuid_i).unwrap_or(&asfloat!(0.0));
             // Get alpha_emission total
             let alpha_emission_i: I96F32 = asfloat!(
                 Self::get_block_emission_for_issuance(Self::get_alpha_issuance(*netuid_i))
@@ -86,13 +116,10 @@ impl<T: Config> Pallet<T> {
             // Get alpha_out.
             let alpha_out_i = alpha_emission_i;
             // Only emit TAO if the subnetwork allows registration.
-            if !Self::get_network_registration_allowed(*netuid_i)
-                && !Self::get_network_pow_registration_allowed(*netuid_i)
-            {
-                tao_in_i = asfloat!(0.0);
+            if !emitting.contains(netuid_i) {
+                tao_in.insert(*netuid_i, asfloat!(0.0));
             }
             // Insert values into maps
-            tao_in.insert(*netuid_i, tao_in_i);
             alpha_in.insert(*netuid_i, alpha_in_i);
             alpha_out.insert(*netuid_i, alpha_out_i);
         }
@@ -175,7 +202,7 @@ impl<T: Config> Pallet<T> {
             // Get root proportion of alpha_out dividends.
             let root_alpha: I96F32 = root_proportion
                 .saturating_mul(alpha_out_i) // Total alpha emission per block remaining.
-                .saturating_mul(asfloat!(0.5)); // 50% to validators.
+                .saturating_mul(Self::get_float_validator_emission_share(*netuid_i)); // Validator share.
             // Remove root alpha from alpha_out.
             log::debug!("root_alpha: {:?}", root_alpha);
             // Get pending alpha as original alpha_out - root_alpha.
@@ -205,50 +232,80 @@ impl<T: Config> Pallet<T> {
         }
 
         // --- 7. Drain pending emission through the subnet based on tempo.
+        // The drain cadence is per subnet: each subnet drains on its own `Tempo`, and a hotkey
+        // registered on several subnets is paid out by each of them independently on that
+        // subnet's cadence, there is no global hotkey emission tempo to aggregate over.
         for &netuid in subnets.iter() {
             // Pass on subnets that have not reached their tempo.
             if Self::should_run_epoch(netuid, current_block) {
-                if let Err(e) = Self::reveal_crv3_commits(netuid) {
-                    log::warn!(
-                        "Failed to reveal commits for subnet {} due to error: {:?}",
-                        netuid,
-                        e
-                    );
-                };
+                Self::run_subnet_epoch(netuid, current_block);
+            } else {
+                // Increment
+                BlocksSinceLastStep::<T>::mutate(netuid, |total| *total = total.saturating_add(1));
 
-                // Restart counters.
-                BlocksSinceLastStep::<T>::insert(netuid, 0);
-                LastMechansimStepBlock::<T>::insert(netuid, current_block);
+                // Pay out the next share of the last drain, if it is being smoothed.
+                Self::distribute_smoothed_emission(netuid);
+            }
+        }
+    }
 
-                // Get and drain the subnet pending emission.
-                let pending_alpha: u64 = PendingEmission::<T>::get(netuid);
-                PendingEmission::<T>::insert(netuid, 0);
+    /// Runs the epoch of a subnet: reveals pending CRv3 commits, restarts the step counters and
+    /// drains the subnet's pending emission through Yuma consensus.
+    pub fn run_subnet_epoch(netuid: u16, current_block: u64) {
+        if let Err(e) = Self::reveal_crv3_commits(netuid) {
+            log::warn!(
+                "Failed to reveal commits for subnet {} due to error: {:?}",
+                netuid,
+                e
+            );
+        };
 
-                // Get and drain the subnet pending root divs.
-                let pending_tao: u64 = PendingRootDivs::<T>::get(netuid);
-                PendingRootDivs::<T>::insert(netuid, 0);
+        // Restart counters.
+        BlocksSinceLastStep::<T>::insert(netuid, 0);
+        LastMechansimStepBlock::<T>::insert(netuid, current_block);
 
-                // Get this amount as alpha that was swapped for pending root divs.
-                let pending_swapped: u64 = PendingAlphaSwapped::<T>::get(netuid);
-                PendingAlphaSwapped::<T>::insert(netuid, 0);
+        // Get and drain the subnet pending emission.
+        let pending_alpha: u64 = PendingEmission::<T>::get(netuid);
+        PendingEmission::<T>::insert(netuid, 0);
 
-                // Get owner cut and drain.
-                let owner_cut: u64 = PendingOwnerCut::<T>::get(netuid);
-                PendingOwnerCut::<T>::insert(netuid, 0);
+        // Get and drain the subnet pending root divs.
+        let pending_tao: u64 = PendingRootDivs::<T>::get(netuid);
+        PendingRootDivs::<T>::insert(netuid, 0);
 
-                // Drain pending root divs, alpha emission, and owner cut.
-                Self::drain_pending_emission(
-                    netuid,
-                    pending_alpha,
-                    pending_tao,
-                    pending_swapped,
-                    owner_cut,
-                );
-            } else {
-                // Increment
-                BlocksSinceLastStep::<T>::mutate(netuid, |total| *total = total.saturating_add(1));
-            }
-        }
+        // Get this amount as alpha that was swapped for pending root divs.
+        let pending_swapped: u64 = PendingAlphaSwapped::<T>::get(netuid);
+        PendingAlphaSwapped::<T>::insert(netuid, 0);
+
+        // Get owner cut and drain.
+        let owner_cut: u64 = PendingOwnerCut::<T>::get(netuid);
+        PendingOwnerCut::<T>::insert(netuid, 0);
+
+        // Drain pending root divs, alpha emission, and owner cut.
+        Self::drain_pending_emission(
+            netuid,
+            pending_alpha,
+            pending_tao,
+            pending_swapped,
+            owner_cut,
+        );
+    }
+
+    /// Runs the epoch of a subnet right away, regardless of its tempo.
+    ///
+    /// Meant for governance to recover a stuck subnet or to try out changes; the subnet's regular
+    /// epochs keep their schedule.
+    pub fn do_force_epoch(origin: T::RuntimeOrigin, netuid: u16) -> DispatchResult {
+        ensure_root(origin)?;
+        ensure!(
+            netuid != Self::get_root_netuid(),
+            Error::<T>::CannotForceRootEpoch
+        );
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+
+        let current_block: u64 = Self::get_current_block_as_u64();
+        Self::run_subnet_epoch(netuid, current_block);
+        Self::deposit_event(Event::EpochForced(netuid));
+        Ok(())
     }
 
     pub fn drain_pending_emission(
@@ -295,6 +352,71 @@ impl<T: Config> Pallet<T> {
         log::debug!("incentives: {:?}", incentives);
         log::debug!("dividends: {:?}", dividends);
 
+        // Whatever is left of the previous drain is paid out in full before the new one starts.
+        PendingSmoothedEmission::<T>::mutate(netuid, |smoothed| {
+            if let Some(smoothed) = smoothed {
+                smoothed.blocks_remaining = 1;
+            }
+        });
+        Self::distribute_smoothed_emission(netuid);
+
+        let smoothing_blocks: u64 = EmissionSmoothingBlocks::<T>::get(netuid);
+        if smoothing_blocks <= 1 {
+            Self::distribute_dividends_and_incentives(
+                netuid,
+                pending_tao,
+                owner_cut,
+                incentives,
+                dividends,
+            );
+        } else {
+            // Spread the emission over the next blocks, starting with this one.
+            PendingSmoothedEmission::<T>::insert(
+                netuid,
+                SmoothedEmission {
+                    blocks_remaining: smoothing_blocks,
+                    pending_tao,
+                    owner_cut,
+                    incentives: incentives.into_iter().collect(),
+                    dividends: dividends
+                        .into_iter()
+                        .map(|(hotkey, dividend)| (hotkey, tou64!(dividend)))
+                        .collect(),
+                },
+            );
+            Self::distribute_smoothed_emission(netuid);
+        }
+    }
+
+    /// Pays out an even share of the emission that is still being smoothed for the subnet. The
+    /// last block pays out everything that is left, so nothing is lost to rounding.
+    pub fn distribute_smoothed_emission(netuid: u16) {
+        let Some(mut smoothed) = PendingSmoothedEmission::<T>::take(netuid) else {
+            return;
+        };
+        let blocks: u64 = smoothed.blocks_remaining.max(1);
+        let share = |remaining: &mut u64| -> u64 {
+            let amount: u64 = remaining.checked_div(blocks).unwrap_or(*remaining);
+            *remaining = remaining.saturating_sub(amount);
+            amount
+        };
+
+        let pending_tao: u64 = share(&mut smoothed.pending_tao);
+        let owner_cut: u64 = share(&mut smoothed.owner_cut);
+        let mut incentives: BTreeMap<T::AccountId, u64> = BTreeMap::new();
+        for (hotkey, remaining) in smoothed.incentives.iter_mut() {
+            incentives.insert(hotkey.clone(), share(remaining));
+        }
+        let mut dividends: BTreeMap<T::AccountId, I96F32> = BTreeMap::new();
+        for (hotkey, remaining) in smoothed.dividends.iter_mut() {
+            dividends.insert(hotkey.clone(), asfloat!(share(remaining)));
+        }
+        log::debug!(
+            "Distributing smoothed emission for netuid {:?}, blocks remaining: {:?}",
+            netuid,
+            blocks
+        );
+
         Self::distribute_dividends_and_incentives(
             netuid,
             pending_tao,
@@ -302,6 +424,142 @@ impl<T: Config> Pallet<T> {
             incentives,
             dividends,
         );
+
+        smoothed.blocks_remaining = blocks.saturating_sub(1);
+        if smoothed.blocks_remaining > 0 {
+            PendingSmoothedEmission::<T>::insert(netuid, smoothed);
+        }
+    }
+
+    /// Returns the part of a hotkey's dividends on the subnet that is paid out as root dividends,
+    /// i.e. its weighted root stake over its alpha stake plus weighted root stake.
+    pub fn get_root_dividend_proportion(hotkey: &T::AccountId, netuid: u16) -> I96F32 {
+        // Get hotkey ALPHA on subnet.
+        let alpha_stake = asfloat!(Self::get_stake_for_hotkey_on_subnet(hotkey, netuid));
+        // Get hotkey TAO on root.
+        let root_stake: I96F32 = asfloat!(Self::get_stake_for_hotkey_on_subnet(
+            hotkey,
+            Self::get_root_netuid()
+        ));
+        // Convert TAO to alpha with weight.
+        let root_alpha: I96F32 = root_stake.saturating_mul(Self::get_tao_weight());
+        // Get total from root and local
+        let total_alpha: I96F32 = alpha_stake.saturating_add(root_alpha);
+        root_alpha.checked_div(total_alpha).unwrap_or(asfloat!(0.0))
+    }
+
+    /// Pays out the emission still being smoothed for a hotkey on a subnet right away, with the
+    /// same math as the scheduled payouts. The hotkey's root dividends take their share of the
+    /// remaining TAO, and the rest of the subnet keeps its schedule.
+    pub fn do_force_drain_pending_emission(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        netuid: u16,
+    ) -> DispatchResult {
+        ensure_root(origin)?;
+        let mut smoothed =
+            PendingSmoothedEmission::<T>::get(netuid).ok_or(Error::<T>::NoPendingEmission)?;
+
+        // Take the hotkey's remaining incentive and dividends out of the schedule.
+        let take = |pending: &mut Vec<(T::AccountId, u64)>| -> Option<u64> {
+            let index = pending.iter().position(|(other, _)| *other == hotkey)?;
+            Some(pending.remove(index).1)
+        };
+        let incentive: Option<u64> = take(&mut smoothed.incentives);
+        let dividend: Option<u64> = take(&mut smoothed.dividends);
+        ensure!(
+            incentive.is_some() || dividend.is_some(),
+            Error::<T>::NoPendingEmission
+        );
+
+        // The remaining TAO is split over the root dividends of all pending hotkeys.
+        let root_divs = |hotkey: &T::AccountId, dividend: u64| -> I96F32 {
+            asfloat!(dividend).saturating_mul(Self::get_root_dividend_proportion(hotkey, netuid))
+        };
+        let own_root_divs: I96F32 = root_divs(&hotkey, dividend.unwrap_or_default());
+        let total_root_divs: I96F32 =
+            smoothed
+                .dividends
+                .iter()
+                .fold(own_root_divs, |total, (other, other_dividend)| {
+                    total.saturating_add(root_divs(other, *other_dividend))
+                });
+        let pending_tao: u64 = tou64!(
+            asfloat!(smoothed.pending_tao).saturating_mul(
+                own_root_divs
+                    .checked_div(total_root_divs)
+                    .unwrap_or(asfloat!(0.0))
+            )
+        );
+        smoothed.pending_tao = smoothed.pending_tao.saturating_sub(pending_tao);
+        PendingSmoothedEmission::<T>::insert(netuid, smoothed);
+
+        let mut incentives: BTreeMap<T::AccountId, u64> = BTreeMap::new();
+        if let Some(incentive) = incentive {
+            incentives.insert(hotkey.clone(), incentive);
+        }
+        let mut dividends: BTreeMap<T::AccountId, I96F32> = BTreeMap::new();
+        if let Some(dividend) = dividend {
+            dividends.insert(hotkey.clone(), asfloat!(dividend));
+        }
+        Self::distribute_dividends_and_incentives(netuid, pending_tao, 0, incentives, dividends);
+
+        Self::deposit_event(Event::PendingEmissionDrained(hotkey, netuid));
+        Ok(())
+    }
+
+    /// Projects the alpha `coldkey` is paid on `netuid` through its stake on `hotkey` once the
+    /// hotkey's pending emission drains, with the same math as the payout itself: the incentive
+    /// and the validator take go to the hotkey owner, and the remaining alpha dividends are split
+    /// over the hotkey's stake as it stands now. Stake added or removed since the last drain is
+    /// counted the same as older stake. Root dividends are paid in TAO on the root subnet and
+    /// aren't part of the projection.
+    pub fn preview_nominator_emission(
+        hotkey: &T::AccountId,
+        coldkey: &T::AccountId,
+        netuid: u16,
+    ) -> u64 {
+        let Some(smoothed) = PendingSmoothedEmission::<T>::get(netuid) else {
+            return 0;
+        };
+        let pending = |pending: &[(T::AccountId, u64)]| -> u64 {
+            pending
+                .iter()
+                .find(|(other, _)| other == hotkey)
+                .map(|(_, amount)| *amount)
+                .unwrap_or_default()
+        };
+        let incentive: u64 = pending(&smoothed.incentives);
+        let dividend: I96F32 = asfloat!(pending(&smoothed.dividends));
+
+        let is_owner: bool = Owner::<T>::get(hotkey) == *coldkey;
+        let root_prop: I96F32 = Self::get_root_dividend_proportion(hotkey, netuid);
+        let mut own_alpha: u64 =
+            Self::get_stake_for_hotkey_and_coldkey_on_subnet(hotkey, coldkey, netuid);
+        let mut hotkey_alpha: u64 = Self::get_stake_for_hotkey_on_subnet(hotkey, netuid);
+        let mut projected: u64 = 0;
+
+        // Pay anything owed to the hotkey owner first, as it grows the owner's share of the stake.
+        let mut pay_owner = |amount: u64| {
+            hotkey_alpha = hotkey_alpha.saturating_add(amount);
+            if is_owner {
+                own_alpha = own_alpha.saturating_add(amount);
+                projected = projected.saturating_add(amount);
+            }
+        };
+        if SubnetOwnerHotkey::<T>::try_get(netuid).ok().as_ref() != Some(hotkey) {
+            pay_owner(incentive);
+        }
+        let alpha_divs: I96F32 = dividend.saturating_sub(dividend.saturating_mul(root_prop));
+        let alpha_take: I96F32 = Self::get_hotkey_take_float(hotkey).saturating_mul(alpha_divs);
+        pay_owner(tou64!(alpha_take));
+
+        // Split the rest over the hotkey's stake.
+        let nominator_divs: I96F32 = asfloat!(tou64!(alpha_divs.saturating_sub(alpha_take)));
+        let own_share: I96F32 = asfloat!(own_alpha)
+            .checked_div(asfloat!(hotkey_alpha))
+            .unwrap_or(asfloat!(0.0));
+        projected.saturating_add(tou64!(nominator_divs.saturating_mul(own_share)))
     }
 
     pub fn distribute_dividends_and_incentives(
@@ -320,19 +578,8 @@ impl<T: Config> Pallet<T> {
         let mut root_dividends: BTreeMap<T::AccountId, I96F32> = BTreeMap::new();
         let mut alpha_dividends: BTreeMap<T::AccountId, I96F32> = BTreeMap::new();
         for (hotkey, dividend) in dividends {
-            // Get hotkey ALPHA on subnet.
-            let alpha_stake = asfloat!(Self::get_stake_for_hotkey_on_subnet(&hotkey, netuid));
-            // Get hotkey TAO on root.
-            let root_stake: I96F32 = asfloat!(Self::get_stake_for_hotkey_on_subnet(
-                &hotkey,
-                Self::get_root_netuid()
-            ));
-            // Convert TAO to alpha with weight.
-            let root_alpha: I96F32 = root_stake.saturating_mul(Self::get_tao_weight());
-            // Get total from root and local
-            let total_alpha: I96F32 = alpha_stake.saturating_add(root_alpha);
-            // Copmute root prop.
-            let root_prop: I96F32 = root_alpha.checked_div(total_alpha).unwrap_or(zero);
+            // Compute root prop.
+            let root_prop: I96F32 = Self::get_root_dividend_proportion(&hotkey, netuid);
             // Compute root dividends
             let root_divs: I96F32 = dividend.saturating_mul(root_prop);
             // Compute alpha dividends
@@ -387,6 +634,14 @@ impl<T: Config> Pallet<T> {
                     netuid,
                     owner_cut,
                 );
+                if owner_cut > 0 {
+                    Self::maybe_add_stake_provider(&owner_coldkey);
+                }
+                Self::deposit_event(Event::SubnetOwnerCutPaid {
+                    netuid,
+                    coldkey: owner_coldkey,
+                    amount: owner_cut,
+                });
             }
         }
 
@@ -405,12 +660,16 @@ impl<T: Config> Pallet<T> {
                 }
             }
             // Increase stake for miner.
+            let miner_coldkey: T::AccountId = Owner::<T>::get(hotkey.clone());
             Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
                 &hotkey.clone(),
-                &Owner::<T>::get(hotkey.clone()),
+                &miner_coldkey,
                 netuid,
                 incentive,
             );
+            if incentive > 0 {
+                Self::maybe_add_stake_provider(&miner_coldkey);
+            }
         }
 
         // Distribute alpha divs.
@@ -423,12 +682,16 @@ impl<T: Config> Pallet<T> {
             alpha_divs = alpha_divs.saturating_sub(alpha_take);
             // Give the validator their take.
             log::debug!("hotkey: {:?} alpha_take: {:?}", hotkey, alpha_take);
+            let validator_coldkey: T::AccountId = Owner::<T>::get(hotkey.clone());
             Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
                 &hotkey,
-                &Owner::<T>::get(hotkey.clone()),
+                &validator_coldkey,
                 netuid,
                 tou64!(alpha_take),
             );
+            if tou64!(alpha_take) > 0 {
+                Self::maybe_add_stake_provider(&validator_coldkey);
+            }
             // Give all other nominators.
             log::debug!("hotkey: {:?} alpha_divs: {:?}", hotkey, alpha_divs);
             Self::increase_stake_for_hotkey_on_subnet(&hotkey.clone(), netuid, tou64!(alpha_divs));
@@ -447,12 +710,16 @@ impl<T: Config> Pallet<T> {
             root_tao = root_tao.saturating_sub(tao_take);
             // Give the validator their take.
             log::debug!("hotkey: {:?} tao_take: {:?}", hotkey, tao_take);
+            let validator_coldkey: T::AccountId = Owner::<T>::get(hotkey.clone());
             Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
                 &hotkey,
-                &Owner::<T>::get(hotkey.clone()),
+                &validator_coldkey,
                 Self::get_root_netuid(),
                 tou64!(tao_take),
             );
+            if tou64!(tao_take) > 0 {
+                Self::maybe_add_stake_provider(&validator_coldkey);
+            }
             // Give rest to nominators.
             log::debug!("hotkey: {:?} root_tao: {:?}", hotkey, root_tao);
             Self::increase_stake_for_hotkey_on_subnet(
@@ -676,8 +943,46 @@ impl<T: Config> Pallet<T> {
         Self::blocks_until_next_epoch(netuid, Self::get_tempo(netuid), current_block) == 0
     }
 
+    /// Returns true if the subnet runs its epoch at the given block with its current tempo.
+    /// The root subnet and subnets that don't exist never do.
+    pub fn is_epoch_block(netuid: u16, block: u64) -> bool {
+        netuid != Self::get_root_netuid()
+            && Self::if_subnet_exist(netuid)
+            && Self::should_run_epoch(netuid, block)
+    }
+
+    /// Lists the `(netuid, block)` pairs of the epochs subnets run within the next `blocks`
+    /// blocks after the current one, ordered by block and then netuid. The window is capped at
+    /// `MAX_UPCOMING_EPOCHS_WINDOW` blocks to bound the size of the result.
+    pub fn get_upcoming_epochs(blocks: u64) -> Vec<(u16, u64)> {
+        let current_block = Self::get_current_block_as_u64();
+        let first_block = current_block.saturating_add(1);
+        let last_block = current_block.saturating_add(blocks.min(MAX_UPCOMING_EPOCHS_WINDOW));
+
+        let mut upcoming: Vec<(u16, u64)> = Vec::new();
+        for netuid in Self::get_all_subnet_netuids() {
+            if netuid == Self::get_root_netuid() {
+                continue;
+            }
+            let tempo = Self::get_tempo(netuid);
+            let mut block = first_block.saturating_add(Self::blocks_until_next_epoch(
+                netuid,
+                tempo,
+                first_block,
+            ));
+            while block <= last_block {
+                upcoming.push((netuid, block));
+                block = block.saturating_add((tempo as u64).saturating_add(1));
+            }
+        }
+        upcoming.sort_by_key(|(netuid, block)| (*block, *netuid));
+
+        upcoming
+    }
+
     /// Helper function which returns the number of blocks remaining before we will run the epoch on this
-    /// network. Networks run their epoch when (block_number + netuid + 1 ) % (tempo + 1) = 0
+    /// network. Networks run their epoch when (block_number + netuid + 1 + offset) % (tempo + 1) = 0,
+    /// where `offset` is the subnet's `EpochOffset` (zero in the table below).
     /// tempo | netuid | # first epoch block
     ///   1        0               0
     ///   1        1               1
@@ -693,7 +998,9 @@ impl<T: Config> Pallet<T> {
         }
         let netuid_plus_one = (netuid as u64).saturating_add(1);
         let tempo_plus_one = (tempo as u64).saturating_add(1);
-        let adjusted_block = block_number.wrapping_add(netuid_plus_one);
+        let adjusted_block = block_number
+            .wrapping_add(netuid_plus_one)
+            .wrapping_add(Self::get_epoch_offset(netuid));
         let remainder = adjusted_block.checked_rem(tempo_plus_one).unwrap_or(0);
         (tempo as u64).saturating_sub(remainder)
     }
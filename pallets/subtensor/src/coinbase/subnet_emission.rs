@@ -0,0 +1,94 @@
+use super::*;
+use alloc::collections::BTreeMap;
+use substrate_fixed::types::I96F32;
+
+impl<T: Config> Pallet<T> {
+    /// Raises every subnet in `tao_in` to at least `MinSubnetEmission` TAO for the block.
+    ///
+    /// The shortfall is taken from the subnets above the floor in proportion to how far above
+    /// it they are, so the total emitted is unchanged. If the total cannot cover the floor for
+    /// every subnet, it is split evenly instead.
+    pub fn apply_subnet_emission_floor(tao_in: &mut BTreeMap<u16, I96F32>) {
+        let zero: I96F32 = I96F32::saturating_from_num(0);
+        let floor: I96F32 = I96F32::saturating_from_num(Self::get_min_subnet_emission());
+        if floor == zero || tao_in.is_empty() {
+            return;
+        }
+
+        let total: I96F32 = tao_in
+            .values()
+            .fold(zero, |acc, tao_in_i| acc.saturating_add(*tao_in_i));
+        let count: I96F32 = I96F32::saturating_from_num(tao_in.len());
+        if floor.saturating_mul(count) >= total {
+            let share: I96F32 = total.checked_div(count).unwrap_or(zero);
+            tao_in.values_mut().for_each(|tao_in_i| *tao_in_i = share);
+            return;
+        }
+
+        // Since the floor fits, the excess above it is strictly larger than the deficit below it.
+        let mut deficit: I96F32 = zero;
+        let mut excess: I96F32 = zero;
+        for tao_in_i in tao_in.values() {
+            if *tao_in_i < floor {
+                deficit = deficit.saturating_add(floor.saturating_sub(*tao_in_i));
+            } else {
+                excess = excess.saturating_add(tao_in_i.saturating_sub(floor));
+            }
+        }
+        let kept: I96F32 = I96F32::saturating_from_num(1)
+            .saturating_sub(deficit.checked_div(excess).unwrap_or(zero));
+        for tao_in_i in tao_in.values_mut() {
+            *tao_in_i = if *tao_in_i < floor {
+                floor
+            } else {
+                floor.saturating_add(tao_in_i.saturating_sub(floor).saturating_mul(kept))
+            };
+        }
+        log::debug!("tao_in after emission floor: {:?}", tao_in);
+    }
+
+    /// Caps every subnet in `tao_in` at `MaxSubnetEmission` TAO for the block.
+    ///
+    /// The excess above the cap is handed to the subnets below it in proportion to their share,
+    /// repeating while that pushes further subnets over the cap. Excess no subnet can take is
+    /// not emitted.
+    pub fn apply_subnet_emission_cap(tao_in: &mut BTreeMap<u16, I96F32>) {
+        let zero: I96F32 = I96F32::saturating_from_num(0);
+        let max_subnet_emission: u64 = Self::get_max_subnet_emission();
+        if max_subnet_emission == u64::MAX {
+            return;
+        }
+        let cap: I96F32 = I96F32::saturating_from_num(max_subnet_emission);
+
+        // Each round either stops or caps at least one more subnet.
+        for _ in 0..tao_in.len() {
+            let mut excess: I96F32 = zero;
+            let mut uncapped_total: I96F32 = zero;
+            for tao_in_i in tao_in.values_mut() {
+                if *tao_in_i > cap {
+                    excess = excess.saturating_add(tao_in_i.saturating_sub(cap));
+                    *tao_in_i = cap;
+                } else if *tao_in_i < cap {
+                    uncapped_total = uncapped_total.saturating_add(*tao_in_i);
+                }
+            }
+            if excess == zero || uncapped_total == zero {
+                break;
+            }
+            for tao_in_i in tao_in.values_mut() {
+                if *tao_in_i < cap {
+                    let share: I96F32 = excess
+                        .saturating_mul(*tao_in_i)
+                        .checked_div(uncapped_total)
+                        .unwrap_or(zero);
+                    *tao_in_i = tao_in_i.saturating_add(share);
+                }
+            }
+        }
+        // Any excess left from the last round.
+        tao_in
+            .values_mut()
+            .for_each(|tao_in_i| *tao_in_i = (*tao_in_i).min(cap));
+        log::debug!("tao_in after emission cap: {:?}", tao_in);
+    }
+}
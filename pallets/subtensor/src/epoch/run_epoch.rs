@@ -40,6 +40,14 @@ impl<T: Config> Pallet<T> {
         // Logical negation of inactive.
         let active: Vec<bool> = inactive.iter().map(|&b| !b).collect();
 
+        // Weights set more than max_weight_age blocks ago are stale and left out of consensus.
+        let max_weight_age: u64 = Self::get_max_weight_age(netuid);
+        let stale_weights: Vec<bool> = last_update
+            .iter()
+            .map(|updated| current_block.saturating_sub(*updated) > max_weight_age)
+            .collect();
+        log::trace!("Stale weights:\n{:?}\n", &stale_weights);
+
         // Block at registration vector (block when each neuron was most recently registered).
         let block_at_registration: Vec<u64> = Self::get_block_at_registration(netuid);
         log::trace!("Block at registration:\n{:?}\n", &block_at_registration);
@@ -107,6 +115,13 @@ impl<T: Config> Pallet<T> {
         inplace_normalize(&mut active_stake);
         log::trace!("S:\n{:?}\n", &active_stake);
 
+        // Stake of validators with stale weights is left out of consensus and bonds, rather than
+        // counting as a vote for nothing.
+        let mut consensus_stake: Vec<I32F32> = active_stake.clone();
+        inplace_mask_vector(&stale_weights, &mut consensus_stake);
+        inplace_normalize(&mut consensus_stake);
+        log::trace!("S (fresh weights):\n{:?}\n", &consensus_stake);
+
         // =============
         // == Weights ==
         // =============
@@ -122,6 +137,10 @@ impl<T: Config> Pallet<T> {
         inplace_mask_rows(&validator_forbids, &mut weights);
         log::trace!("W (permit): {:?}", &weights);
 
+        // Mask weights that are older than the max weight age.
+        inplace_mask_rows(&stale_weights, &mut weights);
+        log::trace!("W (permit+age): {:?}", &weights);
+
         // Remove self-weight by masking diagonal; keep owner_uid self-weight.
         if let Some(owner_uid) = owner_uid {
             inplace_mask_diag_except_index(&mut weights, owner_uid);
@@ -145,12 +164,12 @@ impl<T: Config> Pallet<T> {
         // ================================
 
         // Compute preranks: r_j = SUM(i) w_ij * s_i
-        let preranks: Vec<I32F32> = matmul(&weights, &active_stake);
+        let preranks: Vec<I32F32> = matmul(&weights, &consensus_stake);
 
         // Consensus majority ratio, e.g. 51%.
         let kappa: I32F32 = Self::get_float_kappa(netuid);
         // Calculate consensus as stake-weighted median of weights.
-        let consensus: Vec<I32F32> = weighted_median_col(&active_stake, &weights, kappa);
+        let consensus: Vec<I32F32> = weighted_median_col(&consensus_stake, &weights, kappa);
         // Clip weights at majority consensus.
         let mut clipped_weights: Vec<Vec<I32F32>> = weights.clone();
         inplace_col_clip(&mut clipped_weights, &consensus);
@@ -162,7 +181,7 @@ impl<T: Config> Pallet<T> {
         // ====================================
 
         // Compute ranks: r_j = SUM(i) w_ij * s_i
-        let mut ranks: Vec<I32F32> = matmul(&clipped_weights, &active_stake);
+        let mut ranks: Vec<I32F32> = matmul(&clipped_weights, &consensus_stake);
 
         // Compute server trust: ratio of rank after vs. rank before.
         let trust: Vec<I32F32> = vecdiv(&ranks, &preranks);
@@ -190,7 +209,7 @@ impl<T: Config> Pallet<T> {
         log::trace!("B:\n{:?}\n", &bonds);
 
         // Compute bonds delta column normalized.
-        let mut bonds_delta: Vec<Vec<I32F32>> = row_hadamard(&weights_for_bonds, &active_stake); // ΔB = W◦S
+        let mut bonds_delta: Vec<Vec<I32F32>> = row_hadamard(&weights_for_bonds, &consensus_stake); // ΔB = W◦S
         inplace_col_normalize(&mut bonds_delta); // sum_i b_ij = 1
         log::trace!("ΔB:\n{:?}\n", &bonds_delta);
         // Compute the Exponential Moving Average (EMA) of bonds.
@@ -221,13 +240,20 @@ impl<T: Config> Pallet<T> {
         let mut normalized_server_emission: Vec<I32F32> = incentive.clone(); // Servers get incentive.
         let mut normalized_validator_emission: Vec<I32F32> = dividends.clone(); // Validators get dividends.
         let mut normalized_combined_emission: Vec<I32F32> = combined_emission.clone();
+        // Weigh incentive against dividends by the subnet's validator emission ratio, if set.
+        let split_sum: I32F32 = Self::weigh_emission_split(
+            netuid,
+            &mut normalized_server_emission,
+            &mut normalized_validator_emission,
+        )
+        .unwrap_or(emission_sum);
         // Normalize on the sum of incentive + dividends.
-        inplace_normalize_using_sum(&mut normalized_server_emission, emission_sum);
-        inplace_normalize_using_sum(&mut normalized_validator_emission, emission_sum);
+        inplace_normalize_using_sum(&mut normalized_server_emission, split_sum);
+        inplace_normalize_using_sum(&mut normalized_validator_emission, split_sum);
         inplace_normalize(&mut normalized_combined_emission);
 
         // If emission is zero, replace emission with normalized stake.
-        if emission_sum == I32F32::from(0) {
+        if split_sum == I32F32::from(0) {
             // no weights set | outdated weights | self_weights
             if is_zero(&active_stake) {
                 // no active stake
@@ -404,6 +430,14 @@ impl<T: Config> Pallet<T> {
         // Logical negation of inactive.
         let active: Vec<bool> = inactive.iter().map(|&b| !b).collect();
 
+        // Weights set more than max_weight_age blocks ago are stale and left out of consensus.
+        let max_weight_age: u64 = Self::get_max_weight_age(netuid);
+        let stale_weights: Vec<bool> = last_update
+            .iter()
+            .map(|updated| current_block.saturating_sub(*updated) > max_weight_age)
+            .collect();
+        log::debug!("Stale weights: {:?}", &stale_weights);
+
         // Block at registration vector (block when each neuron was most recently registered).
         let block_at_registration: Vec<u64> = Self::get_block_at_registration(netuid);
         log::trace!("Block at registration: {:?}", &block_at_registration);
@@ -459,6 +493,13 @@ impl<T: Config> Pallet<T> {
         inplace_normalize(&mut active_stake);
         log::debug!("Active Stake:\n{:?}\n", &active_stake);
 
+        // Stake of validators with stale weights is left out of consensus and bonds, rather than
+        // counting as a vote for nothing.
+        let mut consensus_stake: Vec<I32F32> = active_stake.clone();
+        inplace_mask_vector(&stale_weights, &mut consensus_stake);
+        inplace_normalize(&mut consensus_stake);
+        log::debug!("Consensus Stake:\n{:?}\n", &consensus_stake);
+
         // =============
         // == Weights ==
         // =============
@@ -473,6 +514,10 @@ impl<T: Config> Pallet<T> {
         weights = mask_rows_sparse(&validator_forbids, &weights);
         log::trace!("Weights (permit): {:?}", &weights);
 
+        // Mask weights that are older than the max weight age.
+        weights = mask_rows_sparse(&stale_weights, &weights);
+        log::trace!("Weights (permit+age): {:?}", &weights);
+
         // Remove self-weight by masking diagonal; keep owner_uid self-weight.
         if let Some(owner_uid) = owner_uid {
             weights = mask_diag_sparse_except_index(&weights, owner_uid);
@@ -499,13 +544,14 @@ impl<T: Config> Pallet<T> {
         // ================================
 
         // Compute preranks: r_j = SUM(i) w_ij * s_i
-        let preranks: Vec<I32F32> = matmul_sparse(&weights, &active_stake, n);
+        let preranks: Vec<I32F32> = matmul_sparse(&weights, &consensus_stake, n);
         log::trace!("Ranks (before): {:?}", &preranks);
 
         // Consensus majority ratio, e.g. 51%.
         let kappa: I32F32 = Self::get_float_kappa(netuid);
         // Calculate consensus as stake-weighted median of weights.
-        let consensus: Vec<I32F32> = weighted_median_col_sparse(&active_stake, &weights, n, kappa);
+        let consensus: Vec<I32F32> =
+            weighted_median_col_sparse(&consensus_stake, &weights, n, kappa);
         log::trace!("Consensus: {:?}", &consensus);
 
         // Clip weights at majority consensus.
@@ -521,7 +567,7 @@ impl<T: Config> Pallet<T> {
         // =============================
 
         // Compute ranks: r_j = SUM(i) w_ij * s_i.
-        let mut ranks: Vec<I32F32> = matmul_sparse(&clipped_weights, &active_stake, n);
+        let mut ranks: Vec<I32F32> = matmul_sparse(&clipped_weights, &consensus_stake, n);
         log::trace!("Ranks (after): {:?}", &ranks);
 
         // Compute server trust: ratio of rank after vs. rank before.
@@ -563,7 +609,7 @@ impl<T: Config> Pallet<T> {
 
         // Compute bonds delta column normalized.
         let mut bonds_delta: Vec<Vec<(u16, I32F32)>> =
-            row_hadamard_sparse(&weights_for_bonds, &active_stake); // ΔB = W◦S (outdated W masked)
+            row_hadamard_sparse(&weights_for_bonds, &consensus_stake); // ΔB = W◦S (outdated W masked)
         log::trace!("ΔB: {:?}", &bonds_delta);
 
         // Normalize bonds delta.
@@ -598,13 +644,20 @@ impl<T: Config> Pallet<T> {
         let mut normalized_server_emission: Vec<I32F32> = incentive.clone(); // Servers get incentive.
         let mut normalized_validator_emission: Vec<I32F32> = dividends.clone(); // Validators get dividends.
         let mut normalized_combined_emission: Vec<I32F32> = combined_emission.clone();
+        // Weigh incentive against dividends by the subnet's validator emission ratio, if set.
+        let split_sum: I32F32 = Self::weigh_emission_split(
+            netuid,
+            &mut normalized_server_emission,
+            &mut normalized_validator_emission,
+        )
+        .unwrap_or(emission_sum);
         // Normalize on the sum of incentive + dividends.
-        inplace_normalize_using_sum(&mut normalized_server_emission, emission_sum);
-        inplace_normalize_using_sum(&mut normalized_validator_emission, emission_sum);
+        inplace_normalize_using_sum(&mut normalized_server_emission, split_sum);
+        inplace_normalize_using_sum(&mut normalized_validator_emission, split_sum);
         inplace_normalize(&mut normalized_combined_emission);
 
         // If emission is zero, replace emission with normalized stake.
-        if emission_sum == I32F32::from(0) {
+        if split_sum == I32F32::from(0) {
             // no weights set | outdated weights | self_weights
             if is_zero(&active_stake) {
                 // no active stake
@@ -747,6 +800,27 @@ impl<T: Config> Pallet<T> {
             .collect()
     }
 
+    /// Scales incentive by the miner share and dividends by the validator share of the subnet's
+    /// `ValidatorEmissionRatio`, and returns the sum to normalize both on. Returns `None` when the
+    /// subnet has no ratio set, in which case incentive and dividends weigh the same.
+    pub fn weigh_emission_split(
+        netuid: u16,
+        incentive: &mut [I32F32],
+        dividends: &mut [I32F32],
+    ) -> Option<I32F32> {
+        let validator_share: I32F32 =
+            u16_proportion_to_fixed(Self::get_validator_emission_ratio(netuid)?);
+        let server_share: I32F32 = I32F32::saturating_from_num(1).saturating_sub(validator_share);
+        incentive
+            .iter_mut()
+            .for_each(|value| *value = value.saturating_mul(server_share));
+        dividends
+            .iter_mut()
+            .for_each(|value| *value = value.saturating_mul(validator_share));
+
+        Some(sum(incentive).saturating_add(sum(dividends)))
+    }
+
     pub fn get_float_rho(netuid: u16) -> I32F32 {
         I32F32::saturating_from_num(Self::get_rho(netuid))
     }
@@ -57,6 +57,39 @@ extern crate alloc;
 
 pub const MAX_CRV3_COMMIT_SIZE_BYTES: u32 = 5000;
 
+/// Maximum number of hotkeys registered by a single batch_register call.
+pub const MAX_BATCH_REGISTRATIONS: u32 = 16;
+
+/// Maximum number of nominations unstaked by a single recycle_deregistered_stake call.
+pub const MAX_RECYCLED_NOMINATIONS: u32 = 64;
+
+/// Maximum number of a hotkey's stake entries read by a single recycle_deregistered_stake call.
+pub const MAX_RECYCLE_SCANNED_ENTRIES: u32 = 256;
+
+/// How far, in percent, recycling deregistered stake may push the alpha price below the subnet's
+/// moving price.
+pub const MAX_RECYCLE_SLIPPAGE_PERCENT: u64 = 5;
+
+/// Maximum number of storage reads spent checking whether a coldkey still has stake before its
+/// stake provider reference is released.
+pub const MAX_STAKE_PROVIDER_SCAN_READS: u32 = 64;
+
+/// Maximum number of volume based staking fee tiers.
+pub const MAX_STAKING_FEE_VOLUME_TIERS: usize = 16;
+
+/// Number of blocks summed up in one bucket of the recent volume ring buffer.
+pub const RECENT_VOLUME_BUCKET_BLOCKS: u64 = 300;
+/// Number of buckets in the recent volume ring buffer, together covering about 24 hours.
+pub const RECENT_VOLUME_BUCKETS: u64 = 24;
+
+/// Difficulty adjustment scaling the difficulty linearly with the registration rate.
+pub const DIFFICULTY_ALGO_LINEAR: u8 = 0;
+/// Difficulty adjustment scaling the difficulty exponentially with the registration rate.
+pub const DIFFICULTY_ALGO_EXPONENTIAL: u8 = 1;
+
+/// Maximum number of blocks ahead the upcoming epochs runtime API looks, about one day.
+pub const MAX_UPCOMING_EPOCHS_WINDOW: u64 = 7200;
+
 #[deny(missing_docs)]
 #[import_section(errors::errors)]
 #[import_section(events::events)]
@@ -99,7 +132,7 @@ pub mod pallet {
 
     /// Tracks version for migrations. Should be monotonic with respect to the
     /// order of migrations. (i.e. always increasing)
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(7);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(10);
 
     /// Minimum balance required to perform a coldkey swap
     pub const MIN_BALANCE_TO_PERFORM_COLDKEY_SWAP: u64 = 100_000_000; // 0.1 TAO in RAO
@@ -268,6 +301,22 @@ pub mod pallet {
         /// Additional information about the subnet
         pub additional: Vec<u8>,
     }
+
+    /// Data structure for the part of a drained emission that is still being paid out.
+    #[crate::freeze_struct("300184431b8dc0a0")]
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct SmoothedEmission<AccountId> {
+        /// The number of blocks left to pay out the remaining emission over.
+        pub blocks_remaining: u64,
+        /// The remaining root dividends in TAO.
+        pub pending_tao: u64,
+        /// The remaining subnet owner cut.
+        pub owner_cut: u64,
+        /// The remaining incentive per hotkey.
+        pub incentives: Vec<(AccountId, u64)>,
+        /// The remaining dividends per hotkey.
+        pub dividends: Vec<(AccountId, u64)>,
+    }
     /// ============================
     /// ==== Staking + Accounts ====
     /// ============================
@@ -347,6 +396,11 @@ pub mod pallet {
         1_000_000_000
     }
     #[pallet::type_value]
+    /// Default maximum emission per subnet per block, i.e. uncapped.
+    pub fn DefaultMaxSubnetEmission<T: Config>() -> u64 {
+        u64::MAX
+    }
+    #[pallet::type_value]
     /// Default allowed delegation.
     pub fn DefaultAllowsDelegation<T: Config>() -> bool {
         false
@@ -506,6 +560,21 @@ pub mod pallet {
         T::InitialSubnetOwnerCut::get()
     }
     #[pallet::type_value]
+    /// Default value for the minimum subnet owner cut.
+    pub fn DefaultMinOwnerCut<T: Config>() -> u16 {
+        0
+    }
+    #[pallet::type_value]
+    /// Default value for the maximum subnet owner cut.
+    pub fn DefaultMaxOwnerCut<T: Config>() -> u16 {
+        u16::MAX
+    }
+    #[pallet::type_value]
+    /// Default value for the chain-wide max registrations per block.
+    pub fn DefaultMaxTotalRegistrationsPerBlock<T: Config>() -> u16 {
+        u16::MAX
+    }
+    #[pallet::type_value]
     /// Default value for network rate limit.
     pub fn DefaultNetworkRateLimit<T: Config>() -> u64 {
         if cfg!(feature = "pow-faucet") {
@@ -525,6 +594,11 @@ pub mod pallet {
         0
     }
     #[pallet::type_value]
+    /// Default number of blocks a drained emission is paid out over.
+    pub fn DefaultEmissionSmoothingBlocks<T: Config>() -> u64 {
+        1
+    }
+    #[pallet::type_value]
     /// Default value for blocks since last step.
     pub fn DefaultBlocksSinceLastStep<T: Config>() -> u64 {
         0
@@ -551,6 +625,11 @@ pub mod pallet {
         T::InitialTempo::get()
     }
     #[pallet::type_value]
+    /// Default value for the minimum tempo a subnet can be set to.
+    pub fn DefaultMinTempo<T: Config>() -> u16 {
+        T::InitialMinTempo::get()
+    }
+    #[pallet::type_value]
     /// Default value for weights set rate limit.
     pub fn DefaultWeightsSetRateLimit<T: Config>() -> u64 {
         100
@@ -586,6 +665,11 @@ pub mod pallet {
         T::InitialActivityCutoff::get()
     }
     #[pallet::type_value]
+    /// Default maximum weight age, i.e. weights never expire.
+    pub fn DefaultMaxWeightAge<T: Config>() -> u64 {
+        u64::MAX
+    }
+    #[pallet::type_value]
     /// Default maximum weights limit.
     pub fn DefaultMaxWeightsLimit<T: Config>() -> u16 {
         T::InitialMaxWeightsLimit::get()
@@ -641,6 +725,11 @@ pub mod pallet {
         T::InitialAdjustmentAlpha::get()
     }
     #[pallet::type_value]
+    /// Default difficulty adjustment algorithm.
+    pub fn DefaultDifficultyAlgo<T: Config>() -> u8 {
+        DIFFICULTY_ALGO_LINEAR
+    }
+    #[pallet::type_value]
     /// Default minimum stake for weights.
     pub fn DefaultStakeThreshold<T: Config>() -> u64 {
         0
@@ -720,6 +809,11 @@ pub mod pallet {
         T::InitialSenateRequiredStakePercentage::get()
     }
     #[pallet::type_value]
+    /// Default minimum stake required to submit a governance proposal.
+    pub fn DefaultProposalMinStake<T: Config>() -> u64 {
+        0
+    }
+    #[pallet::type_value]
     /// -- ITEM (switches liquid alpha on)
     pub fn DefaultLiquidAlpha<T: Config>() -> bool {
         false
@@ -816,6 +910,11 @@ pub mod pallet {
     pub type SenateRequiredStakePercentage<T> =
         StorageValue<_, u64, ValueQuery, DefaultSenateRequiredStakePercentage<T>>;
 
+    #[pallet::storage]
+    /// ITEM( proposal_min_stake ) The minimum stake a non-senate proposer's hotkey must hold to
+    /// submit a governance proposal.
+    pub type ProposalMinStake<T> = StorageValue<_, u64, ValueQuery, DefaultProposalMinStake<T>>;
+
     /// ============================
     /// ==== Staking Variables ====
     /// ============================
@@ -928,6 +1027,12 @@ pub mod pallet {
     /// --- ITEM ( global_block_emission )
     pub type BlockEmission<T> = StorageValue<_, u64, ValueQuery, DefaultBlockEmission<T>>;
     #[pallet::storage]
+    /// --- ITEM ( min_subnet_emission ) | The least TAO each emitting subnet receives per block, 0 for no floor.
+    pub type MinSubnetEmission<T> = StorageValue<_, u64, ValueQuery, DefaultZeroU64<T>>;
+    #[pallet::storage]
+    /// --- ITEM ( max_subnet_emission ) | The most TAO a single subnet receives per block, uncapped by default.
+    pub type MaxSubnetEmission<T> = StorageValue<_, u64, ValueQuery, DefaultMaxSubnetEmission<T>>;
+    #[pallet::storage]
     /// --- DMap ( hot, netuid ) --> emission | last hotkey emission on network.
     pub type LastHotkeyEmissionOnNetuid<T: Config> = StorageDoubleMap<
         _,
@@ -966,6 +1071,8 @@ pub mod pallet {
     /// separate accounting.
     #[pallet::storage] // --- ITEM ( total_issuance )
     pub type TotalIssuance<T> = StorageValue<_, u64, ValueQuery, DefaultTotalIssuance<T>>;
+    #[pallet::storage] // --- ITEM ( max_total_issuance ) -- emission stops once total issuance reaches it.
+    pub type MaxTotalIssuance<T> = StorageValue<_, u64, ValueQuery, TotalSupply<T>>;
     #[pallet::storage] // --- ITEM ( total_stake )
     pub type TotalStake<T> = StorageValue<_, u64, ValueQuery>;
     #[pallet::storage] // --- ITEM ( dynamic_block ) -- block when dynamic was turned on.
@@ -978,6 +1085,11 @@ pub mod pallet {
     #[pallet::storage] // --- MAP ( netuid ) --> total_volume | The total amount of TAO bought and sold since the start of the network.
     pub type SubnetVolume<T: Config> =
         StorageMap<_, Identity, u16, u128, ValueQuery, DefaultZeroU128<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> recent_volume | (bucket, TAO volume) pairs of the last RECENT_VOLUME_BUCKETS buckets, oldest first.
+    pub type SubnetRecentVolume<T: Config> =
+        StorageMap<_, Identity, u16, Vec<(u64, u128)>, ValueQuery>;
+    #[pallet::storage] // --- ITEM ( staking_fee_volume_tiers ) -- (volume threshold, staking fee) pairs, sorted by threshold. Empty means a flat fee.
+    pub type StakingFeeVolumeTiers<T> = StorageValue<_, Vec<(u128, u64)>, ValueQuery>;
     #[pallet::storage] // --- MAP ( netuid ) --> tao_in_subnet | Returns the amount of TAO in the subnet.
     pub type SubnetTAO<T: Config> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultZeroU64<T>>;
@@ -1005,6 +1117,9 @@ pub mod pallet {
     #[pallet::storage] // --- MAP ( cold ) --> Vec<hot> | Maps coldkey to hotkeys that stake to it
     pub type StakingHotkeys<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, Vec<T::AccountId>, ValueQuery>;
+    #[pallet::storage] // --- MAP ( cold ) --> bool | Whether the coldkey holds a provider reference that keeps it alive while it has stake.
+    pub type StakeProviders<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
     #[pallet::storage] // --- MAP ( cold ) --> Vec<hot> | Returns the vector of hotkeys controlled by this coldkey.
     pub type OwnedHotkeys<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, Vec<T::AccountId>, ValueQuery>;
@@ -1047,6 +1162,27 @@ pub mod pallet {
         U64F64, // Shares
         ValueQuery,
     >;
+    #[pallet::storage] // --- NMAP ( hot, cold, netuid ) --> (alpha, until_block) | Returns the locked alpha for a hotkey, coldkey, netuid triplet and the block it unlocks at.
+    pub type LockedStake<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, T::AccountId>, // hot
+            NMapKey<Blake2_128Concat, T::AccountId>, // cold
+            NMapKey<Identity, u16>,                  // subnet
+        ),
+        (u64, u64), // (alpha_locked, until_block)
+        OptionQuery,
+    >;
+    #[pallet::storage] // --- DMAP ( netuid, hot ) --> last scanned ( cold, netuid ) | Hotkeys pruned from a subnet whose nominations can be recycled, and the stake entry the last recycle stopped at.
+    pub type PrunedHotkeys<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Blake2_128Concat,
+        T::AccountId,
+        Option<(T::AccountId, u16)>,
+        OptionQuery,
+    >;
     #[pallet::storage] // --- MAP ( netuid ) --> token_symbol | Returns the token symbol for a subnet.
     pub type TokenSymbol<T: Config> =
         StorageMap<_, Identity, u16, Vec<u8>, ValueQuery, DefaultUnicodeVecU8<T>>;
@@ -1065,6 +1201,13 @@ pub mod pallet {
     pub type MaxRegistrationsPerBlock<T> =
         StorageMap<_, Identity, u16, u16, ValueQuery, DefaultMaxRegistrationsPerBlock<T>>;
     #[pallet::storage]
+    /// --- ITEM( max_registrations_per_block across all subnets )
+    pub type MaxTotalRegistrationsPerBlock<T> =
+        StorageValue<_, u16, ValueQuery, DefaultMaxTotalRegistrationsPerBlock<T>>;
+    #[pallet::storage]
+    /// --- ITEM( registrations_this_block across all subnets )
+    pub type TotalRegistrationsThisBlock<T> = StorageValue<_, u16, ValueQuery>;
+    #[pallet::storage]
     /// --- ITEM( total_number_of_existing_networks )
     pub type TotalNetworks<T> = StorageValue<_, u16, ValueQuery>;
     #[pallet::storage]
@@ -1094,10 +1237,18 @@ pub mod pallet {
     /// ITEM( subnet_owner_cut )
     pub type SubnetOwnerCut<T> = StorageValue<_, u16, ValueQuery, DefaultSubnetOwnerCut<T>>;
     #[pallet::storage]
+    /// ITEM( min_owner_cut )
+    pub type MinOwnerCut<T> = StorageValue<_, u16, ValueQuery, DefaultMinOwnerCut<T>>;
+    #[pallet::storage]
+    /// ITEM( max_owner_cut )
+    pub type MaxOwnerCut<T> = StorageValue<_, u16, ValueQuery, DefaultMaxOwnerCut<T>>;
+    #[pallet::storage]
     /// ITEM( network_rate_limit )
     pub type NetworkRateLimit<T> = StorageValue<_, u64, ValueQuery, DefaultNetworkRateLimit<T>>;
     #[pallet::storage] // --- ITEM( nominator_min_required_stake )
     pub type NominatorMinRequiredStake<T> = StorageValue<_, u64, ValueQuery, DefaultZeroU64<T>>;
+    #[pallet::storage] // --- ITEM( min_stake_amount ) -- smallest TAO amount a single stake or unstake may move.
+    pub type MinStakeAmount<T> = StorageValue<_, u64, ValueQuery, DefaultZeroU64<T>>;
     #[pallet::storage]
     /// ITEM( weights_version_key_rate_limit ) --- Rate limit in tempos.
     pub type WeightsVersionKeyRateLimit<T> =
@@ -1109,6 +1260,11 @@ pub mod pallet {
     #[pallet::storage] // --- MAP ( netuid ) --> transfer_toggle
     pub type TransferToggle<T: Config> =
         StorageMap<_, Identity, u16, bool, ValueQuery, DefaultTrue<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> whether only whitelisted coldkeys may stake
+    pub type StakeWhitelist<T: Config> = StorageMap<_, Identity, u16, bool, ValueQuery>;
+    #[pallet::storage] // --- DMAP ( netuid, coldkey ) --> whether the coldkey may stake under the whitelist
+    pub type StakeAllowedColdkeys<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
     #[pallet::storage] // --- MAP ( netuid ) --> total_subnet_locked
     pub type SubnetLocked<T: Config> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultZeroU64<T>>;
@@ -1121,6 +1277,10 @@ pub mod pallet {
     /// =================
     #[pallet::storage] // --- MAP ( netuid ) --> tempo
     pub type Tempo<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultTempo<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> blocks the subnet's epoch boundary is shifted by
+    pub type EpochOffset<T> = StorageMap<_, Identity, u16, u64, ValueQuery, DefaultZeroU64<T>>;
+    #[pallet::storage] // --- ITEM ( min_tempo )
+    pub type MinTempo<T> = StorageValue<_, u16, ValueQuery, DefaultMinTempo<T>>;
 
     /// ============================
     /// ==== Subnet Parameters =====
@@ -1177,6 +1337,14 @@ pub mod pallet {
     /// --- MAP ( netuid ) --> pending_owner_cut
     pub type PendingOwnerCut<T> = StorageMap<_, Identity, u16, u64, ValueQuery, DefaultZeroU64<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> emission_smoothing_blocks
+    pub type EmissionSmoothingBlocks<T> =
+        StorageMap<_, Identity, u16, u64, ValueQuery, DefaultEmissionSmoothingBlocks<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> emission drained at the last epoch that is still being paid out
+    pub type PendingSmoothedEmission<T: Config> =
+        StorageMap<_, Identity, u16, SmoothedEmission<T::AccountId>, OptionQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> blocks_since_last_step
     pub type BlocksSinceLastStep<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultBlocksSinceLastStep<T>>;
@@ -1229,6 +1397,14 @@ pub mod pallet {
     pub type ActivityCutoff<T> =
         StorageMap<_, Identity, u16, u16, ValueQuery, DefaultActivityCutoff<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> max_weight_age
+    pub type MaxWeightAge<T> =
+        StorageMap<_, Identity, u16, u64, ValueQuery, DefaultMaxWeightAge<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> share of the emission paid to validators, as a fraction of u16::MAX.
+    /// Unset subnets weigh incentive and dividends the same.
+    pub type ValidatorEmissionRatio<T> = StorageMap<_, Identity, u16, u16, OptionQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> max_weight_limit
     pub type MaxWeightsLimit<T> =
         StorageMap<_, Identity, u16, u16, ValueQuery, DefaultMaxWeightsLimit<T>>;
@@ -1277,6 +1453,10 @@ pub mod pallet {
     pub type AdjustmentAlpha<T: Config> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultAdjustmentAlpha<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> difficulty_algo
+    pub type DifficultyAlgo<T: Config> =
+        StorageMap<_, Identity, u16, u8, ValueQuery, DefaultDifficultyAlgo<T>>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> commit reveal v2 weights are enabled
     pub type CommitRevealWeightsEnabled<T> =
         StorageMap<_, Identity, u16, bool, ValueQuery, DefaultCommitRevealWeightsEnabled<T>>;
@@ -1305,6 +1485,9 @@ pub mod pallet {
     pub type LastAdjustmentBlock<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultLastAdjustmentBlock<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> Difficulty change at last adjustment.
+    pub type LastDifficultyDelta<T> = StorageMap<_, Identity, u16, i64, ValueQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> Registrations of this Block.
     pub type RegistrationsThisBlock<T> =
         StorageMap<_, Identity, u16, u16, ValueQuery, DefaultRegistrationsThisBlock<T>>;
@@ -1393,6 +1576,9 @@ pub mod pallet {
     pub type PruningScores<T: Config> =
         StorageMap<_, Identity, u16, Vec<u16>, ValueQuery, EmptyU16Vec<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> whether pruning score ties evict the most recently registered uid
+    pub type PruneNewestOnTie<T: Config> = StorageMap<_, Identity, u16, bool, ValueQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> validator_permit
     pub type ValidatorPermit<T: Config> =
         StorageMap<_, Identity, u16, Vec<bool>, ValueQuery, EmptyBoolVec<T>>;
@@ -1626,6 +1812,11 @@ pub mod pallet {
             {
                 return false;
             }
+            if Self::get_total_registrations_this_block()
+                >= Self::get_max_total_registrations_per_block()
+            {
+                return false;
+            }
             if Self::get_registrations_this_interval(netuid)
                 >= Self::get_target_registrations_per_interval(netuid).saturating_mul(3)
             {
@@ -1668,6 +1859,7 @@ pub enum CustomTransactionError {
     InvalidIpAddress,
     ServingRateLimitExceeded,
     InvalidPort,
+    NotWhitelisted,
     BadRequest,
 }
 
@@ -1688,6 +1880,7 @@ impl From<CustomTransactionError> for u8 {
             CustomTransactionError::InvalidIpAddress => 11,
             CustomTransactionError::ServingRateLimitExceeded => 12,
             CustomTransactionError::InvalidPort => 13,
+            CustomTransactionError::NotWhitelisted => 14,
             CustomTransactionError::BadRequest => 255,
         }
     }
@@ -1743,7 +1936,9 @@ where
     pub fn result_to_validity(result: Result<(), Error<T>>, priority: u64) -> TransactionValidity {
         if let Err(err) = result {
             match err {
-                Error::<T>::AmountTooLow => Err(InvalidTransaction::Custom(
+                Error::<T>::AmountTooLow
+                | Error::<T>::AmountTooSmall
+                | Error::<T>::StakeAmountTooLow => Err(InvalidTransaction::Custom(
                     CustomTransactionError::StakeAmountTooLow.into(),
                 )
                 .into()),
@@ -1791,6 +1986,10 @@ where
                     CustomTransactionError::InvalidPort.into(),
                 )
                 .into()),
+                Error::<T>::NotWhitelisted => Err(InvalidTransaction::Custom(
+                    CustomTransactionError::NotWhitelisted.into(),
+                )
+                .into()),
                 _ => Err(
                     InvalidTransaction::Custom(CustomTransactionError::BadRequest.into()).into(),
                 ),
@@ -1999,6 +2198,31 @@ where
                     Self::get_priority_staking(who, hotkey, *amount_unstaked),
                 )
             }
+            Some(Call::remove_stake_partial {
+                hotkey,
+                netuid,
+                alpha_requested,
+            }) => {
+                // Validate the clamped amount that will actually be unstaked
+                let alpha_unstaked = (*alpha_requested).min(
+                    Pallet::<T>::get_unlocked_stake_for_hotkey_and_coldkey_on_subnet(
+                        hotkey, who, *netuid,
+                    ),
+                );
+
+                // Fully validate the user input
+                Self::result_to_validity(
+                    Pallet::<T>::validate_remove_stake(
+                        who,
+                        hotkey,
+                        *netuid,
+                        alpha_unstaked,
+                        alpha_unstaked,
+                        false,
+                    ),
+                    Self::get_priority_staking(who, hotkey, alpha_unstaked),
+                )
+            }
             Some(Call::remove_stake_limit {
                 hotkey,
                 netuid,
@@ -2153,7 +2377,11 @@ where
                     Self::get_priority_staking(who, hotkey, *alpha_amount),
                 )
             }
-            Some(Call::register { netuid, .. } | Call::burned_register { netuid, .. }) => {
+            Some(
+                Call::register { netuid, .. }
+                | Call::burned_register { netuid, .. }
+                | Call::batch_register { netuid, .. },
+            ) => {
                 if ColdkeySwapScheduled::<T>::contains_key(who) {
                     return InvalidTransaction::Custom(
                         CustomTransactionError::ColdkeyInSwapSchedule.into(),
@@ -66,6 +66,9 @@ mod config {
         /// Tempo for each network.
         #[pallet::constant]
         type InitialTempo: Get<u16>;
+        /// Lowest tempo a subnet can be set to, besides 0.
+        #[pallet::constant]
+        type InitialMinTempo: Get<u16>;
         /// Initial Difficulty.
         #[pallet::constant]
         type InitialDifficulty: Get<u64>;
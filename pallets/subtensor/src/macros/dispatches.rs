@@ -621,7 +621,8 @@ mod dispatches {
         #[pallet::weight((Weight::from_parts(111_000_000, 0)
 		.saturating_add(Weight::from_parts(0, 43991))
 		.saturating_add(T::DbWeight::get().reads(10))
-		.saturating_add(T::DbWeight::get().writes(7)), DispatchClass::Normal, Pays::No))]
+		.saturating_add(T::DbWeight::get().writes(7))
+		.saturating_add(T::DbWeight::get().reads(u64::from(MAX_STAKE_PROVIDER_SCAN_READS))), DispatchClass::Normal, Pays::No))]
         pub fn remove_stake(
             origin: OriginFor<T>,
             hotkey: T::AccountId,
@@ -1183,7 +1184,8 @@ mod dispatches {
         #[pallet::call_index(59)]
         #[pallet::weight((Weight::from_parts(157_000_000, 0)
 		.saturating_add(T::DbWeight::get().reads(16))
-		.saturating_add(T::DbWeight::get().writes(30)), DispatchClass::Operational, Pays::No))]
+		.saturating_add(T::DbWeight::get().writes(30))
+		.saturating_add(Self::get_free_epoch_offset_weight()), DispatchClass::Operational, Pays::No))]
         pub fn register_network(origin: OriginFor<T>, hotkey: T::AccountId) -> DispatchResult {
             Self::do_register_network(origin, &hotkey, 1, None)
         }
@@ -1521,7 +1523,8 @@ mod dispatches {
         #[pallet::call_index(79)]
         #[pallet::weight((Weight::from_parts(157_000_000, 0)
                 .saturating_add(T::DbWeight::get().reads(16))
-                .saturating_add(T::DbWeight::get().writes(30)), DispatchClass::Operational, Pays::No))]
+                .saturating_add(T::DbWeight::get().writes(30))
+                .saturating_add(Self::get_free_epoch_offset_weight()), DispatchClass::Operational, Pays::No))]
         pub fn register_network_with_identity(
             origin: OriginFor<T>,
             hotkey: T::AccountId,
@@ -1556,7 +1559,8 @@ mod dispatches {
         /// * `TxRateLimitExceeded`:
         ///     - Thrown if key has hit transaction rate limit
         #[pallet::call_index(83)]
-        #[pallet::weight((Weight::from_parts(3_000_000, 0).saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+        #[pallet::weight((Weight::from_parts(3_000_000, 0).saturating_add(T::DbWeight::get().writes(1))
+		.saturating_add(T::DbWeight::get().reads(u64::from(MAX_STAKE_PROVIDER_SCAN_READS))), DispatchClass::Operational, Pays::No))]
         pub fn unstake_all(origin: OriginFor<T>, hotkey: T::AccountId) -> DispatchResult {
             Self::do_unstake_all(origin, hotkey)
         }
@@ -1614,7 +1618,8 @@ mod dispatches {
         ///     - The alpha stake amount to move.
         ///
         #[pallet::call_index(85)]
-        #[pallet::weight((Weight::from_parts(3_000_000, 0).saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+        #[pallet::weight((Weight::from_parts(3_000_000, 0).saturating_add(T::DbWeight::get().writes(1))
+		.saturating_add(T::DbWeight::get().reads(u64::from(MAX_STAKE_PROVIDER_SCAN_READS))), DispatchClass::Operational, Pays::No))]
         pub fn move_stake(
             origin: T::RuntimeOrigin,
             origin_hotkey: T::AccountId,
@@ -1655,7 +1660,8 @@ mod dispatches {
         /// # Events
         /// May emit a `StakeTransferred` event on success.
         #[pallet::call_index(86)]
-        #[pallet::weight((Weight::from_parts(3_000_000, 0).saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+        #[pallet::weight((Weight::from_parts(3_000_000, 0).saturating_add(T::DbWeight::get().writes(1))
+		.saturating_add(T::DbWeight::get().reads(u64::from(MAX_STAKE_PROVIDER_SCAN_READS))), DispatchClass::Operational, Pays::No))]
         pub fn transfer_stake(
             origin: T::RuntimeOrigin,
             destination_coldkey: T::AccountId,
@@ -1695,7 +1701,9 @@ mod dispatches {
         /// May emit a `StakeSwapped` event on success.
         #[pallet::call_index(87)]
         #[pallet::weight((
-            Weight::from_parts(3_000_000, 0).saturating_add(T::DbWeight::get().writes(1)),
+            Weight::from_parts(3_000_000, 0)
+                .saturating_add(T::DbWeight::get().writes(1))
+                .saturating_add(T::DbWeight::get().reads(u64::from(MAX_STAKE_PROVIDER_SCAN_READS))),
             DispatchClass::Operational,
             Pays::No
         ))]
@@ -1819,7 +1827,8 @@ mod dispatches {
         #[pallet::weight((Weight::from_parts(111_000_000, 0)
 		.saturating_add(Weight::from_parts(0, 43991))
 		.saturating_add(T::DbWeight::get().reads(10))
-		.saturating_add(T::DbWeight::get().writes(7)), DispatchClass::Normal, Pays::No))]
+		.saturating_add(T::DbWeight::get().writes(7))
+		.saturating_add(T::DbWeight::get().reads(u64::from(MAX_STAKE_PROVIDER_SCAN_READS))), DispatchClass::Normal, Pays::No))]
         pub fn remove_stake_limit(
             origin: OriginFor<T>,
             hotkey: T::AccountId,
@@ -1861,7 +1870,9 @@ mod dispatches {
         /// May emit a `StakeSwapped` event on success.
         #[pallet::call_index(90)]
         #[pallet::weight((
-            Weight::from_parts(3_000_000, 0).saturating_add(T::DbWeight::get().writes(1)),
+            Weight::from_parts(3_000_000, 0)
+                .saturating_add(T::DbWeight::get().writes(1))
+                .saturating_add(T::DbWeight::get().reads(u64::from(MAX_STAKE_PROVIDER_SCAN_READS))),
             DispatchClass::Operational,
             Pays::No
         ))]
@@ -1909,5 +1920,192 @@ mod dispatches {
 
             Ok(())
         }
+
+        /// Locks a portion of the stake on a hotkey on a subnet until a block.
+        ///
+        /// Locked stake can't be unstaked or moved before the unlock block.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the transaction, which must be signed by the coldkey that owns the stake.
+        /// * `hotkey` - The hotkey the stake is on.
+        /// * `netuid` - The network/subnet ID the stake is on.
+        /// * `alpha_locked` - The amount of stake to lock.
+        /// * `until_block` - The block at which the stake unlocks.
+        ///
+        /// # Errors
+        /// Returns an error if:
+        /// * The subnet or the hotkey does not exist.
+        /// * `until_block` is not in the future.
+        /// * There is insufficient stake on `(coldkey, hotkey, netuid)`.
+        ///
+        /// # Events
+        /// Emits a `StakeLocked` event on success.
+        #[pallet::call_index(101)]
+        #[pallet::weight((
+            Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(5, 1)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn lock_stake(
+            origin: T::RuntimeOrigin,
+            hotkey: T::AccountId,
+            netuid: u16,
+            alpha_locked: u64,
+            until_block: u64,
+        ) -> DispatchResult {
+            Self::do_lock_stake(origin, hotkey, netuid, alpha_locked, until_block)
+        }
+
+        /// Removes up to `alpha_requested` stake from the staking account, clamping the amount
+        /// to the stake that can currently be withdrawn.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The associated hotkey account.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- Subnetwork UID.
+        ///
+        /// * 'alpha_requested' (u64):
+        /// 	- The maximum amount of stake to be removed.
+        ///
+        /// # Event:
+        /// * StakeRemoved;
+        /// 	- On the successfully removing stake from the hotkey account.
+        ///
+        /// * StakeRemovedPartial;
+        /// 	- With the requested and the actually removed amounts.
+        ///
+        /// # Raises:
+        /// * 'NotEnoughStakeToWithdraw':
+        /// 	- Thrown if there is no stake on the hotkey to withdraw.
+        ///
+        /// * 'StakeIsLocked':
+        /// 	- Thrown if all of the stake on the hotkey is locked.
+        ///
+        #[pallet::call_index(102)]
+        #[pallet::weight((Weight::from_parts(111_000_000, 0)
+		.saturating_add(Weight::from_parts(0, 43991))
+		.saturating_add(T::DbWeight::get().reads(12))
+		.saturating_add(T::DbWeight::get().writes(7)), DispatchClass::Normal, Pays::No))]
+        pub fn remove_stake_partial(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: u16,
+            alpha_requested: u64,
+        ) -> DispatchResult {
+            Self::do_remove_stake_partial(origin, hotkey, netuid, alpha_requested)
+        }
+
+        /// Registers several hotkeys of the calling coldkey on a subnet at once by burning TAO.
+        ///
+        /// The batch is all-or-nothing and holds at most `MAX_BATCH_REGISTRATIONS` hotkeys.
+        #[pallet::call_index(103)]
+        #[pallet::weight((Weight::from_parts(177_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(26))
+		.saturating_add(T::DbWeight::get().writes(24))
+		.saturating_mul(hotkeys.len() as u64), DispatchClass::Normal, Pays::No))]
+        pub fn batch_register(
+            origin: OriginFor<T>,
+            netuid: u16,
+            hotkeys: Vec<T::AccountId>,
+        ) -> DispatchResult {
+            Self::do_batch_register(origin, netuid, hotkeys)
+        }
+
+        /// Returns the stake nominated to a hotkey pruned from the subnet to its coldkeys as TAO.
+        ///
+        /// Anyone can call this. At most `MAX_RECYCLED_NOMINATIONS` nominations are unstaked
+        /// and `MAX_RECYCLE_SCANNED_ENTRIES` stake entries read per call, and locked stake is
+        /// left in place.
+        #[pallet::call_index(104)]
+        #[pallet::weight((Pallet::<T>::recycle_nominations_weight()
+		.saturating_add(T::DbWeight::get().reads(9)), DispatchClass::Normal, Pays::Yes))]
+        pub fn recycle_deregistered_stake(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: u16,
+        ) -> DispatchResult {
+            Self::do_recycle_deregistered_stake(origin, hotkey, netuid)
+        }
+
+        /// Runs the epoch of a subnet right away, regardless of its tempo, and distributes its
+        /// pending emission.
+        ///
+        /// Only callable by root. Used to recover a stuck subnet or to try out changes. Charged the
+        /// benchmarked block step weight, which covers an epoch.
+        #[pallet::call_index(105)]
+        #[pallet::weight((
+            Self::get_block_step_weight(),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn force_epoch(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::do_force_epoch(origin, netuid)
+        }
+
+        /// Pays out the emission still being smoothed for a hotkey on a subnet right away,
+        /// regardless of the smoothing schedule, with the same math as the scheduled payouts.
+        ///
+        /// Only callable by root. Used for emergency operations and to make tests deterministic.
+        #[pallet::call_index(106)]
+        #[pallet::weight((Weight::from_parts(111_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(12))
+		.saturating_add(T::DbWeight::get().writes(7))
+		.saturating_add(T::DbWeight::get().reads(
+			u64::from(SubnetworkN::<T>::get(netuid)).saturating_mul(4)
+		)), DispatchClass::Operational, Pays::No))]
+        pub fn force_drain_pending_emission(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: u16,
+        ) -> DispatchResult {
+            Self::do_force_drain_pending_emission(origin, hotkey, netuid)
+        }
+
+        /// Emits a hash of the subnet's neurons, stakes and weights, so indexers can checkpoint
+        /// the subnet and detect divergence.
+        ///
+        /// Callable by any signed account.
+        #[pallet::call_index(107)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().reads(
+			u64::from(SubnetworkN::<T>::get(netuid)).saturating_mul(3)
+		)), DispatchClass::Normal, Pays::Yes))]
+        pub fn emit_subnet_state_hash(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::do_emit_subnet_state_hash(origin, netuid)
+        }
+
+        /// Sets the TLS certificate of the calling hotkey on a subnet, leaving its axon as is.
+        ///
+        /// The certificate is the algorithm byte followed by the public key, at most 65 bytes.
+        #[pallet::call_index(108)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_neuron_certificate(
+            origin: OriginFor<T>,
+            netuid: u16,
+            certificate: Vec<u8>,
+        ) -> DispatchResult {
+            Self::do_set_neuron_certificate(origin, netuid, certificate)
+        }
+
+        /// Removes a subnetwork on behalf of its owner.
+        ///
+        /// The caller must be the subnet owner coldkey. Unlike `dissolve_network`, this does not
+        /// need root and the caller pays for the removal.
+        #[pallet::call_index(109)]
+        #[pallet::weight((Weight::from_parts(119_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(6))
+		.saturating_add(T::DbWeight::get().writes(31)), DispatchClass::Normal, Pays::Yes))]
+        pub fn dissolve_owned_network(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            let coldkey = ensure_signed(origin)?;
+            Self::user_remove_network(coldkey, netuid)
+        }
     }
 }
@@ -61,6 +61,9 @@ mod errors {
         /// Number of registrations in this block exceeds the allowed number (i.e., exceeds the
         /// subnet hyperparameter "max_regs_per_block").
         TooManyRegistrationsThisBlock,
+        /// Number of registrations in this block across all subnets exceeds the chain-wide
+        /// limit "max_total_regs_per_block".
+        TooManyTotalRegistrationsThisBlock,
         /// The caller is requesting registering a neuron which already exists in the active set.
         HotKeyAlreadyRegisteredInSubNet,
         /// The new hotkey is the same as old one
@@ -195,5 +198,35 @@ mod errors {
         ActivityCutoffTooLow,
         /// Call is disabled
         CallDisabled,
+        /// The unlock block of a stake lock must be in the future.
+        InvalidStakeLockBlock,
+        /// Not enough stake to lock this amount.
+        NotEnoughStakeToLock,
+        /// Trying to unstake or move stake that is locked.
+        StakeIsLocked,
+        /// The registration batch is empty or too large.
+        InvalidBatchRegistrationSize,
+        /// The subnet doesn't have room for the whole registration batch.
+        BatchExceedsSubnetCapacity,
+        /// The hotkey still holds a uid on the subnet.
+        HotKeyStillRegisteredOnSubnet,
+        /// The stake operation moves less than the configured minimum stake amount.
+        AmountTooSmall,
+        /// The nomination would end up below the minimum nominator stake.
+        StakeAmountTooLow,
+        /// The subnet only accepts stake from whitelisted coldkeys.
+        NotWhitelisted,
+        /// The root subnet doesn't run epochs.
+        CannotForceRootEpoch,
+        /// The hotkey has no emission left to pay out on the subnet.
+        NoPendingEmission,
+        /// The neuron certificate is empty or too long.
+        InvalidNeuronCertificate,
+        /// The hotkey was not pruned from the subnet, so its stake can't be recycled.
+        HotKeyNotPrunedFromSubnet,
+        /// The hotkey has child or parent keys on the subnet.
+        HotKeyHasChildOrParentKeys,
+        /// The subnet has weight commits that haven't been revealed yet.
+        PendingWeightCommits,
     }
 }
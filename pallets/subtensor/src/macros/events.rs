@@ -39,8 +39,14 @@ mod events {
         RegistrationPerIntervalSet(u16, u16),
         /// we set max registrations per block.
         MaxRegistrationsPerBlockSet(u16, u16),
+        /// we set max registrations per block across all subnets.
+        MaxTotalRegistrationsPerBlockSet(u16),
         /// an activity cutoff is set for a subnet.
         ActivityCutoffSet(u16, u16),
+        /// the maximum age of weights used in consensus is set for a subnet.
+        MaxWeightAgeSet(u16, u64),
+        /// the share of emission paid to validators is set for a subnet.
+        ValidatorEmissionRatioSet(u16, u16),
         /// Rho value is set.
         RhoSet(u16, u16),
         /// Kappa is set for a subnet.
@@ -103,6 +109,16 @@ mod events {
         PowRegistrationAllowed(u16, bool),
         /// setting tempo on a network
         TempoSet(u16, u16),
+        /// setting the offset of a subnet's epoch boundary.
+        EpochOffsetSet(u16, u64),
+        /// setting the minimum tempo a subnet can be set to.
+        MinTempoSet(u16),
+        /// setting the number of blocks a subnet's drained emission is paid out over.
+        EmissionSmoothingBlocksSet(u16, u64),
+        /// the cumulative TAO volume of a subnet has been reset by governance.
+        SubnetVolumeSet(u16, u128),
+        /// the volume based staking fee tiers have been set.
+        StakingFeeVolumeTiersSet(Vec<(u128, u64)>),
         /// setting the RAO recycled for registration.
         RAORecycledForRegistrationSet(u16, u64),
         /// min stake is set for validators to set weights.
@@ -111,10 +127,20 @@ mod events {
         SenateRequiredStakePercentSet(u64),
         /// setting the adjustment alpha on a subnet.
         AdjustmentAlphaSet(u16, u64),
+        /// setting the difficulty adjustment algorithm on a subnet.
+        DifficultyAlgoSet(u16, u8),
         /// the faucet it called on the test net.
         Faucet(T::AccountId, u64),
         /// the subnet owner cut is set.
         SubnetOwnerCutSet(u16),
+        /// the minimum subnet owner cut is set.
+        MinOwnerCutSet(u16),
+        /// the maximum subnet owner cut is set.
+        MaxOwnerCutSet(u16),
+        /// the maximum total issuance is set.
+        MaxTotalIssuanceSet(u64),
+        /// the minimum stake operation amount is set.
+        MinStakeAmountSet(u64),
         /// the network creation rate limit is set.
         NetworkRateLimitSet(u64),
         /// the network immunity period is set.
@@ -270,10 +296,106 @@ mod events {
         /// (netuid, bool)
         TransferToggle(u16, bool),
 
+        /// Event called when the stake whitelist is toggled on a subnet.
+        ///
+        /// Parameters:
+        /// (netuid, enabled)
+        StakeWhitelistSet(u16, bool),
+
+        /// A coldkey has been added to or removed from a subnet's stake whitelist.
+        StakeWhitelistUpdated {
+            /// the subnet whose whitelist changed
+            netuid: u16,
+            /// the coldkey added or removed
+            coldkey: T::AccountId,
+            /// whether the coldkey may now stake while the whitelist is on
+            allowed: bool,
+        },
+
         /// The owner hotkey for a subnet has been set.
         ///
         /// Parameters:
         /// (netuid, new_hotkey)
         SubnetOwnerHotkeySet(u16, T::AccountId),
+
+        /// Stake has been locked until a block.
+        ///
+        /// Parameters:
+        /// (coldkey, hotkey, netuid, alpha_locked, until_block)
+        StakeLocked(T::AccountId, T::AccountId, u16, u64, u64),
+
+        /// Stake has been removed with the requested amount clamped to the available stake.
+        ///
+        /// Parameters:
+        /// (coldkey, hotkey, netuid, alpha_requested, alpha_unstaked)
+        StakeRemovedPartial(T::AccountId, T::AccountId, u16, u64, u64),
+
+        /// A nomination below the minimum required stake has been cleared.
+        NominationCleared {
+            /// the account ID of the nominator coldkey
+            coldkey: T::AccountId,
+            /// the account ID of the hotkey
+            hotkey: T::AccountId,
+            /// the subnet the nomination was on
+            netuid: u16,
+            /// the TAO credited back to the coldkey
+            returned_tao: u64,
+        },
+
+        /// The subnet owner cut has been credited to the subnet owner.
+        SubnetOwnerCutPaid {
+            /// the subnet the cut was paid on
+            netuid: u16,
+            /// the account ID of the subnet owner coldkey
+            coldkey: T::AccountId,
+            /// the alpha credited to the subnet owner
+            amount: u64,
+        },
+
+        /// Stake nominated to a deregistered hotkey has been returned to its coldkeys.
+        DeregisteredStakeRecycled {
+            /// the account ID of the deregistered hotkey
+            hotkey: T::AccountId,
+            /// the subnet the hotkey was deregistered from
+            netuid: u16,
+            /// the number of nominations unstaked
+            nominations: u32,
+        },
+
+        /// Governance ran the epoch of a subnet outside its tempo.
+        ///
+        /// Parameters:
+        /// (netuid)
+        EpochForced(u16),
+
+        /// The emission still being paid out to a hotkey on a subnet was drained by root.
+        ///
+        /// Parameters:
+        /// (hotkey, netuid)
+        PendingEmissionDrained(T::AccountId, u16),
+
+        /// The state hash of a subnet was emitted for off-chain verification.
+        ///
+        /// Parameters:
+        /// (netuid, state_hash)
+        SubnetStateHash(u16, H256),
+
+        /// A hotkey set its neuron certificate on a subnet.
+        ///
+        /// Parameters:
+        /// (netuid, hotkey)
+        NeuronCertificateSet(u16, T::AccountId),
+
+        /// The minimum TAO emission per block for each subnet was set.
+        ///
+        /// Parameters:
+        /// (min_subnet_emission)
+        MinSubnetEmissionSet(u64),
+
+        /// The maximum TAO emission per block for a single subnet was set.
+        ///
+        /// Parameters:
+        /// (max_subnet_emission)
+        MaxSubnetEmissionSet(u64),
     }
 }
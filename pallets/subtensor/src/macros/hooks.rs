@@ -20,16 +20,12 @@ mod hooks {
                 Ok(_) => {
                     // --- If the block step was successful, return the weight.
                     log::debug!("Successfully ran block step.");
-                    Weight::from_parts(110_634_229_000_u64, 0)
-                        .saturating_add(T::DbWeight::get().reads(8304_u64))
-                        .saturating_add(T::DbWeight::get().writes(110_u64))
+                    Self::get_block_step_weight()
                 }
                 Err(e) => {
                     // --- If the block step was unsuccessful, return the weight anyway.
                     log::error!("Error while stepping block: {:?}", e);
-                    Weight::from_parts(110_634_229_000_u64, 0)
-                        .saturating_add(T::DbWeight::get().reads(8304_u64))
-                        .saturating_add(T::DbWeight::get().writes(110_u64))
+                    Self::get_block_step_weight()
                 }
             }
         }
@@ -81,7 +77,20 @@ mod hooks {
                 // Remove Stake map entries
 				.saturating_add(migrations::migrate_remove_stake_map::migrate_remove_stake_map::<T>())
                 // Remove unused maps entries
-				.saturating_add(migrations::migrate_remove_unused_maps_and_values::migrate_remove_unused_maps_and_values::<T>());
+				.saturating_add(migrations::migrate_remove_unused_maps_and_values::migrate_remove_unused_maps_and_values::<T>())
+                // Storage version v7 -> v8
+                // Remove neuron certificates exceeding the serving size limits
+                .saturating_add(migrations::migrate_truncate_oversized_serving::migrate_truncate_oversized_serving::<T>())
+                // Storage version v8 -> v9
+                // Backfill BlockAtRegistration for neurons that were appended without it
+                .saturating_add(migrations::migrate_backfill_block_at_registration::migrate_backfill_block_at_registration::<T>())
+                // Storage version v9 -> v10
+                // Remove IsNetworkMember entries without a uid on the subnet
+                .saturating_add(migrations::migrate_remove_stranded_network_members::migrate_remove_stranded_network_members::<T>())
+                // Keep coldkeys that were staked before the stake provider existed alive
+                .saturating_add(migrations::migrate_add_stake_providers::migrate_add_stake_providers::<T>())
+                // Allow recycling the stake of hotkeys deregistered before recycling existed
+                .saturating_add(migrations::migrate_backfill_pruned_hotkeys::migrate_backfill_pruned_hotkeys::<T>());
             weight
         }
 
@@ -0,0 +1,55 @@
+use alloc::string::String;
+
+use frame_support::{traits::Get, weights::Weight};
+
+use super::*;
+
+/// Takes the stake provider reference on every coldkey that already holds stake.
+///
+/// The reference is only taken when stake is added, so coldkeys that were staked before it
+/// existed could still be reaped by the balances pallet once their free balance is gone.
+pub fn migrate_add_stake_providers<T: Config>() -> Weight {
+    let migration_name = b"migrate_add_stake_providers".to_vec();
+
+    // Initialize the weight with one read operation.
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Check if the migration has already run
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    let mut added: u64 = 0;
+    for coldkey in StakingHotkeys::<T>::iter_keys() {
+        weight.saturating_accrue(
+            T::DbWeight::get().reads(u64::from(MAX_STAKE_PROVIDER_SCAN_READS).saturating_add(2)),
+        );
+        if StakeProviders::<T>::get(&coldkey) || !Pallet::<T>::coldkey_has_stake(&coldkey) {
+            continue;
+        }
+        Pallet::<T>::maybe_add_stake_provider(&coldkey);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        added = added.saturating_add(1);
+    }
+
+    // Mark the migration as completed
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{:?}' completed, added {} stake providers.",
+        String::from_utf8_lossy(&migration_name),
+        added
+    );
+
+    // Return the migration weight.
+    weight
+}
@@ -0,0 +1,81 @@
+use super::*;
+use frame_support::{
+    traits::{Get, GetStorageVersion, StorageVersion},
+    weights::Weight,
+};
+use log::info;
+use sp_std::vec::Vec;
+
+/// Constant for logging purposes
+const LOG_TARGET: &str = "migrate_backfill_block_at_registration";
+
+/// Backfills `BlockAtRegistration` for neurons that were appended without it
+///
+/// A missing entry reads as block 0, which makes the neuron look like it was registered at
+/// genesis and skews the immunity period logic. Every uid in `Keys` without an entry gets the
+/// current block as its registration block.
+///
+/// This migration is applicable only if the current storage version is below 9, after which it
+/// updates the storage version to 9.
+///
+/// # Returns
+///
+/// * `Weight` - The computational weight of this operation
+///
+/// # Example
+///
+/// ```ignore
+/// let weight = migrate_backfill_block_at_registration::<T>();
+/// ```
+pub fn migrate_backfill_block_at_registration<T: Config>() -> Weight {
+    let new_storage_version = 9;
+
+    // Initialize weight counter
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Get current on-chain storage version
+    let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+    if onchain_version >= new_storage_version {
+        info!(
+            target: LOG_TARGET,
+            "Migration to v9 already completed. Current version: {:?}", onchain_version
+        );
+        return weight;
+    }
+
+    info!(
+        target: LOG_TARGET,
+        "Backfilling BlockAtRegistration. Current version: {:?}", onchain_version
+    );
+
+    let current_block = Pallet::<T>::get_current_block_as_u64();
+    weight.saturating_accrue(T::DbWeight::get().reads(1));
+
+    // Collect the uids first so the maps aren't mutated while iterating them.
+    let mut missing: Vec<(u16, u16)> = Vec::new();
+    for (netuid, uid, _) in Keys::<T>::iter() {
+        weight.saturating_accrue(T::DbWeight::get().reads(2));
+        if !BlockAtRegistration::<T>::contains_key(netuid, uid) {
+            missing.push((netuid, uid));
+        }
+    }
+
+    for (netuid, uid) in missing {
+        info!(
+            target: LOG_TARGET,
+            "Setting BlockAtRegistration of uid {:?} on netuid {:?} to {:?}",
+            uid,
+            netuid,
+            current_block
+        );
+        BlockAtRegistration::<T>::insert(netuid, uid, current_block);
+        weight.saturating_accrue(T::DbWeight::get().writes(1));
+    }
+
+    // Update storage version
+    StorageVersion::new(new_storage_version).put::<Pallet<T>>();
+    weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+    weight
+}
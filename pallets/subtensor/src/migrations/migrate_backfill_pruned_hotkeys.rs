@@ -0,0 +1,64 @@
+use alloc::string::String;
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::vec::Vec;
+
+use super::*;
+
+/// Adds a `PrunedHotkeys` entry for every hotkey that holds stake on a subnet it has no uid on.
+///
+/// Hotkeys are only added to `PrunedHotkeys` when they lose their uid, so the stake of hotkeys
+/// deregistered before recycling existed could never be returned to its nominators.
+pub fn migrate_backfill_pruned_hotkeys<T: Config>() -> Weight {
+    let migration_name = b"migrate_backfill_pruned_hotkeys".to_vec();
+
+    // Initialize the weight with one read operation.
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Check if the migration has already run
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // Collect first, writing to the storage being iterated is undefined.
+    let mut pruned: Vec<(u16, T::AccountId)> = Vec::new();
+    for (hotkey, netuid, alpha) in TotalHotkeyAlpha::<T>::iter() {
+        weight.saturating_accrue(T::DbWeight::get().reads(1));
+        if alpha == 0 || netuid == Pallet::<T>::get_root_netuid() {
+            continue;
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads(3));
+        if Pallet::<T>::if_subnet_exist(netuid)
+            && !Uids::<T>::contains_key(netuid, &hotkey)
+            && !PrunedHotkeys::<T>::contains_key(netuid, &hotkey)
+        {
+            pruned.push((netuid, hotkey));
+        }
+    }
+
+    for (netuid, hotkey) in pruned.iter() {
+        PrunedHotkeys::<T>::insert(netuid, hotkey, None::<(T::AccountId, u16)>);
+        weight.saturating_accrue(T::DbWeight::get().writes(1));
+    }
+
+    // Mark the migration as completed
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{:?}' completed, added {} pruned hotkeys.",
+        String::from_utf8_lossy(&migration_name),
+        pruned.len()
+    );
+
+    // Return the migration weight.
+    weight
+}
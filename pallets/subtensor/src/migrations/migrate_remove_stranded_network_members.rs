@@ -0,0 +1,73 @@
+use super::*;
+use frame_support::{
+    traits::{Get, GetStorageVersion, StorageVersion},
+    weights::Weight,
+};
+use log::info;
+use sp_std::vec::Vec;
+
+/// Constant for logging purposes
+const LOG_TARGET: &str = "migrate_remove_stranded_network_members";
+
+/// Removes `IsNetworkMember` entries that have no uid behind them
+///
+/// A hotkey flagged as a member of a subnet it holds no uid on shows up in
+/// `get_registered_networks_for_hotkey` and passes `is_hotkey_registered_on_any_network`. Every
+/// `IsNetworkMember(hotkey, netuid) = true` entry is checked against `Uids(netuid, hotkey)`, and
+/// the ones without a uid are removed.
+///
+/// This migration is applicable only if the current storage version is below 10, after which it
+/// updates the storage version to 10.
+///
+/// # Returns
+///
+/// * `Weight` - The computational weight of this operation
+///
+/// # Example
+///
+/// ```ignore
+/// let weight = migrate_remove_stranded_network_members::<T>();
+/// ```
+pub fn migrate_remove_stranded_network_members<T: Config>() -> Weight {
+    let new_storage_version = 10;
+
+    // Initialize weight counter
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Get current on-chain storage version
+    let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+    if onchain_version >= new_storage_version {
+        info!(
+            target: LOG_TARGET,
+            "Migration to v10 already completed. Current version: {:?}", onchain_version
+        );
+        return weight;
+    }
+
+    // Collect first, removing while iterating the map is undefined.
+    let mut stranded: Vec<(T::AccountId, u16)> = Vec::new();
+    for (hotkey, netuid, is_member) in IsNetworkMember::<T>::iter() {
+        weight.saturating_accrue(T::DbWeight::get().reads(2));
+        if is_member && !Uids::<T>::contains_key(netuid, &hotkey) {
+            stranded.push((hotkey, netuid));
+        }
+    }
+
+    for (hotkey, netuid) in stranded.iter() {
+        IsNetworkMember::<T>::remove(hotkey, netuid);
+        weight.saturating_accrue(T::DbWeight::get().writes(1));
+    }
+
+    info!(
+        target: LOG_TARGET,
+        "Removed {:?} stranded IsNetworkMember entries",
+        stranded.len()
+    );
+
+    // Update storage version
+    StorageVersion::new(new_storage_version).put::<Pallet<T>>();
+    weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+    weight
+}
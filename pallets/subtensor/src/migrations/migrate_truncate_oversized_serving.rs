@@ -0,0 +1,106 @@
+use super::*;
+use frame_support::{
+    pallet_prelude::*,
+    storage_alias,
+    traits::{Get, GetStorageVersion, StorageVersion},
+    weights::Weight,
+};
+use log::info;
+use sp_std::vec::Vec;
+
+/// Constant for logging purposes
+const LOG_TARGET: &str = "migrate_truncate_oversized_serving";
+
+/// Module containing the unbounded storage format of neuron certificates
+pub mod deprecated_neuron_certificate_format {
+    use super::*;
+
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+    pub struct NeuronCertificate {
+        pub public_key: Vec<u8>,
+        pub algorithm: u8,
+    }
+
+    #[storage_alias]
+    pub(super) type NeuronCertificates<T: Config> = StorageDoubleMap<
+        Pallet<T>,
+        Identity,
+        u16,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        NeuronCertificate,
+        OptionQuery,
+    >;
+}
+
+/// Removes serving entries that exceed the current serving size limits
+///
+/// Neuron certificates whose public key is longer than the bound of
+/// [`NeuronCertificate::public_key`] can no longer be decoded. Truncating a TLS public key would
+/// leave a key that doesn't verify anything, so oversized certificates are removed instead and
+/// the neuron has to serve a new one.
+///
+/// This migration is applicable only if the current storage version is below 8, after which it
+/// updates the storage version to 8.
+///
+/// # Returns
+///
+/// * `Weight` - The computational weight of this operation
+///
+/// # Example
+///
+/// ```ignore
+/// let weight = migrate_truncate_oversized_serving::<T>();
+/// ```
+pub fn migrate_truncate_oversized_serving<T: Config>() -> Weight {
+    let new_storage_version = 8;
+
+    // Initialize weight counter
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Get current on-chain storage version
+    let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+    if onchain_version >= new_storage_version {
+        info!(
+            target: LOG_TARGET,
+            "Migration to v8 already completed. Current version: {:?}", onchain_version
+        );
+        return weight;
+    }
+
+    info!(
+        target: LOG_TARGET,
+        "Removing oversized neuron certificates. Current version: {:?}", onchain_version
+    );
+
+    // Collect the oversized certificates first so the map isn't mutated while iterating it.
+    let mut oversized: Vec<(u16, T::AccountId, usize)> = Vec::new();
+    for (netuid, hotkey, certificate) in
+        deprecated_neuron_certificate_format::NeuronCertificates::<T>::iter()
+    {
+        weight.saturating_accrue(T::DbWeight::get().reads(1));
+        let len = certificate.public_key.len();
+        if BoundedVec::<u8, ConstU32<64>>::try_from(certificate.public_key).is_err() {
+            oversized.push((netuid, hotkey, len));
+        }
+    }
+
+    for (netuid, hotkey, len) in oversized {
+        info!(
+            target: LOG_TARGET,
+            "Removing neuron certificate of hotkey {:?} on netuid {:?}: public key is {:?} bytes",
+            hotkey,
+            netuid,
+            len
+        );
+        NeuronCertificates::<T>::remove(netuid, &hotkey);
+        weight.saturating_accrue(T::DbWeight::get().writes(1));
+    }
+
+    // Update storage version
+    StorageVersion::new(new_storage_version).put::<Pallet<T>>();
+    weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+    weight
+}
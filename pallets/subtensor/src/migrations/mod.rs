@@ -1,4 +1,7 @@
 use super::*;
+pub mod migrate_add_stake_providers;
+pub mod migrate_backfill_block_at_registration;
+pub mod migrate_backfill_pruned_hotkeys;
 pub mod migrate_chain_identity;
 pub mod migrate_commit_reveal_v2;
 pub mod migrate_create_root_network;
@@ -10,6 +13,7 @@ pub mod migrate_init_total_issuance;
 pub mod migrate_populate_owned_hotkeys;
 pub mod migrate_rao;
 pub mod migrate_remove_stake_map;
+pub mod migrate_remove_stranded_network_members;
 pub mod migrate_remove_unused_maps_and_values;
 pub mod migrate_set_min_burn;
 pub mod migrate_set_min_difficulty;
@@ -19,3 +23,4 @@ pub mod migrate_to_v1_separate_emission;
 pub mod migrate_to_v2_fixed_total_stake;
 pub mod migrate_total_issuance;
 pub mod migrate_transfer_ownership_to_foundation;
+pub mod migrate_truncate_oversized_serving;
@@ -107,6 +107,32 @@ pub struct Metagraph<AccountId: TypeInfo + Encode + Decode> {
     alpha_dividends_per_hotkey: Vec<(AccountId, Compact<u64>)>, // List of dividend payout in alpha via subnet.
 }
 
+/// The per-uid vectors of a subnet's metagraph in uid order, so clients can read the whole
+/// metagraph in one call instead of one call per field.
+#[freeze_struct("70d2119f9bc0120c")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct FullMetagraph<AccountId: TypeInfo + Encode + Decode> {
+    pub netuid: Compact<u16>,
+    pub keys: Vec<AccountId>,           // hotkey per UID
+    pub stake: Vec<Compact<u64>>,       // Alpha staked per UID
+    pub rank: Vec<Compact<u16>>,        // Rank per UID
+    pub trust: Vec<Compact<u16>>,       // Trust per UID
+    pub consensus: Vec<Compact<u16>>,   // Consensus per UID
+    pub incentive: Vec<Compact<u16>>,   // Mining incentives per UID
+    pub dividends: Vec<Compact<u16>>,   // Dividends per UID
+    pub emission: Vec<Compact<u64>>,    // Emission per UID
+    pub active: Vec<bool>,              // Active per UID
+    pub validator_permit: Vec<bool>,    // Val permit per UID
+    pub last_update: Vec<Compact<u64>>, // Last update per UID
+    pub axons: Vec<AxonInfo>,           // UID axons.
+}
+
+/// Cuts or pads a per-uid vector to the subnet's neuron count.
+fn per_uid<V: Default>(mut values: Vec<V>, n: usize) -> Vec<V> {
+    values.resize_with(n, V::default);
+    values
+}
+
 impl<T: Config> Pallet<T> {
     pub fn get_metagraph(netuid: u16) -> Option<Metagraph<T::AccountId>> {
         if !Self::if_subnet_exist(netuid) {
@@ -283,6 +309,50 @@ impl<T: Config> Pallet<T> {
             alpha_dividends_per_hotkey,
         })
     }
+    /// Retrieves the per-uid vectors of a subnet's metagraph in a single response.
+    ///
+    /// Every vector holds exactly `SubnetworkN` entries, so the response is bounded by the
+    /// subnet's neuron count. Returns `None` if the subnet does not exist.
+    pub fn get_full_metagraph(netuid: u16) -> Option<FullMetagraph<T::AccountId>> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+        let n: u16 = Self::get_subnetwork_n(netuid);
+        let mut keys: Vec<T::AccountId> = vec![];
+        let mut stake: Vec<Compact<u64>> = vec![];
+        let mut axons: Vec<AxonInfo> = vec![];
+        for uid in 0..n {
+            let hotkey = Keys::<T>::get(netuid, uid);
+            stake.push(Self::get_stake_for_hotkey_on_subnet(&hotkey, netuid).into());
+            axons.push(Self::get_axon_info(netuid, &hotkey));
+            keys.push(hotkey);
+        }
+        let n = usize::from(n);
+        let compact = |values: Vec<u16>| -> Vec<Compact<u16>> {
+            per_uid(values, n).into_iter().map(Compact::from).collect()
+        };
+        Some(FullMetagraph {
+            netuid: netuid.into(),
+            keys,
+            stake,
+            rank: compact(Rank::<T>::get(netuid)),
+            trust: compact(Trust::<T>::get(netuid)),
+            consensus: compact(Consensus::<T>::get(netuid)),
+            incentive: compact(Incentive::<T>::get(netuid)),
+            dividends: compact(Dividends::<T>::get(netuid)),
+            emission: per_uid(Emission::<T>::get(netuid), n)
+                .into_iter()
+                .map(Compact::from)
+                .collect(),
+            active: per_uid(Active::<T>::get(netuid), n),
+            validator_permit: per_uid(ValidatorPermit::<T>::get(netuid), n),
+            last_update: per_uid(LastUpdate::<T>::get(netuid), n)
+                .into_iter()
+                .map(Compact::from)
+                .collect(),
+            axons,
+        })
+    }
     pub fn get_all_metagraphs() -> Vec<Option<Metagraph<T::AccountId>>> {
         let netuids: Vec<u16> = Self::get_all_subnet_netuids();
         let mut metagraphs = Vec::<Option<Metagraph<T::AccountId>>>::new();
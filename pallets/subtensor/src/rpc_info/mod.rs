@@ -6,3 +6,4 @@ pub mod neuron_info;
 pub mod show_subnet;
 pub mod stake_info;
 pub mod subnet_info;
+pub mod supply_info;
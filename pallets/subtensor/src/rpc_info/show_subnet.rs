@@ -36,6 +36,18 @@ pub struct SubnetState<AccountId: TypeInfo + Encode + Decode> {
     // // has no weights or bonds
 }
 
+/// The per-uid vectors kept in step by `append_neuron`, without the stake and emission data of
+/// [`SubnetState`].
+#[freeze_struct("770f510a92f8c910")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct SubnetNeuronState {
+    pub netuid: Compact<u16>,
+    pub active: Vec<bool>,
+    pub validator_permit: Vec<bool>,
+    pub last_update: Vec<Compact<u64>>,
+    pub pruning_scores: Vec<Compact<u16>>,
+}
+
 impl<T: Config> Pallet<T> {
     /// Retrieves the emission history for a list of hotkeys across all subnets.
     ///
@@ -169,4 +181,37 @@ impl<T: Config> Pallet<T> {
             emission_history,
         })
     }
+
+    /// Retrieves the active flags, validator permits, last updates and pruning scores of a subnet
+    /// in uid order.
+    ///
+    /// Every vector is cut to the subnet's neuron count, so the response never grows past
+    /// `SubnetworkN`. Returns `None` if the subnet does not exist.
+    pub fn get_subnet_neuron_state(netuid: u16) -> Option<SubnetNeuronState> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+        let n = usize::from(Self::get_subnetwork_n(netuid));
+        let mut active: Vec<bool> = Active::<T>::get(netuid);
+        active.truncate(n);
+        let mut validator_permit: Vec<bool> = ValidatorPermit::<T>::get(netuid);
+        validator_permit.truncate(n);
+        let last_update: Vec<Compact<u64>> = LastUpdate::<T>::get(netuid)
+            .into_iter()
+            .take(n)
+            .map(Compact::from)
+            .collect();
+        let pruning_scores: Vec<Compact<u16>> = PruningScores::<T>::get(netuid)
+            .into_iter()
+            .take(n)
+            .map(Compact::from)
+            .collect();
+        Some(SubnetNeuronState {
+            netuid: netuid.into(),
+            active,
+            validator_permit,
+            last_update,
+            pruning_scores,
+        })
+    }
 }
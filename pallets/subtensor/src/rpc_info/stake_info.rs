@@ -1,8 +1,9 @@
 use super::*;
 use frame_support::pallet_prelude::{Decode, Encode};
 extern crate alloc;
+use alloc::collections::BTreeMap;
 use codec::Compact;
-use substrate_fixed::types::I96F32;
+use substrate_fixed::types::{I96F32, U64F64};
 
 #[freeze_struct("5cfb3c84c3af3116")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
@@ -142,4 +143,114 @@ impl<T: Config> Pallet<T> {
             I96F32::saturating_from_num(amount),
         )
     }
+    /// Returns the maximum amount that can be staked (`is_add`) or unstaked on
+    /// `netuid` without crossing `limit_price`. Read-only preview of the limit
+    /// order calculations.
+    pub fn get_max_stake_amount(netuid: u16, limit_price: u64, is_add: bool) -> u64 {
+        if is_add {
+            Self::get_max_amount_add(netuid, limit_price)
+        } else {
+            Self::get_max_amount_remove(netuid, limit_price)
+        }
+    }
+
+    /// Returns the stake of `coldkey` on `hotkey` on `netuid` split into the part that can be
+    /// unstaked or moved right now and the part that is locked, as `(free, locked)`.
+    pub fn get_stake_breakdown(
+        coldkey_account: T::AccountId,
+        hotkey_account: T::AccountId,
+        netuid: u16,
+    ) -> (u64, u64) {
+        let alpha: u64 = Self::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey_account,
+            &coldkey_account,
+            netuid,
+        );
+        let free: u64 = Self::get_unlocked_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey_account,
+            &coldkey_account,
+            netuid,
+        );
+
+        (free, alpha.saturating_sub(free))
+    }
+
+    /// Checks the stake on `netuid` for inconsistencies and returns a
+    /// `(hotkey, coldkey, discrepancy)` entry for each one found:
+    /// * stake in `Alpha` whose hotkey is missing from the coldkey's `StakingHotkeys`, with the
+    ///   unindexed stake as the discrepancy;
+    /// * a hotkey whose `Alpha` shares don't add up to its `TotalHotkeyShares`, with the sum minus
+    ///   the total as the discrepancy. The total isn't tied to a coldkey, so these are reported
+    ///   against the hotkey's owner.
+    ///
+    /// This iterates the whole `Alpha` and `TotalHotkeyShares` maps, so it is only meant for
+    /// off-chain diagnostics.
+    pub fn check_stake_consistency(netuid: u16) -> Vec<(T::AccountId, T::AccountId, i64)> {
+        let zero = U64F64::saturating_from_num(0);
+        let mut discrepancies: Vec<(T::AccountId, T::AccountId, i64)> = Vec::new();
+        let to_i64 = |value: i128| -> i64 {
+            i64::try_from(value).unwrap_or(if value < 0 { i64::MIN } else { i64::MAX })
+        };
+
+        // Sum the shares per hotkey and check every staking pair is indexed.
+        let mut hotkey_shares: BTreeMap<T::AccountId, U64F64> = BTreeMap::new();
+        for ((hotkey, coldkey, alpha_netuid), shares) in Alpha::<T>::iter() {
+            if alpha_netuid != netuid || shares == zero {
+                continue;
+            }
+            hotkey_shares
+                .entry(hotkey.clone())
+                .and_modify(|total| *total = total.saturating_add(shares))
+                .or_insert(shares);
+            if !StakingHotkeys::<T>::get(&coldkey).contains(&hotkey) {
+                let stake =
+                    Self::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
+                discrepancies.push((hotkey, coldkey, to_i64(i128::from(stake))));
+            }
+        }
+
+        // Compare the summed shares against the stored totals. Fractions of a share are rounding.
+        let mut share_differences: Vec<(T::AccountId, i128)> = Vec::new();
+        for (hotkey, shares_netuid, total_shares) in TotalHotkeyShares::<T>::iter() {
+            if shares_netuid != netuid {
+                continue;
+            }
+            let shares = hotkey_shares.remove(&hotkey).unwrap_or(zero);
+            share_differences.push((
+                hotkey,
+                i128::from(shares.saturating_to_num::<u64>())
+                    .saturating_sub(i128::from(total_shares.saturating_to_num::<u64>())),
+            ));
+        }
+        for (hotkey, shares) in hotkey_shares {
+            share_differences.push((hotkey, i128::from(shares.saturating_to_num::<u64>())));
+        }
+        for (hotkey, difference) in share_differences {
+            if difference != 0 {
+                let owner = Owner::<T>::get(&hotkey);
+                discrepancies.push((hotkey, owner, to_i64(difference)));
+            }
+        }
+
+        discrepancies
+    }
+
+    /// Returns the stake on `hotkey` split per subnet as `(netuid, alpha, tao_value)` entries,
+    /// with the alpha valued at the subnet's current price. Subnets where the hotkey holds no
+    /// stake are left out.
+    pub fn get_hotkey_stake_breakdown(hotkey: &T::AccountId) -> Vec<(u16, u64, u64)> {
+        Self::get_all_subnet_netuids()
+            .into_iter()
+            .filter_map(|netuid| {
+                let alpha: u64 = Self::get_stake_for_hotkey_on_subnet(hotkey, netuid);
+                if alpha == 0 {
+                    return None;
+                }
+                let tao_value: u64 = I96F32::saturating_from_num(alpha)
+                    .saturating_mul(Self::get_alpha_price(netuid))
+                    .saturating_to_num::<u64>();
+                Some((netuid, alpha, tao_value))
+            })
+            .collect()
+    }
 }
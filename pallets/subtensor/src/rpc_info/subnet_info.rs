@@ -287,4 +287,24 @@ impl<T: Config> Pallet<T> {
             liquid_alpha_enabled,
         })
     }
+
+    /// Returns the current registration difficulty of a subnet, the block it was last adjusted at
+    /// and how much it moved at that adjustment.
+    pub fn get_difficulty_info(netuid: u16) -> (u64, u64, i64) {
+        (
+            Self::get_difficulty_as_u64(netuid),
+            Self::get_last_adjustment_block(netuid),
+            LastDifficultyDelta::<T>::get(netuid),
+        )
+    }
+
+    /// Returns what a neuron registration on the subnet currently costs: the TAO burned by a
+    /// burned registration, the PoW difficulty, and whether registration is allowed at all.
+    pub fn get_registration_cost(netuid: u16) -> (u64, u64, bool) {
+        (
+            Self::get_burn_as_u64(netuid),
+            Self::get_difficulty_as_u64(netuid),
+            Self::get_network_registration_allowed(netuid),
+        )
+    }
 }
@@ -0,0 +1,45 @@
+use super::*;
+extern crate alloc;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use subtensor_macros::freeze_struct;
+
+#[freeze_struct("954df3fb4f339557")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct SubnetSupply {
+    netuid: Compact<u16>,
+    subnet_tao: Compact<u64>,
+    subnet_alpha_in: Compact<u64>,
+}
+
+#[freeze_struct("407809db539b81cc")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct SupplyInfo {
+    total_issuance: Compact<u64>,
+    total_stake: Compact<u64>,
+    subnets: Vec<SubnetSupply>,
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn get_supply_info() -> SupplyInfo {
+        let subnets: Vec<SubnetSupply> = Self::get_all_subnet_netuids()
+            .into_iter()
+            .map(|netuid| SubnetSupply {
+                netuid: netuid.into(),
+                subnet_tao: SubnetTAO::<T>::get(netuid).into(),
+                subnet_alpha_in: SubnetAlphaIn::<T>::get(netuid).into(),
+            })
+            .collect();
+        SupplyInfo {
+            total_issuance: TotalIssuance::<T>::get().into(),
+            total_stake: TotalStake::<T>::get().into(),
+            subnets,
+        }
+    }
+
+    /// Returns how much TAO can still be emitted before the total issuance reaches
+    /// [`MaxTotalIssuance`].
+    pub fn get_remaining_issuance() -> u64 {
+        Self::get_max_total_issuance().saturating_sub(TotalIssuance::<T>::get())
+    }
+}
@@ -63,7 +63,7 @@ impl<T: Config> Pallet<T> {
 
         // 4. Swap the stake into alpha on the subnet and increase counters.
         // Emit the staking event.
-        let fee = DefaultStakingFee::<T>::get();
+        let fee = Self::get_volume_staking_fee(netuid);
         Self::stake_into_subnet(
             &hotkey,
             &coldkey,
@@ -159,7 +159,7 @@ impl<T: Config> Pallet<T> {
 
         // 6. Swap the stake into alpha on the subnet and increase counters.
         // Emit the staking event.
-        let fee = DefaultStakingFee::<T>::get();
+        let fee = Self::get_volume_staking_fee(netuid);
         Self::stake_into_subnet(
             &hotkey,
             &coldkey,
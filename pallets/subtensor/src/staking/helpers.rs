@@ -78,6 +78,32 @@ impl<T: Config> Pallet<T> {
             .sum::<u64>()
     }
 
+    /// Returns the TAO value of the stake a coldkey has delegated to hotkeys it doesn't own,
+    /// summed over every subnet.
+    ///
+    /// This walks every hotkey the coldkey stakes to and every subnet under each of them, so it
+    /// is a heavy call meant for runtime API queries, not for use inside extrinsics.
+    pub fn get_total_delegated_stake_for_coldkey(coldkey: &T::AccountId) -> u64 {
+        StakingHotkeys::<T>::get(coldkey)
+            .iter()
+            .filter(|hotkey| Owner::<T>::get(hotkey) != *coldkey)
+            .map(|hotkey| {
+                let mut total_stake: u64 = 0;
+                for (netuid, _) in Alpha::<T>::iter_prefix((hotkey, coldkey)) {
+                    let alpha_stake =
+                        Self::get_stake_for_hotkey_and_coldkey_on_subnet(hotkey, coldkey, netuid);
+                    let tao_price: I96F32 = Self::get_alpha_price(netuid);
+                    total_stake = total_stake.saturating_add(
+                        I96F32::saturating_from_num(alpha_stake)
+                            .saturating_mul(tao_price)
+                            .saturating_to_num::<u64>(),
+                    );
+                }
+                total_stake
+            })
+            .fold(0u64, |acc, stake| acc.saturating_add(stake))
+    }
+
     // Creates a cold - hot pairing account if the hotkey is not already an active account.
     //
     pub fn create_account_if_non_existent(coldkey: &T::AccountId, hotkey: &T::AccountId) {
@@ -173,7 +199,10 @@ impl<T: Config> Pallet<T> {
             // Log if the stake is below the minimum required
             let stake: u64 =
                 Self::get_stake_for_hotkey_and_coldkey_on_subnet(hotkey, coldkey, netuid);
-            if stake < Self::get_nominator_min_required_stake() {
+            // Locked stake is never force-unstaked.
+            let locked: u64 =
+                Self::get_locked_stake_for_hotkey_and_coldkey_on_subnet(hotkey, coldkey, netuid);
+            if stake > 0 && locked == 0 && stake < Self::get_nominator_min_required_stake() {
                 // Log the clearing of a small nomination
                 // Remove the stake from the nominator account. (this is a more forceful unstake operation which )
                 // Actually deletes the staking account.
@@ -181,6 +210,21 @@ impl<T: Config> Pallet<T> {
                 let cleared_stake = Self::unstake_from_subnet(hotkey, coldkey, netuid, stake, 0);
                 // Add the stake to the coldkey account.
                 Self::add_balance_to_coldkey_account(coldkey, cleared_stake);
+                // Signal the clear, the stake leaves the map outside of a regular unstake.
+                log::debug!(
+                    "NominationCleared( coldkey:{:?}, hotkey:{:?}, netuid: {:?}, returned_tao:{:?} )",
+                    coldkey,
+                    hotkey,
+                    netuid,
+                    cleared_stake
+                );
+                Self::deposit_event(Event::NominationCleared {
+                    coldkey: coldkey.clone(),
+                    hotkey: hotkey.clone(),
+                    netuid,
+                    returned_tao: cleared_stake,
+                });
+                Self::maybe_release_stake_provider(coldkey);
             }
         }
     }
@@ -196,6 +240,52 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Returns true if the coldkey holds stake on any hotkey on an existing subnet.
+    ///
+    /// The scan stops after `MAX_STAKE_PROVIDER_SCAN_READS` storage reads and then assumes the
+    /// coldkey still has stake, so a cut short scan keeps the provider rather than dropping it.
+    pub fn coldkey_has_stake(coldkey: &T::AccountId) -> bool {
+        let mut reads_left: u32 = MAX_STAKE_PROVIDER_SCAN_READS.saturating_sub(1);
+        for hotkey in StakingHotkeys::<T>::get(coldkey) {
+            let Some(left) = reads_left.checked_sub(1) else {
+                return true;
+            };
+            reads_left = left;
+            for (netuid, shares) in Alpha::<T>::iter_prefix((&hotkey, coldkey)) {
+                let Some(left) = reads_left.checked_sub(2) else {
+                    return true;
+                };
+                reads_left = left;
+                if shares != 0 && Self::if_subnet_exist(netuid) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /// Takes a provider reference on a coldkey that has stake, so the balances pallet doesn't reap
+    /// the account when all of its free balance is staked or transferred away.
+    pub fn maybe_add_stake_provider(coldkey: &T::AccountId) {
+        if !StakeProviders::<T>::get(coldkey) {
+            let _ = system::Pallet::<T>::inc_providers(coldkey);
+            StakeProviders::<T>::insert(coldkey, true);
+        }
+    }
+
+    /// Drops the stake provider reference of a coldkey once it has no stake left.
+    ///
+    /// Call this after the unstaked TAO is credited, otherwise an account with no free balance
+    /// would be reaped and then recreated by the deposit.
+    pub fn maybe_release_stake_provider(coldkey: &T::AccountId) {
+        if !StakeProviders::<T>::get(coldkey) || Self::coldkey_has_stake(coldkey) {
+            return;
+        }
+        if system::Pallet::<T>::dec_providers(coldkey).is_ok() {
+            StakeProviders::<T>::remove(coldkey);
+        }
+    }
+
     pub fn add_balance_to_coldkey_account(
         coldkey: &T::AccountId,
         amount: <<T as Config>::Currency as fungible::Inspect<<T as system::Config>::AccountId>>::Balance,
@@ -0,0 +1,187 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic lock_stake: Locks a portion of the stake a coldkey
+    /// holds on a hotkey on a subnet until the given block.
+    ///
+    /// Locked stake can't be unstaked or moved until the unlock block, which is what lets
+    /// governance weight be gated on it without voters unstaking right after a vote.
+    ///
+    /// Locking again while a lock is active never weakens it: the resulting lock keeps the larger
+    /// of the two amounts and the later of the two unlock blocks.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     -  The associated hotkey account.
+    ///
+    /// * 'netuid' (u16):
+    ///     -  The subnet the stake is locked on.
+    ///
+    /// * 'alpha_locked' (u64):
+    ///     -  The amount of alpha to lock.
+    ///
+    /// * 'until_block' (u64):
+    ///     -  The block at which the stake becomes movable again.
+    ///
+    /// # Event:
+    /// * StakeLocked;
+    ///     -  On successfully locking the stake.
+    ///
+    /// # Raises:
+    /// * 'SubnetNotExists':
+    ///     -  Thrown if the subnet does not exist.
+    ///
+    /// * 'HotKeyAccountNotExists':
+    ///     -  Thrown if the hotkey does not exist.
+    ///
+    /// * 'InvalidStakeLockBlock':
+    ///     -  Thrown if the unlock block is not in the future.
+    ///
+    /// * 'NotEnoughStakeToLock':
+    ///     -  Thrown if the coldkey doesn't hold enough stake on the hotkey to lock this amount.
+    ///
+    pub fn do_lock_stake(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        netuid: u16,
+        alpha_locked: u64,
+        until_block: u64,
+    ) -> dispatch::DispatchResult {
+        // 1. We check the transaction is signed by the caller and retrieve the T::AccountId coldkey information.
+        let coldkey = ensure_signed(origin)?;
+        log::debug!(
+            "do_lock_stake( origin:{:?} hotkey:{:?}, netuid: {:?}, alpha_locked:{:?}, until_block:{:?} )",
+            coldkey,
+            hotkey,
+            netuid,
+            alpha_locked,
+            until_block
+        );
+
+        // 2. Validate the user input.
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+        ensure!(
+            Self::hotkey_account_exists(&hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+        ensure!(
+            until_block > Self::get_current_block_as_u64(),
+            Error::<T>::InvalidStakeLockBlock
+        );
+        ensure!(
+            Self::has_enough_stake_on_subnet(&hotkey, &coldkey, netuid, alpha_locked),
+            Error::<T>::NotEnoughStakeToLock
+        );
+
+        // 3. Merge with the currently active lock, if any.
+        let (alpha_locked, until_block) =
+            Self::merge_stake_lock(&hotkey, &coldkey, netuid, alpha_locked, until_block);
+
+        // 4. Emit the event.
+        log::debug!(
+            "StakeLocked( coldkey:{:?}, hotkey:{:?}, netuid: {:?}, alpha_locked:{:?}, until_block:{:?} )",
+            coldkey,
+            hotkey,
+            netuid,
+            alpha_locked,
+            until_block
+        );
+        Self::deposit_event(Event::StakeLocked(
+            coldkey,
+            hotkey,
+            netuid,
+            alpha_locked,
+            until_block,
+        ));
+
+        // Done and ok.
+        Ok(())
+    }
+
+    /// Merges a lock into the active lock on the hotkey, coldkey and netuid, keeping the larger of
+    /// the two amounts and the later of the two unlock blocks, and returns the merged lock.
+    fn merge_stake_lock(
+        hotkey: &T::AccountId,
+        coldkey: &T::AccountId,
+        netuid: u16,
+        alpha_locked: u64,
+        until_block: u64,
+    ) -> (u64, u64) {
+        let (current_locked, current_until_block) =
+            Self::get_stake_lock(hotkey, coldkey, netuid).unwrap_or_default();
+        let merged = (
+            alpha_locked.max(current_locked),
+            until_block.max(current_until_block),
+        );
+        LockedStake::<T>::insert((hotkey, coldkey, netuid), merged);
+        merged
+    }
+
+    /// Removes the stake lock on the hotkey, coldkey and netuid once it has expired.
+    pub fn prune_expired_stake_lock(hotkey: &T::AccountId, coldkey: &T::AccountId, netuid: u16) {
+        if LockedStake::<T>::get((hotkey, coldkey, netuid))
+            .is_some_and(|(_, until_block)| until_block <= Self::get_current_block_as_u64())
+        {
+            LockedStake::<T>::remove((hotkey, coldkey, netuid));
+        }
+    }
+
+    /// Returns the active stake lock as `(alpha_locked, until_block)`, or `None` if there's no
+    /// lock or it has already expired.
+    pub fn get_stake_lock(
+        hotkey: &T::AccountId,
+        coldkey: &T::AccountId,
+        netuid: u16,
+    ) -> Option<(u64, u64)> {
+        LockedStake::<T>::get((hotkey, coldkey, netuid))
+            .filter(|(_, until_block)| *until_block > Self::get_current_block_as_u64())
+    }
+
+    /// Returns the amount of alpha currently locked for the hotkey, coldkey and netuid.
+    pub fn get_locked_stake_for_hotkey_and_coldkey_on_subnet(
+        hotkey: &T::AccountId,
+        coldkey: &T::AccountId,
+        netuid: u16,
+    ) -> u64 {
+        Self::get_stake_lock(hotkey, coldkey, netuid)
+            .map(|(alpha_locked, _)| alpha_locked)
+            .unwrap_or_default()
+    }
+
+    /// Returns the amount of alpha that can currently be unstaked or moved for the hotkey,
+    /// coldkey and netuid.
+    pub fn get_unlocked_stake_for_hotkey_and_coldkey_on_subnet(
+        hotkey: &T::AccountId,
+        coldkey: &T::AccountId,
+        netuid: u16,
+    ) -> u64 {
+        Self::get_stake_for_hotkey_and_coldkey_on_subnet(hotkey, coldkey, netuid).saturating_sub(
+            Self::get_locked_stake_for_hotkey_and_coldkey_on_subnet(hotkey, coldkey, netuid),
+        )
+    }
+
+    /// Moves a stake lock onto another (hotkey, coldkey) pair when keys are swapped, so that
+    /// swapping doesn't release locked stake. An existing lock on the destination is merged the
+    /// same way `do_lock_stake` merges locks.
+    pub fn swap_stake_lock(
+        old_hotkey: &T::AccountId,
+        old_coldkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        new_coldkey: &T::AccountId,
+        netuid: u16,
+    ) {
+        let Some((alpha_locked, until_block)) =
+            LockedStake::<T>::take((old_hotkey, old_coldkey, netuid))
+        else {
+            return;
+        };
+        if until_block <= Self::get_current_block_as_u64() {
+            return;
+        }
+
+        Self::merge_stake_lock(new_hotkey, new_coldkey, netuid, alpha_locked, until_block);
+    }
+}
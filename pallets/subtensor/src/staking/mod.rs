@@ -4,7 +4,9 @@ pub mod add_stake;
 pub mod decrease_take;
 pub mod helpers;
 pub mod increase_take;
+pub mod lock_stake;
 pub mod move_stake;
+pub mod recycle_stake;
 pub mod remove_stake;
 pub mod set_children;
 pub mod stake_utils;
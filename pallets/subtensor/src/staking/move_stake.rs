@@ -107,6 +107,36 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::TransferToggle(netuid, toggle));
         Ok(())
     }
+    pub fn set_stake_whitelist(netuid: u16, enabled: bool) {
+        StakeWhitelist::<T>::insert(netuid, enabled);
+        Self::deposit_event(Event::StakeWhitelistSet(netuid, enabled));
+    }
+    pub fn set_stake_allowed_coldkey(netuid: u16, coldkey: T::AccountId, allowed: bool) {
+        if allowed {
+            StakeAllowedColdkeys::<T>::insert(netuid, &coldkey, true);
+        } else {
+            StakeAllowedColdkeys::<T>::remove(netuid, &coldkey);
+        }
+        Self::deposit_event(Event::StakeWhitelistUpdated {
+            netuid,
+            coldkey,
+            allowed,
+        });
+    }
+    /// Returns whether the coldkey may add stake on the subnet, which is always the case unless
+    /// the subnet's stake whitelist is on.
+    pub fn is_coldkey_allowed_to_stake(netuid: u16, coldkey: &T::AccountId) -> bool {
+        !StakeWhitelist::<T>::get(netuid) || StakeAllowedColdkeys::<T>::get(netuid, coldkey)
+    }
+    /// Returns whether the coldkey may add stake on the subnet at all, independent of the hotkey
+    /// and amount: the subnet must exist, the coldkey must pass the subnet's stake whitelist,
+    /// and the coldkey must not have a coldkey swap scheduled. Call filters set by the runtime,
+    /// such as safe mode, are checked by the runtime API on top of this.
+    pub fn can_stake(netuid: u16, coldkey: &T::AccountId) -> bool {
+        Self::if_subnet_exist(netuid)
+            && Self::is_coldkey_allowed_to_stake(netuid, coldkey)
+            && !ColdkeySwapScheduled::<T>::contains_key(coldkey)
+    }
     pub fn do_transfer_stake(
         origin: T::RuntimeOrigin,
         destination_coldkey: T::AccountId,
@@ -373,6 +403,9 @@ impl<T: Config> Pallet<T> {
             );
         }
 
+        // A transfer can leave the origin coldkey without stake.
+        Self::maybe_release_stake_provider(origin_coldkey);
+
         Ok(tao_unstaked.saturating_sub(fee))
     }
 
@@ -0,0 +1,207 @@
+use super::*;
+use substrate_fixed::types::I96F32;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic recycle_deregistered_stake: Unstakes the alpha
+    /// nominated to a hotkey pruned from a subnet back to its coldkeys.
+    ///
+    /// A hotkey replaced by `replace_neuron` keeps its stake, but the stake no longer earns
+    /// anything on the subnet. Anyone can call this to return it to the nominators as TAO. Only
+    /// hotkeys recorded in `PrunedHotkeys` when they were pruned qualify, and never one with child
+    /// or parent keys on the subnet. At most `MAX_RECYCLED_NOMINATIONS` nominations are unstaked
+    /// and `MAX_RECYCLE_SCANNED_ENTRIES` stake entries read per call, so a hotkey with more
+    /// nominators takes several calls. The hotkey is dropped from `PrunedHotkeys` once all its
+    /// stake entries have been scanned. Locked stake stays in place until it unlocks. The unstakes
+    /// stop once the alpha price would fall `MAX_RECYCLE_SLIPPAGE_PERCENT` below the moving
+    /// price, so the call can't be sandwiched for more than that.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  Any signed account.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     -  The deregistered hotkey.
+    ///
+    /// * 'netuid' (u16):
+    ///     -  The subnet the hotkey was deregistered from.
+    ///
+    /// # Event:
+    /// * StakeRemoved;
+    ///     -  For every nomination unstaked.
+    ///
+    /// * DeregisteredStakeRecycled;
+    ///     -  With the number of nominations unstaked.
+    ///
+    /// # Raises:
+    /// * 'SubnetNotExists':
+    ///     -  Thrown if the subnet does not exist or is the root subnet.
+    ///
+    /// * 'HotKeyStillRegisteredOnSubnet':
+    ///     -  Thrown if the hotkey still holds a uid on the subnet.
+    ///
+    /// * 'HotKeyNotPrunedFromSubnet':
+    ///     -  Thrown if the hotkey was never pruned from the subnet, or its stake was already
+    ///        recycled.
+    ///
+    /// * 'HotKeyHasChildOrParentKeys':
+    ///     -  Thrown if the hotkey has child or parent keys on the subnet.
+    ///
+    /// * 'SlippageTooHigh':
+    ///     -  Thrown if the alpha price is already too far below the moving price.
+    ///
+    pub fn do_recycle_deregistered_stake(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        netuid: u16,
+    ) -> dispatch::DispatchResult {
+        // 1. Anyone can trigger the cleanup.
+        let caller = ensure_signed(origin)?;
+        log::debug!(
+            "do_recycle_deregistered_stake( origin:{:?} hotkey:{:?}, netuid: {:?} )",
+            caller,
+            hotkey,
+            netuid
+        );
+
+        // 2. Root stake isn't tied to a root uid, so only subnet stake is recycled.
+        ensure!(
+            netuid != Self::get_root_netuid() && Self::if_subnet_exist(netuid),
+            Error::<T>::SubnetNotExists
+        );
+
+        // 3. Ensure the hotkey was pruned from the subnet and has not registered again.
+        ensure!(
+            !Self::is_hotkey_registered_on_network(netuid, &hotkey),
+            Error::<T>::HotKeyStillRegisteredOnSubnet
+        );
+        ensure!(
+            PrunedHotkeys::<T>::contains_key(netuid, &hotkey),
+            Error::<T>::HotKeyNotPrunedFromSubnet
+        );
+
+        // 4. Stake backing child or parent keys is left alone.
+        ensure!(
+            !Self::has_child_or_parent_keys(&hotkey, netuid),
+            Error::<T>::HotKeyHasChildOrParentKeys
+        );
+
+        // 5. Ensure the price hasn't been pushed down ahead of the unstakes.
+        ensure!(
+            Self::get_max_amount_remove(netuid, Self::recycle_limit_price(netuid)) > 0,
+            Error::<T>::SlippageTooHigh
+        );
+
+        // 6. Swap a bounded batch of nominations back to TAO and credit them to their coldkeys.
+        // The hotkey is dropped from `PrunedHotkeys` once its stake entries are exhausted.
+        let (recycled, _) = Self::recycle_nominations(&hotkey, netuid, MAX_RECYCLED_NOMINATIONS);
+
+        // 7. Report the batch.
+        Self::deposit_event(Event::DeregisteredStakeRecycled {
+            hotkey,
+            netuid,
+            nominations: recycled,
+        });
+
+        // Done and ok.
+        Ok(())
+    }
+
+    /// Returns the weight of a `recycle_nominations` call unstaking up to
+    /// `MAX_RECYCLED_NOMINATIONS` nominations.
+    pub fn recycle_nominations_weight() -> Weight {
+        Weight::from_parts(111_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(15))
+            .saturating_add(T::DbWeight::get().reads(u64::from(MAX_STAKE_PROVIDER_SCAN_READS)))
+            .saturating_add(T::DbWeight::get().writes(7))
+            .saturating_mul(u64::from(MAX_RECYCLED_NOMINATIONS))
+            .saturating_add(
+                T::DbWeight::get().reads(u64::from(MAX_RECYCLE_SCANNED_ENTRIES).saturating_add(4)),
+            )
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Returns the lowest alpha price, in rao, that recycling deregistered stake may push the
+    /// subnet to: `MAX_RECYCLE_SLIPPAGE_PERCENT` below the moving price.
+    pub fn recycle_limit_price(netuid: u16) -> u64 {
+        Self::get_moving_alpha_price(netuid)
+            .saturating_mul(I96F32::saturating_from_num(
+                100_u64.saturating_sub(MAX_RECYCLE_SLIPPAGE_PERCENT),
+            ))
+            .saturating_mul(I96F32::saturating_from_num(10_000_000))
+            .saturating_to_num::<u64>()
+    }
+
+    /// Unstakes up to `limit` nominations with unlocked stake on `hotkey` on `netuid` back to
+    /// their coldkeys as TAO. Returns how many were unstaked, and whether the scan reached the end
+    /// of the hotkey's stake entries.
+    ///
+    /// At most `MAX_RECYCLE_SCANNED_ENTRIES` of the hotkey's stake entries, on any subnet, are
+    /// read. For a hotkey in `PrunedHotkeys` the scan resumes after the entry the previous call
+    /// stopped at, and is removed from `PrunedHotkeys` once it reaches the end. Unstaking stops at
+    /// `recycle_limit_price`, in which case the next call rescans the same entries.
+    pub fn recycle_nominations(hotkey: &T::AccountId, netuid: u16, limit: u32) -> (u32, bool) {
+        let start = PrunedHotkeys::<T>::get(netuid, hotkey).flatten();
+        let entries = match start.clone() {
+            Some((coldkey, alpha_netuid)) => Alpha::<T>::iter_prefix_from(
+                (hotkey,),
+                Alpha::<T>::hashed_key_for((hotkey, coldkey, alpha_netuid)),
+            ),
+            None => Alpha::<T>::iter_prefix((hotkey,)),
+        };
+
+        let mut nominations: Vec<(T::AccountId, u64)> = Vec::new();
+        let mut last_scanned: Option<(T::AccountId, u16)> = None;
+        let mut scanned: u32 = 0;
+        let mut exhausted = true;
+        for ((coldkey, alpha_netuid), _) in entries {
+            if scanned >= MAX_RECYCLE_SCANNED_ENTRIES || nominations.len() >= limit as usize {
+                exhausted = false;
+                break;
+            }
+            scanned = scanned.saturating_add(1);
+            if alpha_netuid == netuid {
+                let alpha = Self::get_unlocked_stake_for_hotkey_and_coldkey_on_subnet(
+                    hotkey, &coldkey, netuid,
+                );
+                if alpha > 0 {
+                    nominations.push((coldkey.clone(), alpha));
+                }
+            }
+            last_scanned = Some((coldkey, alpha_netuid));
+        }
+
+        let limit_price = Self::recycle_limit_price(netuid);
+        let mut recycled: u32 = 0;
+        let mut halted = false;
+        for (coldkey, alpha) in nominations {
+            let alpha = alpha.min(Self::get_max_amount_remove(netuid, limit_price));
+            if alpha == 0 {
+                halted = true;
+                break;
+            }
+            let fee = Self::calculate_staking_fee(
+                Some((hotkey, netuid)),
+                &coldkey,
+                None,
+                &coldkey,
+                I96F32::saturating_from_num(alpha),
+            );
+            let tao_unstaked: u64 = Self::unstake_from_subnet(hotkey, &coldkey, netuid, alpha, fee);
+            Self::add_balance_to_coldkey_account(&coldkey, tao_unstaked);
+            Self::clear_small_nomination_if_required(hotkey, &coldkey, netuid);
+            Self::maybe_release_stake_provider(&coldkey);
+            recycled = recycled.saturating_add(1);
+        }
+
+        if PrunedHotkeys::<T>::contains_key(netuid, hotkey) {
+            if halted {
+                PrunedHotkeys::<T>::insert(netuid, hotkey, start);
+            } else if exhausted {
+                PrunedHotkeys::<T>::remove(netuid, hotkey);
+            } else {
+                PrunedHotkeys::<T>::insert(netuid, hotkey, last_scanned);
+            }
+        }
+        (recycled, exhausted && !halted)
+    }
+}
@@ -73,6 +73,7 @@ impl<T: Config> Pallet<T> {
 
         // 5. If the stake is below the minimum, we clear the nomination from storage.
         Self::clear_small_nomination_if_required(&hotkey, &coldkey, netuid);
+        Self::maybe_release_stake_provider(&coldkey);
 
         // 6. Check if stake lowered below MinStake and remove Pending children if it did
         if Self::get_total_stake_for_hotkey(&hotkey) < StakeThreshold::<T>::get() {
@@ -85,6 +86,77 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// ---- The implementation for the extrinsic remove_stake_partial: Removes up to the requested
+    /// stake from a hotkey account and adds it onto a coldkey.
+    ///
+    /// The requested amount is clamped to the stake that can currently be withdrawn, so small
+    /// drifts between a client-side estimate and the on-chain stake don't fail the unstake.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     -  The associated hotkey account.
+    ///
+    /// * 'alpha_requested' (u64):
+    ///     -  The maximum amount of stake to be removed from the hotkey staking account.
+    ///
+    /// # Event:
+    /// * StakeRemoved;
+    ///     -  On the successfully removing stake from the hotkey account.
+    ///
+    /// * StakeRemovedPartial;
+    ///     -  With the requested and the actually removed amounts.
+    ///
+    /// # Raises:
+    /// * 'NotEnoughStakeToWithdraw':
+    ///     -  Thrown if there is no stake on the hotkey to withdraw.
+    ///
+    /// * 'StakeIsLocked':
+    ///     -  Thrown if all of the stake on the hotkey is locked.
+    ///
+    pub fn do_remove_stake_partial(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        netuid: u16,
+        alpha_requested: u64,
+    ) -> dispatch::DispatchResult {
+        // 1. We check the transaction is signed by the caller and retrieve the T::AccountId coldkey information.
+        let coldkey = ensure_signed(origin.clone())?;
+        log::debug!(
+            "do_remove_stake_partial( origin:{:?} hotkey:{:?}, netuid: {:?}, alpha_requested:{:?} )",
+            coldkey,
+            hotkey,
+            netuid,
+            alpha_requested
+        );
+
+        // 2. Clamp the requested amount to the stake that can be withdrawn.
+        let alpha_staked =
+            Self::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
+        ensure!(alpha_staked > 0, Error::<T>::NotEnoughStakeToWithdraw);
+        let alpha_available =
+            Self::get_unlocked_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
+        ensure!(alpha_available > 0, Error::<T>::StakeIsLocked);
+        let alpha_unstaked = alpha_requested.min(alpha_available);
+
+        // 3. Unstake the clamped amount.
+        Self::do_remove_stake(origin, hotkey.clone(), netuid, alpha_unstaked)?;
+
+        // 4. Report the requested and actual amounts.
+        Self::deposit_event(Event::StakeRemovedPartial(
+            coldkey,
+            hotkey,
+            netuid,
+            alpha_requested,
+            alpha_unstaked,
+        ));
+
+        // Done and ok.
+        Ok(())
+    }
+
     /// ---- The implementation for the extrinsic unstake_all: Removes all stake from a hotkey account across all subnets and adds it onto a coldkey.
     ///
     /// # Args:
@@ -131,9 +203,10 @@ impl<T: Config> Pallet<T> {
 
         // 4. Iterate through all subnets and remove stake.
         for netuid in netuids.into_iter() {
-            // Ensure that the hotkey has enough stake to withdraw.
-            let alpha_unstaked =
-                Self::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
+            // Only the unlocked part of the stake is withdrawn.
+            let alpha_unstaked = Self::get_unlocked_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey, &coldkey, netuid,
+            );
             let fee = Self::calculate_staking_fee(
                 Some((&hotkey, netuid)),
                 &coldkey,
@@ -154,6 +227,7 @@ impl<T: Config> Pallet<T> {
                 Self::clear_small_nomination_if_required(&hotkey, &coldkey, netuid);
             }
         }
+        Self::maybe_release_stake_provider(&coldkey);
 
         // 5. Done and ok.
         Ok(())
@@ -208,9 +282,10 @@ impl<T: Config> Pallet<T> {
         for netuid in netuids.into_iter() {
             // If not Root network.
             if netuid != Self::get_root_netuid() {
-                // Ensure that the hotkey has enough stake to withdraw.
-                let alpha_unstaked =
-                    Self::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
+                // Only the unlocked part of the stake is withdrawn.
+                let alpha_unstaked = Self::get_unlocked_stake_for_hotkey_and_coldkey_on_subnet(
+                    &hotkey, &coldkey, netuid,
+                );
                 let fee = Self::calculate_staking_fee(
                     Some((&hotkey, netuid)),
                     &coldkey,
@@ -335,6 +410,7 @@ impl<T: Config> Pallet<T> {
 
         // 6. If the stake is below the minimum, we clear the nomination from storage.
         Self::clear_small_nomination_if_required(&hotkey, &coldkey, netuid);
+        Self::maybe_release_stake_provider(&coldkey);
 
         // 7. Check if stake lowered below MinStake and remove Pending children if it did
         if Self::get_total_stake_for_hotkey(&hotkey) < StakeThreshold::<T>::get() {
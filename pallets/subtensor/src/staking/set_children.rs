@@ -272,6 +272,12 @@ impl<T: Config> Pallet<T> {
         ParentKeys::<T>::get(child, netuid)
     }
 
+    /// Returns true if the hotkey has child or parent keys on the subnet.
+    pub fn has_child_or_parent_keys(hotkey: &T::AccountId, netuid: u16) -> bool {
+        !ChildKeys::<T>::get(hotkey, netuid).is_empty()
+            || !ParentKeys::<T>::get(hotkey, netuid).is_empty()
+    }
+
     /// Sets the childkey take for a given hotkey.
     ///
     /// This function allows a coldkey to set the childkey take for a given hotkey.
@@ -45,6 +45,26 @@ impl<T: Config> Pallet<T> {
                 .unwrap_or(I96F32::saturating_from_num(0))
         }
     }
+
+    /// Returns the price of one alpha on the subnet in rao, i.e. `get_alpha_price` scaled by
+    /// 10^9 and saturated to `u64`.
+    pub fn get_alpha_price_in_rao(netuid: u16) -> u64 {
+        Self::get_alpha_price(netuid)
+            .saturating_mul(I96F32::saturating_from_num(1_000_000_000))
+            .saturating_to_num::<u64>()
+    }
+
+    /// Returns the `(SubnetTAO, SubnetAlphaIn)` reserves that price stake swaps on the subnet.
+    ///
+    /// Root and stable subnets don't price through a pool and swap 1:1, so `(0, 0)` is returned
+    /// for them.
+    pub fn get_subnet_reserves(netuid: u16) -> (u64, u64) {
+        if netuid == Self::get_root_netuid() || SubnetMechanism::<T>::get(netuid) == 0 {
+            return (0, 0);
+        }
+        (SubnetTAO::<T>::get(netuid), SubnetAlphaIn::<T>::get(netuid))
+    }
+
     pub fn get_moving_alpha_price(netuid: u16) -> I96F32 {
         if netuid == Self::get_root_netuid() {
             // Root.
@@ -712,6 +732,7 @@ impl<T: Config> Pallet<T> {
             SubnetVolume::<T>::mutate(netuid, |total| {
                 *total = total.saturating_add(tao.into());
             });
+            Self::record_recent_volume(netuid, tao);
             // Step 9. Return the alpha received.
             alpha
         } else {
@@ -719,6 +740,33 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Adds traded TAO to the current bucket of the subnet's recent volume ring buffer, dropping
+    /// buckets that fell out of the window.
+    pub fn record_recent_volume(netuid: u16, tao: u64) {
+        let bucket = Self::get_current_block_as_u64()
+            .checked_div(RECENT_VOLUME_BUCKET_BLOCKS)
+            .unwrap_or_default();
+        SubnetRecentVolume::<T>::mutate(netuid, |buckets| {
+            buckets.retain(|(b, _)| b.saturating_add(RECENT_VOLUME_BUCKETS) > bucket);
+            match buckets.last_mut() {
+                Some((b, volume)) if *b == bucket => *volume = volume.saturating_add(tao.into()),
+                _ => buckets.push((bucket, tao.into())),
+            }
+        });
+    }
+
+    /// Returns the TAO volume traded on the subnet in the last
+    /// `RECENT_VOLUME_BUCKETS * RECENT_VOLUME_BUCKET_BLOCKS` blocks, counted in whole buckets.
+    pub fn get_recent_volume(netuid: u16) -> u128 {
+        let bucket = Self::get_current_block_as_u64()
+            .checked_div(RECENT_VOLUME_BUCKET_BLOCKS)
+            .unwrap_or_default();
+        SubnetRecentVolume::<T>::get(netuid)
+            .into_iter()
+            .filter(|(b, _)| b.saturating_add(RECENT_VOLUME_BUCKETS) > bucket)
+            .fold(0u128, |total, (_, volume)| total.saturating_add(volume))
+    }
+
     /// Swaps a subnet's Alpba token for TAO.
     ///
     /// Updates TaoIn, AlphaIn, and AlphaOut
@@ -744,6 +792,7 @@ impl<T: Config> Pallet<T> {
             SubnetVolume::<T>::mutate(netuid, |total| {
                 *total = total.saturating_add(tao.into());
             });
+            Self::record_recent_volume(netuid, tao);
             // Step 9. Return the tao received.
             tao
         } else {
@@ -764,6 +813,7 @@ impl<T: Config> Pallet<T> {
         // Step 1: Decrease alpha on subneet
         let actual_alpha_decrease =
             Self::decrease_stake_for_hotkey_and_coldkey_on_subnet(hotkey, coldkey, netuid, alpha);
+        Self::prune_expired_stake_lock(hotkey, coldkey, netuid);
 
         // Step 2: Swap the alpha for TAO.
         let tao: u64 = Self::swap_alpha_for_tao(netuid, actual_alpha_decrease);
@@ -839,6 +889,9 @@ impl<T: Config> Pallet<T> {
                 staking_hotkeys.push(hotkey.clone());
                 StakingHotkeys::<T>::insert(coldkey, staking_hotkeys.clone());
             }
+
+            // Keep the coldkey alive while it has stake.
+            Self::maybe_add_stake_provider(coldkey);
         }
 
         // Step 5. Increase Tao reserves by the fee amount.
@@ -879,6 +932,26 @@ impl<T: Config> Pallet<T> {
         SharePool::<AlphaShareKey<T>, HotkeyAlphaSharePoolDataOperations<T>>::new(ops)
     }
 
+    /// Returns the smallest TAO amount that covers the default minimum stake and the staking fee.
+    fn get_default_min_add_stake_amount() -> u64 {
+        DefaultMinStake::<T>::get().saturating_add(DefaultStakingFee::<T>::get())
+    }
+
+    /// Returns the smallest TAO amount `validate_add_stake` accepts.
+    pub fn get_min_add_stake_amount() -> u64 {
+        Self::get_default_min_add_stake_amount().max(MinStakeAmount::<T>::get())
+    }
+
+    /// Returns the smallest TAO equivalent above the default minimum stake.
+    fn get_default_min_remove_stake_amount() -> u64 {
+        DefaultMinStake::<T>::get().saturating_add(1)
+    }
+
+    /// Returns the smallest TAO equivalent of an unstake that `validate_remove_stake` accepts.
+    pub fn get_min_remove_stake_amount() -> u64 {
+        Self::get_default_min_remove_stake_amount().max(MinStakeAmount::<T>::get())
+    }
+
     /// Validate add_stake user input
     ///
     pub fn validate_add_stake(
@@ -892,11 +965,23 @@ impl<T: Config> Pallet<T> {
         // Ensure that the subnet exists.
         ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
 
-        // Get the minimum balance (and amount) that satisfies the transaction
-        let min_amount = DefaultMinStake::<T>::get().saturating_add(DefaultStakingFee::<T>::get());
+        // Ensure that the stake_to_be_added covers the default minimum stake and the fee
+        ensure!(
+            stake_to_be_added >= Self::get_default_min_add_stake_amount(),
+            Error::<T>::AmountTooLow
+        );
+
+        // Ensure that the coldkey may stake on the subnet
+        ensure!(
+            Self::is_coldkey_allowed_to_stake(netuid, coldkey),
+            Error::<T>::NotWhitelisted
+        );
 
-        // Ensure that the stake_to_be_added is at least the min_amount
-        ensure!(stake_to_be_added >= min_amount, Error::<T>::AmountTooLow);
+        // Ensure that the stake_to_be_added is at least the configured minimum stake amount
+        ensure!(
+            stake_to_be_added >= Self::get_min_add_stake_amount(),
+            Error::<T>::AmountTooSmall
+        );
 
         // Ensure that if partial execution is not allowed, the amount will not cause
         // slippage over desired
@@ -929,6 +1014,25 @@ impl<T: Config> Pallet<T> {
         );
         ensure!(try_stake_result, Error::<T>::InsufficientLiquidity);
 
+        // Ensure a nomination doesn't end up below the minimum nominator stake, where it would
+        // be cleared by the next unstake or nomination sweep.
+        if !Self::coldkey_owns_hotkey(coldkey, hotkey) {
+            let possible_stake = if allow_partial {
+                stake_to_be_added.min(max_amount)
+            } else {
+                stake_to_be_added
+            };
+            let tao_staked = possible_stake.saturating_sub(Self::get_volume_staking_fee(netuid));
+            let alpha_staked = Self::sim_swap_tao_for_alpha(netuid, tao_staked).unwrap_or(0);
+            let resulting_stake =
+                Self::get_stake_for_hotkey_and_coldkey_on_subnet(hotkey, coldkey, netuid)
+                    .saturating_add(alpha_staked);
+            ensure!(
+                resulting_stake >= Self::get_nominator_min_required_stake(),
+                Error::<T>::StakeAmountTooLow
+            );
+        }
+
         Ok(())
     }
 
@@ -948,9 +1052,13 @@ impl<T: Config> Pallet<T> {
         // Ensure that the stake amount to be removed is above the minimum in tao equivalent.
         if let Some(tao_equivalent) = Self::sim_swap_alpha_for_tao(netuid, alpha_unstaked) {
             ensure!(
-                tao_equivalent > DefaultMinStake::<T>::get(),
+                tao_equivalent >= Self::get_default_min_remove_stake_amount(),
                 Error::<T>::AmountTooLow
             );
+            ensure!(
+                tao_equivalent >= Self::get_min_remove_stake_amount(),
+                Error::<T>::AmountTooSmall
+            );
         } else {
             return Err(Error::<T>::InsufficientLiquidity);
         };
@@ -973,6 +1081,15 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NotEnoughStakeToWithdraw
         );
 
+        // Ensure that the stake being withdrawn is not locked.
+        ensure!(
+            alpha_unstaked
+                <= Self::get_unlocked_stake_for_hotkey_and_coldkey_on_subnet(
+                    hotkey, coldkey, netuid
+                ),
+            Error::<T>::StakeIsLocked
+        );
+
         Ok(())
     }
 
@@ -981,7 +1098,7 @@ impl<T: Config> Pallet<T> {
     ///
     pub fn validate_stake_transition(
         origin_coldkey: &T::AccountId,
-        _destination_coldkey: &T::AccountId,
+        destination_coldkey: &T::AccountId,
         origin_hotkey: &T::AccountId,
         destination_hotkey: &T::AccountId,
         origin_netuid: u16,
@@ -1003,6 +1120,15 @@ impl<T: Config> Pallet<T> {
             );
         }
 
+        // Ensure that the destination coldkey may hold stake on the destination subnet. Moving
+        // stake between hotkeys on the same subnet doesn't bring in a new staker.
+        if origin_netuid != destination_netuid || origin_coldkey != destination_coldkey {
+            ensure!(
+                Self::is_coldkey_allowed_to_stake(destination_netuid, destination_coldkey),
+                Error::<T>::NotWhitelisted
+            );
+        }
+
         // Ensure that the origin hotkey account exists
         ensure!(
             Self::hotkey_account_exists(origin_hotkey),
@@ -1026,6 +1152,17 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NotEnoughStakeToWithdraw
         );
 
+        // Ensure that the stake being moved is not locked.
+        ensure!(
+            alpha_amount
+                <= Self::get_unlocked_stake_for_hotkey_and_coldkey_on_subnet(
+                    origin_hotkey,
+                    origin_coldkey,
+                    origin_netuid,
+                ),
+            Error::<T>::StakeIsLocked
+        );
+
         // Ensure that the stake amount to be removed is above the minimum in tao equivalent.
         let tao_equivalent_result = Self::sim_swap_alpha_for_tao(origin_netuid, alpha_amount);
         if let Some(tao_equivalent) = tao_equivalent_result {
@@ -1086,7 +1223,7 @@ impl<T: Config> Pallet<T> {
                     // This is a stake move/swap/transfer
                     if destination_netuid == origin_netuid {
                         // If destination is on the same subnet, use the default fee
-                        return DefaultStakingFee::<T>::get();
+                        return Self::get_volume_staking_fee(origin_netuid);
                     }
                 }
 
@@ -1094,7 +1231,7 @@ impl<T: Config> Pallet<T> {
                     || SubnetMechanism::<T>::get(origin_netuid) == 0
                 {
                     // If the origin netuid is root, or the subnet mechanism is 0, use the default fee
-                    DefaultStakingFee::<T>::get()
+                    Self::get_volume_staking_fee(origin_netuid)
                 } else {
                     // Otherwise, calculate the fee based on the alpha estimate
                     let fee = alpha_estimate
@@ -1110,13 +1247,44 @@ impl<T: Config> Pallet<T> {
                         .saturating_mul(Self::get_alpha_price(origin_netuid)) // fee needs to be in TAO
                         .saturating_to_num::<u64>();
 
-                    fee.max(DefaultStakingFee::<T>::get())
+                    fee.max(Self::get_volume_staking_fee(origin_netuid))
                 }
             }
             // If origin is not defined, we are adding stake; use default fee
-            None => DefaultStakingFee::<T>::get(),
+            None => destination
+                .map(|(_, destination_netuid)| Self::get_volume_staking_fee(destination_netuid))
+                .unwrap_or_else(DefaultStakingFee::<T>::get),
         }
     }
+
+    /// Returns the flat staking fee of a subnet after its volume discount.
+    ///
+    /// The fee is the one of the highest [`StakingFeeVolumeTiers`] threshold the subnet's
+    /// cumulative volume has reached, or [`DefaultStakingFee`] if no threshold was reached.
+    pub fn get_volume_staking_fee(netuid: u16) -> u64 {
+        let volume = SubnetVolume::<T>::get(netuid);
+        StakingFeeVolumeTiers::<T>::get()
+            .into_iter()
+            .take_while(|(threshold, _)| *threshold <= volume)
+            .last()
+            .map(|(_, fee)| fee)
+            .unwrap_or_else(DefaultStakingFee::<T>::get)
+    }
+
+    /// Returns true if there are at most [`MAX_STAKING_FEE_VOLUME_TIERS`] tiers with strictly
+    /// increasing volume thresholds and fees that never go up with volume or exceed the default
+    /// staking fee.
+    pub fn are_staking_fee_volume_tiers_valid(tiers: &[(u128, u64)]) -> bool {
+        let within_default_fee = tiers
+            .first()
+            .is_none_or(|(_, fee)| *fee <= DefaultStakingFee::<T>::get());
+        let sorted = tiers.iter().zip(tiers.iter().skip(1)).all(
+            |((threshold, fee), (next_threshold, next_fee))| {
+                next_threshold > threshold && next_fee <= fee
+            },
+        );
+        tiers.len() <= MAX_STAKING_FEE_VOLUME_TIERS && within_default_fee && sorted
+    }
 }
 
 ///////////////////////////////////////////
@@ -98,6 +98,11 @@ impl<T: Config> Pallet<T> {
                 < Self::get_max_registrations_per_block(netuid),
             Error::<T>::TooManyRegistrationsThisBlock
         );
+        ensure!(
+            Self::get_total_registrations_this_block()
+                < Self::get_max_total_registrations_per_block(),
+            Error::<T>::TooManyTotalRegistrationsThisBlock
+        );
 
         // --- 4. Ensure we are not exceeding the max allowed registrations per interval.
         ensure!(
@@ -134,9 +139,162 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NoNeuronIdAvailable
         );
 
-        // --- 10. Ensure the remove operation from the coldkey is a success.
+        // --- 10. Burn the registration cost and register the neuron.
+        Self::burn_and_register_neuron(&coldkey, netuid, hotkey, registration_cost)?;
+
+        // --- 11. Ok and done.
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic batch_register: registering several hotkeys of
+    /// the calling coldkey at once by burning TAO.
+    ///
+    /// The batch is all-or-nothing. Each hotkey takes a free slot or, once the subnet is full, the
+    /// slot of the neuron with the lowest pruning score, like a single burned registration. A
+    /// batch that would prune a neuron it registered itself doesn't fit in the subnet and fails.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the calling coldkey.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The u16 network identifier.
+    ///
+    /// * 'hotkeys' ( Vec<T::AccountId> ):
+    ///     - Hotkeys to be registered to the network, at most MAX_BATCH_REGISTRATIONS.
+    ///
+    /// # Event:
+    /// * NeuronRegistered;
+    ///     - For each hotkey registered to a neuron slot on the subnetwork.
+    ///
+    /// # Raises:
+    /// * 'InvalidBatchRegistrationSize':
+    ///     - The batch is empty or holds more than MAX_BATCH_REGISTRATIONS hotkeys.
+    ///
+    /// * 'SubNetworkDoesNotExist':
+    ///     - Attempting to registed to a non existent network.
+    ///
+    /// * 'TooManyRegistrationsThisBlock':
+    ///     - The batch exceeds the total allowed on this network this block.
+    ///
+    /// * 'TooManyRegistrationsThisInterval':
+    ///     - The batch exceeds the total allowed on this network this interval.
+    ///
+    /// * 'HotKeyAlreadyRegisteredInSubNet':
+    ///     - One of the hotkeys is already registered on this network.
+    ///
+    /// * 'BatchExceedsSubnetCapacity':
+    ///     - The subnet doesn't have room for the whole batch.
+    ///
+    pub fn do_batch_register(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        hotkeys: Vec<T::AccountId>,
+    ) -> DispatchResult {
+        // --- 1. Check that the caller has signed the transaction. (the coldkey of the pairing)
+        let coldkey = ensure_signed(origin)?;
+        log::debug!(
+            "do_batch_register( coldkey:{:?} netuid:{:?} hotkeys:{:?} )",
+            coldkey,
+            netuid,
+            hotkeys
+        );
+
+        // --- 2. Ensure the batch size is within bounds.
+        ensure!(
+            !hotkeys.is_empty() && hotkeys.len() <= MAX_BATCH_REGISTRATIONS as usize,
+            Error::<T>::InvalidBatchRegistrationSize
+        );
+        let batch_size = hotkeys.len() as u16;
+
+        // --- 3. Ensure the passed network is valid and allows registrations.
+        ensure!(
+            netuid != Self::get_root_netuid(),
+            Error::<T>::RegistrationNotPermittedOnRootSubnet
+        );
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            Self::get_network_registration_allowed(netuid),
+            Error::<T>::SubNetRegistrationDisabled
+        );
+
+        // --- 4. Ensure the whole batch stays within the registration limits.
+        ensure!(
+            Self::get_registrations_this_block(netuid).saturating_add(batch_size)
+                <= Self::get_max_registrations_per_block(netuid),
+            Error::<T>::TooManyRegistrationsThisBlock
+        );
+        ensure!(
+            Self::get_total_registrations_this_block().saturating_add(batch_size)
+                <= Self::get_max_total_registrations_per_block(),
+            Error::<T>::TooManyTotalRegistrationsThisBlock
+        );
+        ensure!(
+            Self::get_registrations_this_interval(netuid).saturating_add(batch_size)
+                <= Self::get_target_registrations_per_interval(netuid).saturating_mul(3),
+            Error::<T>::TooManyRegistrationsThisInterval
+        );
+        ensure!(
+            Self::get_max_allowed_uids(netuid) != 0,
+            Error::<T>::NoNeuronIdAvailable
+        );
+
+        // --- 5. Ensure the coldkey can pay for the whole batch.
+        let registration_cost = Self::get_burn_as_u64(netuid);
+        ensure!(
+            Self::can_remove_balance_from_coldkey_account(
+                &coldkey,
+                registration_cost.saturating_mul(batch_size as u64)
+            ),
+            Error::<T>::NotEnoughBalanceToStake
+        );
+
+        // --- 6. Register the hotkeys one by one. Any error reverts the whole batch.
+        let mut registered_uids: Vec<u16> = Vec::with_capacity(hotkeys.len());
+        for hotkey in hotkeys {
+            ensure!(
+                !Uids::<T>::contains_key(netuid, &hotkey),
+                Error::<T>::HotKeyAlreadyRegisteredInSubNet
+            );
+
+            Self::create_account_if_non_existent(&coldkey, &hotkey);
+            ensure!(
+                Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+                Error::<T>::NonAssociatedColdKey
+            );
+
+            // Once the subnet is full, the slot picked for replacement must not be one this batch
+            // just filled.
+            if Self::get_subnetwork_n(netuid) >= Self::get_max_allowed_uids(netuid) {
+                ensure!(
+                    !registered_uids.contains(&Self::get_neuron_to_prune(netuid)),
+                    Error::<T>::BatchExceedsSubnetCapacity
+                );
+            }
+
+            let neuron_uid =
+                Self::burn_and_register_neuron(&coldkey, netuid, hotkey, registration_cost)?;
+            registered_uids.push(neuron_uid);
+        }
+
+        // --- 7. Ok and done.
+        Ok(())
+    }
+
+    /// Burns the registration cost from the coldkey and registers the hotkey on the subnet,
+    /// recording the registration in the block and interval counters. Returns the neuron uid.
+    fn burn_and_register_neuron(
+        coldkey: &T::AccountId,
+        netuid: u16,
+        hotkey: T::AccountId,
+        registration_cost: u64,
+    ) -> Result<u16, DispatchError> {
+        // --- 1. Ensure the remove operation from the coldkey is a success.
         let actual_burn_amount =
-            Self::remove_balance_from_coldkey_account(&coldkey, registration_cost)?;
+            Self::remove_balance_from_coldkey_account(coldkey, registration_cost)?;
 
         // Tokens are swapped and then burned.
         let burned_alpha: u64 = Self::swap_tao_for_alpha(netuid, actual_burn_amount);
@@ -145,13 +303,14 @@ impl<T: Config> Pallet<T> {
         // Actually perform the registration.
         let neuron_uid: u16 = Self::register_neuron(netuid, &hotkey);
 
-        // --- 14. Record the registration and increment block and interval counters.
+        // --- 2. Record the registration and increment block and interval counters.
         BurnRegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
         RegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
         RegistrationsThisBlock::<T>::mutate(netuid, |val| val.saturating_inc());
+        TotalRegistrationsThisBlock::<T>::mutate(|val| val.saturating_inc());
         Self::increase_rao_recycled(netuid, Self::get_burn_as_u64(netuid));
 
-        // --- 15. Deposit successful event.
+        // --- 3. Deposit successful event.
         log::debug!(
             "NeuronRegistered( netuid:{:?} uid:{:?} hotkey:{:?}  ) ",
             netuid,
@@ -160,8 +319,7 @@ impl<T: Config> Pallet<T> {
         );
         Self::deposit_event(Event::NeuronRegistered(netuid, neuron_uid, hotkey));
 
-        // --- 16. Ok and done.
-        Ok(())
+        Ok(neuron_uid)
     }
 
     /// ---- The implementation for the extrinsic do_registration.
@@ -257,6 +415,11 @@ impl<T: Config> Pallet<T> {
                 < Self::get_max_registrations_per_block(netuid),
             Error::<T>::TooManyRegistrationsThisBlock
         );
+        ensure!(
+            Self::get_total_registrations_this_block()
+                < Self::get_max_total_registrations_per_block(),
+            Error::<T>::TooManyTotalRegistrationsThisBlock
+        );
 
         // --- 5. Ensure we are not exceeding the max allowed registrations per interval.
         ensure!(
@@ -324,6 +487,7 @@ impl<T: Config> Pallet<T> {
         POWRegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
         RegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
         RegistrationsThisBlock::<T>::mutate(netuid, |val| val.saturating_inc());
+        TotalRegistrationsThisBlock::<T>::mutate(|val| val.saturating_inc());
 
         // --- 13. Deposit successful event.
         log::debug!(
@@ -402,28 +566,37 @@ impl<T: Config> Pallet<T> {
     }
 
     /// Determine which peer to prune from the network by finding the element with the lowest pruning score out of
-    /// immunity period. If there is a tie for lowest pruning score, the neuron registered earliest is pruned.
-    /// If all neurons are in immunity period, the neuron with the lowest pruning score is pruned. If there is a tie for
-    /// the lowest pruning score, the immune neuron registered earliest is pruned.
-    /// Ties for earliest registration are broken by the neuron with the lowest uid.
+    /// immunity period. If there is a tie for lowest pruning score, the neuron registered earliest is pruned, or the
+    /// neuron registered latest if [`PruneNewestOnTie`] is set for the subnet.
+    /// If all neurons are in immunity period, the neuron with the lowest pruning score is pruned, with ties broken
+    /// the same way.
+    /// Ties for registration block are broken by the neuron with the lowest uid.
     pub fn get_neuron_to_prune(netuid: u16) -> u16 {
-        let mut min_score: u16 = u16::MAX;
-        let mut min_score_in_immunity: u16 = u16::MAX;
-        let mut earliest_registration: u64 = u64::MAX;
-        let mut earliest_registration_in_immunity: u64 = u64::MAX;
-        let mut uid_to_prune: u16 = 0;
-        let mut uid_to_prune_in_immunity: u16 = 0;
-
-        // This boolean is used instead of checking if min_score == u16::MAX, to avoid the case
-        // where all non-immune neurons have pruning score u16::MAX
-        // This may be unlikely in practice.
-        let mut found_non_immune = false;
+        // Best candidates so far as (pruning_score, block_at_registration, uid).
+        let mut to_prune: Option<(u16, u64, u16)> = None;
+        let mut to_prune_in_immunity: Option<(u16, u64, u16)> = None;
 
         let neurons_n = Self::get_subnetwork_n(netuid);
         if neurons_n == 0 {
             return 0; // If there are no neurons in this network.
         }
 
+        // A neuron replaces the current candidate if it has a lower pruning score, or if the
+        // scores are equal and it wins the registration block tie-break.
+        let prune_newest_on_tie = PruneNewestOnTie::<T>::get(netuid);
+        let replaces =
+            |pruning_score: u16, block_at_registration: u64, candidate: Option<(u16, u64, u16)>| {
+                candidate.is_none_or(|(min_score, tie_block, _)| {
+                    pruning_score < min_score
+                        || (pruning_score == min_score
+                            && if prune_newest_on_tie {
+                                block_at_registration > tie_block
+                            } else {
+                                block_at_registration < tie_block
+                            })
+                })
+            };
+
         for neuron_uid in 0..neurons_n {
             // Do not deregister the owner's hotkey from the `SubnetOwnerHotkey` map
             if let Ok(hotkey) = Self::get_hotkey_for_net_and_uid(netuid, neuron_uid) {
@@ -439,40 +612,24 @@ impl<T: Config> Pallet<T> {
                 Self::get_neuron_block_at_registration(netuid, neuron_uid);
             let is_immune = Self::get_neuron_is_immune(netuid, neuron_uid);
 
-            if is_immune {
-                // if the immune neuron has a lower pruning score than the minimum for immune neurons,
-                // or, if the pruning scores are equal and the immune neuron was registered earlier than the current minimum for immune neurons,
-                // then update the minimum pruning score and the uid to prune for immune neurons
-                if pruning_score < min_score_in_immunity
-                    || (pruning_score == min_score_in_immunity
-                        && block_at_registration < earliest_registration_in_immunity)
-                {
-                    min_score_in_immunity = pruning_score;
-                    earliest_registration_in_immunity = block_at_registration;
-                    uid_to_prune_in_immunity = neuron_uid;
-                }
+            // Immune and non-immune neurons are tracked separately, as immune neurons are only
+            // pruned when no non-immune neuron is left.
+            let candidate = if is_immune {
+                &mut to_prune_in_immunity
             } else {
-                found_non_immune = true;
-                // if the non-immune neuron has a lower pruning score than the minimum for non-immune neurons,
-                // or, if the pruning scores are equal and the non-immune neuron was registered earlier than the current minimum for non-immune neurons,
-                // then update the minimum pruning score and the uid to prune for non-immune neurons
-                if pruning_score < min_score
-                    || (pruning_score == min_score && block_at_registration < earliest_registration)
-                {
-                    min_score = pruning_score;
-                    earliest_registration = block_at_registration;
-                    uid_to_prune = neuron_uid;
-                }
+                &mut to_prune
+            };
+            if replaces(pruning_score, block_at_registration, *candidate) {
+                *candidate = Some((pruning_score, block_at_registration, neuron_uid));
             }
         }
 
-        if found_non_immune {
-            Self::set_pruning_score_for_uid(netuid, uid_to_prune, u16::MAX);
-            uid_to_prune
-        } else {
-            Self::set_pruning_score_for_uid(netuid, uid_to_prune_in_immunity, u16::MAX);
-            uid_to_prune_in_immunity
-        }
+        let uid_to_prune = to_prune
+            .or(to_prune_in_immunity)
+            .map(|(_, _, uid)| uid)
+            .unwrap_or_default();
+        Self::set_pruning_score_for_uid(netuid, uid_to_prune, u16::MAX);
+        uid_to_prune
     }
 
     /// Determine whether the given hash satisfies the given difficulty.
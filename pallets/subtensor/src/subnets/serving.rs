@@ -215,6 +215,77 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// ---- The implementation for the extrinsic set_neuron_certificate, which sets the TLS certificate
+    /// of the calling hotkey on a network without touching its axon information.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the caller.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The u16 network identifier.
+    ///
+    /// * 'certificate' (Vec<u8>):
+    ///     - The algorithm byte followed by the public key, at most 65 bytes in total.
+    ///
+    /// # Event:
+    /// * NeuronCertificateSet;
+    ///     - On successfully setting the certificate.
+    ///
+    /// # Raises:
+    /// * 'SubNetworkDoesNotExist':
+    ///     - Attempting to set a certificate on a non-existent network.
+    ///
+    /// * 'HotKeyNotRegisteredInSubNet':
+    ///     - The hotkey is not registered on the network.
+    ///
+    /// * 'InvalidNeuronCertificate':
+    ///     - The certificate is empty or longer than 65 bytes.
+    ///
+    /// * 'ServingRateLimitExceeded':
+    ///     - Attempting to set the certificate again within the serving rate limit.
+    ///
+    pub fn do_set_neuron_certificate(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        certificate: Vec<u8>,
+    ) -> dispatch::DispatchResult {
+        // We check the callers (hotkey) signature.
+        let hotkey_id = ensure_signed(origin)?;
+
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            Self::is_hotkey_registered_on_network(netuid, &hotkey_id),
+            Error::<T>::HotKeyNotRegisteredInSubNet
+        );
+
+        // The certificate has its own timer, limited to the subnet's serving rate limit.
+        let tx_type = TransactionType::SetNeuronCertificate;
+        ensure!(
+            Self::passes_rate_limit_on_subnet(&tx_type, &hotkey_id, netuid),
+            Error::<T>::ServingRateLimitExceeded
+        );
+
+        // The conversion rejects empty and oversized certificates.
+        let certificate = NeuronCertificateOf::try_from(certificate)
+            .map_err(|_| Error::<T>::InvalidNeuronCertificate)?;
+        NeuronCertificates::<T>::insert(netuid, hotkey_id.clone(), certificate);
+        Self::set_last_transaction_block_on_subnet(
+            &hotkey_id,
+            netuid,
+            &tx_type,
+            Self::get_current_block_as_u64(),
+        );
+
+        log::debug!("NeuronCertificateSet( hotkey:{:?} ) ", hotkey_id.clone());
+        Self::deposit_event(Event::NeuronCertificateSet(netuid, hotkey_id));
+
+        Ok(())
+    }
+
     /********************************
      --==[[  Helper functions   ]]==--
     *********************************/
@@ -239,6 +310,19 @@ impl<T: Config> Pallet<T> {
         rate_limit == 0 || last_serve == 0 || current_block.saturating_sub(last_serve) >= rate_limit
     }
 
+    /// Returns the certificate of a hotkey on a network in the same layout it is submitted in,
+    /// the algorithm byte followed by the public key, or an empty vector if none is set.
+    pub fn get_neuron_certificate(netuid: u16, hotkey: &T::AccountId) -> Vec<u8> {
+        NeuronCertificates::<T>::get(netuid, hotkey)
+            .map(|certificate| {
+                let mut bytes = Vec::with_capacity(certificate.public_key.len().saturating_add(1));
+                bytes.push(certificate.algorithm);
+                bytes.extend(certificate.public_key.into_inner());
+                bytes
+            })
+            .unwrap_or_default()
+    }
+
     pub fn get_axon_info(netuid: u16, hotkey: &T::AccountId) -> AxonInfoOf {
         if let Some(axons) = Axons::<T>::get(netuid, hotkey) {
             axons
@@ -256,6 +340,15 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Returns the axon served by `hotkey` on `netuid`, or `None` if the hotkey is not registered
+    /// on the subnet or has not served an axon there.
+    pub fn get_registered_axon_info(netuid: u16, hotkey: &T::AccountId) -> Option<AxonInfoOf> {
+        if !Self::is_hotkey_registered_on_network(netuid, hotkey) {
+            return None;
+        }
+        Axons::<T>::get(netuid, hotkey)
+    }
+
     pub fn get_prometheus_info(netuid: u16, hotkey: &T::AccountId) -> PrometheusInfoOf {
         if let Some(prometheus) = Prometheus::<T>::get(netuid, hotkey) {
             prometheus
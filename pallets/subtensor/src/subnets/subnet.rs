@@ -1,6 +1,8 @@
 use super::*;
+use alloc::collections::BTreeSet;
 use frame_support::IterableStorageMap;
-use sp_core::Get;
+use sp_core::{Get, H256};
+use sp_runtime::traits::{BlakeTwo256, Hash};
 
 impl<T: Config> Pallet<T> {
     /// Retrieves the unique identifier (UID) for the root network.
@@ -67,8 +69,11 @@ impl<T: Config> Pallet<T> {
 
     /// Finds the next available mechanism ID.
     ///
-    /// This function iterates through possible mechanism IDs starting from 0
-    /// until it finds an ID that is not currently in use.
+    /// This function iterates through possible mechanism IDs starting from 1
+    /// until it finds an ID that is not currently in use. Netuids freed by dissolved
+    /// subnets are reused, lowest first.
+    ///
+    /// This is the netuid the next `register_network` call will be assigned.
     ///
     /// # Returns
     /// * `u16` - The next available mechanism ID.
@@ -170,8 +175,13 @@ impl<T: Config> Pallet<T> {
         // --- 7. Set the lock amount for use to determine pricing.
         Self::set_network_last_lock(actual_tao_lock_amount);
 
-        // --- 8. Set initial and custom parameters for the network.
+        // --- 8. Set initial and custom parameters for the network, raising the tempo to the minimum.
         let default_tempo = DefaultTempo::<T>::get();
+        let default_tempo = if Self::is_tempo_allowed(default_tempo) {
+            default_tempo
+        } else {
+            Self::get_min_tempo()
+        };
         Self::init_new_network(netuid_to_register, default_tempo);
         log::debug!("init_new_network: {:?}", netuid_to_register);
 
@@ -245,6 +255,39 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Returns the smallest epoch offset for which the subnet doesn't run its epoch on the same
+    /// blocks as another subnet with the given tempo. Returns zero if every offset is taken.
+    pub fn get_free_epoch_offset(netuid: u16, tempo: u16) -> u64 {
+        let tempo_plus_one = (tempo as u64).saturating_add(1);
+        // Position of a subnet's epoch blocks within the epoch, for a given offset.
+        let phase = |netuid: u16, offset: u64| {
+            (netuid as u64)
+                .saturating_add(1)
+                .saturating_add(offset)
+                .checked_rem(tempo_plus_one)
+                .unwrap_or_default()
+        };
+        let taken: BTreeSet<u64> = Self::get_all_subnet_netuids()
+            .into_iter()
+            .filter(|other| *other != netuid && Self::get_tempo(*other) == tempo)
+            .map(|other| phase(other, Self::get_epoch_offset(other)))
+            .collect();
+
+        (0..tempo_plus_one)
+            .find(|offset| !taken.contains(&phase(netuid, *offset)))
+            .unwrap_or_default()
+    }
+
+    /// Returns the weight of `get_free_epoch_offset`, which reads the tempo and epoch offset of
+    /// every subnet.
+    pub fn get_free_epoch_offset_weight() -> Weight {
+        T::DbWeight::get().reads(
+            u64::from(Self::get_num_subnets())
+                .saturating_mul(3)
+                .saturating_add(1),
+        )
+    }
+
     /// Sets initial and custom parameters for a new network.
     pub fn init_new_network(netuid: u16, tempo: u16) {
         // --- 1. Set network to 0 size.
@@ -256,6 +299,9 @@ impl<T: Config> Pallet<T> {
         // --- 3. Fill tempo memory item.
         Tempo::<T>::insert(netuid, tempo);
 
+        // --- 3b. Stagger the network's epochs against the networks already running.
+        EpochOffset::<T>::insert(netuid, Self::get_free_epoch_offset(netuid, tempo));
+
         // --- 4 Fill modality item.
         NetworkModality::<T>::insert(netuid, 0);
 
@@ -320,4 +366,31 @@ impl<T: Config> Pallet<T> {
             );
         }
     }
+
+    /// Hashes the core state of a subnet: the hotkey, alpha stake and weights of every uid, in
+    /// uid order. Nodes with the same subnet state always compute the same hash.
+    pub fn get_subnet_state_hash(netuid: u16) -> H256 {
+        let neurons: Vec<(T::AccountId, u64, Vec<(u16, u16)>)> =
+            (0..Self::get_subnetwork_n(netuid))
+                .map(|uid| {
+                    let hotkey = Keys::<T>::get(netuid, uid);
+                    let stake = Self::get_stake_for_hotkey_on_subnet(&hotkey, netuid);
+                    (hotkey, stake, Weights::<T>::get(netuid, uid))
+                })
+                .collect();
+        BlakeTwo256::hash_of(&(netuid, neurons))
+    }
+
+    /// Emits the state hash of a subnet, so indexers can checkpoint the subnet and detect
+    /// divergence from the chain.
+    pub fn do_emit_subnet_state_hash(origin: T::RuntimeOrigin, netuid: u16) -> DispatchResult {
+        ensure_signed(origin)?;
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        let state_hash = Self::get_subnet_state_hash(netuid);
+        Self::deposit_event(Event::SubnetStateHash(netuid, state_hash));
+        Ok(())
+    }
 }
@@ -1,4 +1,5 @@
 use super::*;
+use alloc::collections::BTreeSet;
 use frame_support::storage::IterableStorageDoubleMap;
 use sp_std::vec;
 
@@ -8,6 +9,16 @@ impl<T: Config> Pallet<T> {
         SubnetworkN::<T>::get(netuid)
     }
 
+    /// Returns the number of free slots on a network.
+    pub fn get_available_slots(netuid: u16) -> u16 {
+        Self::get_max_allowed_uids(netuid).saturating_sub(Self::get_subnetwork_n(netuid))
+    }
+
+    /// Returns true if a registration on the network can take a free slot without pruning a neuron.
+    pub fn is_slot_available(netuid: u16) -> bool {
+        Self::get_available_slots(netuid) > 0
+    }
+
     /// Sets value for the element at the given position if it exists.
     pub fn set_element_at<N>(vec: &mut [N], position: usize, value: N) {
         if let Some(element) = vec.get_mut(position) {
@@ -57,11 +68,14 @@ impl<T: Config> Pallet<T> {
             }
         }
 
-        // 2. Remove previous set memberships.
+        // 2. Remove previous set memberships, and allow recycling the old hotkey's stake.
         Uids::<T>::remove(netuid, old_hotkey.clone());
         IsNetworkMember::<T>::remove(old_hotkey.clone(), netuid);
         #[allow(unknown_lints)]
         Keys::<T>::remove(netuid, uid_to_replace);
+        if TotalHotkeyAlpha::<T>::get(old_hotkey, netuid) > 0 {
+            PrunedHotkeys::<T>::insert(netuid, old_hotkey.clone(), None::<(T::AccountId, u16)>);
+        }
 
         // 3. Create new set memberships.
         Self::set_active_for_uid(netuid, uid_to_replace, true); // Set to active by default.
@@ -69,6 +83,7 @@ impl<T: Config> Pallet<T> {
         Uids::<T>::insert(netuid, new_hotkey.clone(), uid_to_replace); // Make uid - hotkey association.
         BlockAtRegistration::<T>::insert(netuid, uid_to_replace, block_number); // Fill block at registration.
         IsNetworkMember::<T>::insert(new_hotkey.clone(), netuid, true); // Fill network is member.
+        PrunedHotkeys::<T>::remove(netuid, new_hotkey); // A registered hotkey keeps its stake.
 
         // 4. Clear neuron certificates
         NeuronCertificates::<T>::remove(netuid, old_hotkey.clone());
@@ -78,6 +93,9 @@ impl<T: Config> Pallet<T> {
 
         // 5a. reset axon info for the new uid.
         Axons::<T>::remove(netuid, old_hotkey);
+
+        #[cfg(debug_assertions)]
+        Self::assert_subnet_vectors_consistent(netuid);
     }
 
     /// Appends the uid to the network.
@@ -112,6 +130,181 @@ impl<T: Config> Pallet<T> {
         Uids::<T>::insert(netuid, new_hotkey.clone(), next_uid); // Make uid - hotkey association.
         BlockAtRegistration::<T>::insert(netuid, next_uid, block_number); // Fill block at registration.
         IsNetworkMember::<T>::insert(new_hotkey.clone(), netuid, true); // Fill network is member.
+        PrunedHotkeys::<T>::remove(netuid, new_hotkey); // A registered hotkey keeps its stake.
+
+        #[cfg(debug_assertions)]
+        Self::assert_subnet_vectors_consistent(netuid);
+    }
+
+    /// Checks that every per-uid vector on the network has one entry per uid. A mismatch left by
+    /// a migration or a bug shifts positional reads and makes indexing elsewhere panic.
+    ///
+    /// The check is a `debug_assert`, so it only fires in debug builds.
+    pub fn assert_subnet_vectors_consistent(netuid: u16) {
+        let neurons_n = usize::from(Self::get_subnetwork_n(netuid));
+        let lengths: [(&str, usize); 11] = [
+            ("Rank", Rank::<T>::get(netuid).len()),
+            ("Trust", Trust::<T>::get(netuid).len()),
+            ("Active", Active::<T>::get(netuid).len()),
+            ("Emission", Emission::<T>::get(netuid).len()),
+            ("Consensus", Consensus::<T>::get(netuid).len()),
+            ("Incentive", Incentive::<T>::get(netuid).len()),
+            ("Dividends", Dividends::<T>::get(netuid).len()),
+            ("LastUpdate", LastUpdate::<T>::get(netuid).len()),
+            ("PruningScores", PruningScores::<T>::get(netuid).len()),
+            ("ValidatorTrust", ValidatorTrust::<T>::get(netuid).len()),
+            ("ValidatorPermit", ValidatorPermit::<T>::get(netuid).len()),
+        ];
+        for (name, len) in lengths {
+            debug_assert_eq!(
+                len, neurons_n,
+                "{} has {} entries on subnet {} with {} uids",
+                name, len, netuid, neurons_n
+            );
+        }
+    }
+
+    /// Deregisters neurons until the network holds at most `max_n` of them, then compacts the
+    /// remaining uids to `0..n` in their current order.
+    ///
+    /// Neurons are removed in the order `get_neuron_to_prune` would pick them: non-immune before
+    /// immune, lowest pruning score first, ties broken on the registration block. The subnet
+    /// owner hotkey is never removed. The stake of the removed hotkeys is left in place for
+    /// `recycle_deregistered_stake`, so the trim doesn't pay for unstaking every nomination.
+    ///
+    /// Pending weight commits hash the current uids, so the trim is refused while the subnet has
+    /// any, rather than letting a later reveal apply them to renumbered neurons.
+    ///
+    /// Returns the number of neurons removed.
+    pub fn trim_to_max_allowed_uids(netuid: u16, max_n: u16) -> Result<u16, DispatchError> {
+        let neurons_n: u16 = Self::get_subnetwork_n(netuid);
+        if neurons_n <= max_n {
+            return Ok(0);
+        }
+        ensure!(
+            !Self::has_pending_weight_commits(netuid),
+            Error::<T>::PendingWeightCommits
+        );
+
+        // 1. Pick the uids to remove in pruning order.
+        let owner_hotkey: Option<T::AccountId> = SubnetOwnerHotkey::<T>::try_get(netuid).ok();
+        let prune_newest_on_tie: bool = PruneNewestOnTie::<T>::get(netuid);
+        let mut candidates: Vec<(bool, u16, u64, u16)> = (0..neurons_n)
+            .filter(|uid| owner_hotkey.as_ref() != Some(&Keys::<T>::get(netuid, uid)))
+            .map(|uid| {
+                (
+                    Self::get_neuron_is_immune(netuid, uid),
+                    Self::get_pruning_score_for_uid(netuid, uid),
+                    Self::get_neuron_block_at_registration(netuid, uid),
+                    uid,
+                )
+            })
+            .collect();
+        candidates.sort_by(|a, b| {
+            let by_block = if prune_newest_on_tie {
+                b.2.cmp(&a.2)
+            } else {
+                a.2.cmp(&b.2)
+            };
+            a.0.cmp(&b.0).then(a.1.cmp(&b.1)).then(by_block)
+        });
+        let removed: BTreeSet<u16> = candidates
+            .into_iter()
+            .take(usize::from(neurons_n.saturating_sub(max_n)))
+            .map(|(_, _, _, uid)| uid)
+            .collect();
+        let kept: Vec<u16> = (0..neurons_n)
+            .filter(|uid| !removed.contains(uid))
+            .collect();
+
+        // 2. Remove the memberships and serving info of the removed hotkeys.
+        for uid in removed.iter() {
+            let hotkey: T::AccountId = Keys::<T>::get(netuid, uid);
+            Uids::<T>::remove(netuid, &hotkey);
+            IsNetworkMember::<T>::remove(&hotkey, netuid);
+            NeuronCertificates::<T>::remove(netuid, &hotkey);
+            Axons::<T>::remove(netuid, &hotkey);
+            Prometheus::<T>::remove(netuid, &hotkey);
+            if TotalHotkeyAlpha::<T>::get(&hotkey, netuid) > 0 {
+                PrunedHotkeys::<T>::insert(netuid, &hotkey, None::<(T::AccountId, u16)>);
+            }
+        }
+
+        // 3. Move the remaining neurons to their new uids, dropping weights and bonds on removed uids.
+        let new_uid = |uid: u16| -> Option<u16> {
+            kept.binary_search(&uid)
+                .ok()
+                .and_then(|index| u16::try_from(index).ok())
+        };
+        let remap = |row: Vec<(u16, u16)>| -> Vec<(u16, u16)> {
+            row.into_iter()
+                .filter_map(|(uid, value)| new_uid(uid).map(|uid| (uid, value)))
+                .collect()
+        };
+        let moved: Vec<(T::AccountId, u64, Vec<(u16, u16)>, Vec<(u16, u16)>)> = kept
+            .iter()
+            .map(|uid| {
+                (
+                    Keys::<T>::get(netuid, uid),
+                    BlockAtRegistration::<T>::get(netuid, uid),
+                    remap(Weights::<T>::get(netuid, uid)),
+                    remap(Bonds::<T>::get(netuid, uid)),
+                )
+            })
+            .collect();
+        for uid in 0..neurons_n {
+            Keys::<T>::remove(netuid, uid);
+            BlockAtRegistration::<T>::remove(netuid, uid);
+            Weights::<T>::remove(netuid, uid);
+            Bonds::<T>::remove(netuid, uid);
+        }
+        for (uid, (hotkey, block_at_registration, weights, bonds)) in (0..).zip(moved.into_iter()) {
+            Keys::<T>::insert(netuid, uid, hotkey.clone());
+            Uids::<T>::insert(netuid, hotkey, uid);
+            BlockAtRegistration::<T>::insert(netuid, uid, block_at_registration);
+            if !weights.is_empty() {
+                Weights::<T>::insert(netuid, uid, weights);
+            }
+            if !bonds.is_empty() {
+                Bonds::<T>::insert(netuid, uid, bonds);
+            }
+        }
+
+        // 4. Shrink the Yuma Consensus vectors.
+        Rank::<T>::mutate(netuid, |v| Self::retain_uids(v, &removed));
+        Trust::<T>::mutate(netuid, |v| Self::retain_uids(v, &removed));
+        Active::<T>::mutate(netuid, |v| Self::retain_uids(v, &removed));
+        Emission::<T>::mutate(netuid, |v| Self::retain_uids(v, &removed));
+        Consensus::<T>::mutate(netuid, |v| Self::retain_uids(v, &removed));
+        Incentive::<T>::mutate(netuid, |v| Self::retain_uids(v, &removed));
+        Dividends::<T>::mutate(netuid, |v| Self::retain_uids(v, &removed));
+        LastUpdate::<T>::mutate(netuid, |v| Self::retain_uids(v, &removed));
+        PruningScores::<T>::mutate(netuid, |v| Self::retain_uids(v, &removed));
+        ValidatorTrust::<T>::mutate(netuid, |v| Self::retain_uids(v, &removed));
+        ValidatorPermit::<T>::mutate(netuid, |v| Self::retain_uids(v, &removed));
+        StakeWeight::<T>::mutate(netuid, |v| Self::retain_uids(v, &removed));
+        let removed_n: u16 = neurons_n.saturating_sub(kept.len() as u16);
+        SubnetworkN::<T>::insert(netuid, kept.len() as u16);
+        #[cfg(debug_assertions)]
+        Self::assert_subnet_vectors_consistent(netuid);
+
+        log::debug!(
+            "trim_to_max_allowed_uids( netuid: {:?} | max_n: {:?} | removed: {:?} ) ",
+            netuid,
+            max_n,
+            removed_n
+        );
+        Ok(removed_n)
+    }
+
+    /// Drops the entries of a per-uid vector at the given uids.
+    fn retain_uids<V>(values: &mut Vec<V>, removed: &BTreeSet<u16>) {
+        let mut uid: u16 = 0;
+        values.retain(|_| {
+            let keep = !removed.contains(&uid);
+            uid = uid.saturating_add(1);
+            keep
+        });
     }
 
     /// Returns true if the uid is set on the network.
@@ -1059,7 +1059,9 @@ impl<T: Config> Pallet<T> {
         let tempo: u64 = Self::get_tempo(netuid) as u64;
         let tempo_plus_one: u64 = tempo.saturating_add(1);
         let netuid_plus_one: u64 = (netuid as u64).saturating_add(1);
-        let block_with_offset: u64 = block_number.saturating_add(netuid_plus_one);
+        let block_with_offset: u64 = block_number
+            .saturating_add(netuid_plus_one)
+            .saturating_add(Self::get_epoch_offset(netuid));
 
         block_with_offset.checked_div(tempo_plus_one).unwrap_or(0)
     }
@@ -1073,6 +1075,13 @@ impl<T: Config> Pallet<T> {
         current_epoch > commit_epoch.saturating_add(reveal_period)
     }
 
+    /// Returns true if the subnet has commit-reveal or CRV3 weight commits that haven't been
+    /// revealed or expired yet.
+    pub fn has_pending_weight_commits(netuid: u16) -> bool {
+        WeightCommits::<T>::iter_prefix(netuid).next().is_some()
+            || CRV3WeightCommits::<T>::iter_prefix(netuid).next().is_some()
+    }
+
     pub fn get_reveal_blocks(netuid: u16, commit_block: u64) -> (u64, u64) {
         let reveal_period: u64 = Self::get_reveal_period(netuid);
         let tempo: u64 = Self::get_tempo(netuid) as u64;
@@ -1084,7 +1093,8 @@ impl<T: Config> Pallet<T> {
 
         let first_reveal_block = reveal_epoch
             .saturating_mul(tempo_plus_one)
-            .saturating_sub(netuid_plus_one);
+            .saturating_sub(netuid_plus_one)
+            .saturating_sub(Self::get_epoch_offset(netuid));
         let last_reveal_block = first_reveal_block.saturating_add(tempo);
 
         (first_reveal_block, last_reveal_block)
@@ -1096,4 +1106,25 @@ impl<T: Config> Pallet<T> {
     pub fn get_reveal_period(netuid: u16) -> u64 {
         RevealPeriodEpochs::<T>::get(netuid)
     }
+
+    /// Returns, for each validator on the subnet, how many blocks have passed since it last set
+    /// weights. Validators are the uids holding a validator permit.
+    pub fn get_weight_ages(netuid: u16) -> Vec<(u16, u64)> {
+        let current_block = Self::get_current_block_as_u64();
+        let last_update = Self::get_last_update(netuid);
+        Self::get_validator_permit(netuid)
+            .into_iter()
+            .zip(last_update)
+            .zip(0..Self::get_subnetwork_n(netuid))
+            .filter(|((permit, _), _)| *permit)
+            .map(|((_, updated), uid)| (uid, current_block.saturating_sub(updated)))
+            .collect()
+    }
+
+    /// Returns the block at which the hotkey last set or committed weights on the subnet, or
+    /// `None` if the hotkey isn't registered there.
+    pub fn get_last_weight_set_block(netuid: u16, hotkey: &T::AccountId) -> Option<u64> {
+        let uid = Self::get_uid_for_net_and_hotkey(netuid, hotkey).ok()?;
+        Some(Self::get_last_update_for_uid(netuid, uid))
+    }
 }
@@ -172,9 +172,11 @@ impl<T: Config> Pallet<T> {
                 );
                 // Remove the value from the old account.
                 Alpha::<T>::remove((&hotkey, old_coldkey, netuid));
+                // Carry over any stake lock.
+                Self::swap_stake_lock(&hotkey, old_coldkey, &hotkey, new_coldkey, netuid);
             }
             // Add the weight for the read and write.
-            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(4, 4));
         }
 
         // 4. Swap TotalColdkeyAlpha (DEPRECATED)
@@ -204,6 +206,13 @@ impl<T: Config> Pallet<T> {
         StakingHotkeys::<T>::insert(new_coldkey, new_staking_hotkeys);
         weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
 
+        // Move the provider reference that keeps a staked coldkey alive.
+        if StakeProviders::<T>::get(old_coldkey) {
+            Self::maybe_add_stake_provider(new_coldkey);
+            Self::maybe_release_stake_provider(old_coldkey);
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+
         // 6. Swap hotkey owners.
         // Owner: MAP ( hotkey ) --> coldkey | Owner of the hotkey.
         // OwnedHotkeys: MAP ( coldkey ) --> Vec<hotkeys> | Hotkeys owned by the coldkey.
@@ -118,6 +118,7 @@ impl<T: Config> Pallet<T> {
     ///    - Updates axon information.
     ///    - Transfers weight commits.
     ///    - Updates loaded emission data.
+    ///    - Re-keys the emission still being smoothed.
     /// 10. Transfers all stake information, including updating staking hotkeys for each coldkey.
     ///
     /// Throughout the process, the function accumulates the computational weight of operations performed.
@@ -308,6 +309,31 @@ impl<T: Config> Pallet<T> {
                     weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
                 }
             }
+
+            // 10.8. Swap the emission still being smoothed.
+            // PendingSmoothedEmission( netuid ) --> SmoothedEmission -- the rest of the last drain.
+            // Dividends are paid to parents too, so this isn't limited to network members.
+            weight.saturating_accrue(T::DbWeight::get().reads(1));
+            if let Some(mut smoothed) = PendingSmoothedEmission::<T>::get(netuid) {
+                let rekey = |pending: &mut Vec<(T::AccountId, u64)>| -> bool {
+                    let Some(index) = pending.iter().position(|(hotkey, _)| hotkey == old_hotkey)
+                    else {
+                        return false;
+                    };
+                    let (_, amount) = pending.remove(index);
+                    match pending.iter_mut().find(|(hotkey, _)| hotkey == new_hotkey) {
+                        Some((_, existing)) => *existing = existing.saturating_add(amount),
+                        None => pending.push((new_hotkey.clone(), amount)),
+                    }
+                    true
+                };
+                let incentive_swapped = rekey(&mut smoothed.incentives);
+                let dividend_swapped = rekey(&mut smoothed.dividends);
+                if incentive_swapped || dividend_swapped {
+                    PendingSmoothedEmission::<T>::insert(netuid, smoothed);
+                    weight.saturating_accrue(T::DbWeight::get().writes(1));
+                }
+            }
         });
 
         // 11. Swap Alpha
@@ -328,6 +354,10 @@ impl<T: Config> Pallet<T> {
             );
             weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
 
+            // Carry over any stake lock.
+            Self::swap_stake_lock(old_hotkey, &coldkey, new_hotkey, &coldkey, netuid);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+
             // Swap StakingHotkeys.
             // StakingHotkeys( coldkey ) --> Vec<hotkey> -- the hotkeys that the coldkey stakes.
             let mut staking_hotkeys = StakingHotkeys::<T>::get(&coldkey);
@@ -4,7 +4,8 @@ use super::mock::*;
 use crate::*;
 use alloc::collections::BTreeMap;
 use approx::assert_abs_diff_eq;
-use frame_support::assert_ok;
+use frame_support::sp_runtime::DispatchError;
+use frame_support::{assert_noop, assert_ok};
 use sp_core::U256;
 use substrate_fixed::types::I64F64;
 use substrate_fixed::types::I96F32;
@@ -175,6 +176,82 @@ fn test_coinbase_tao_issuance_different_prices() {
     });
 }
 
+// Test the minimum subnet emission floor.
+// This test verifies that:
+// - A subnet whose price share is below the floor is lifted to it
+// - The difference is taken from the subnet above the floor
+// - The total TAO emitted is unchanged
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_coinbase_min_subnet_emission --exact --show-output --nocapture
+#[test]
+fn test_coinbase_min_subnet_emission() {
+    new_test_ext(1).execute_with(|| {
+        let netuid1: u16 = 1;
+        let netuid2: u16 = 2;
+        let emission: u64 = 100_000_000;
+        let floor: u64 = 30_000_000;
+        add_network(netuid1, 1, 0);
+        add_network(netuid2, 1, 0);
+        // Make subnets dynamic.
+        SubnetMechanism::<Test>::insert(netuid1, 1);
+        SubnetMechanism::<Test>::insert(netuid2, 1);
+        // Prices would split the emission 90/10.
+        SubnetMovingPrice::<Test>::insert(netuid1, I96F32::from_num(9));
+        SubnetMovingPrice::<Test>::insert(netuid2, I96F32::from_num(1));
+        SubtensorModule::set_min_subnet_emission(floor);
+        // Run the coinbase with the emission amount.
+        SubtensorModule::run_coinbase(I96F32::from_num(emission));
+        // The smaller subnet gets the floor and the larger one the rest.
+        assert_eq!(SubnetTaoInEmission::<Test>::get(netuid2), floor);
+        close(
+            SubnetTaoInEmission::<Test>::get(netuid1),
+            emission - floor,
+            2,
+        );
+        close(
+            SubnetTAO::<Test>::get(netuid1) + SubnetTAO::<Test>::get(netuid2),
+            emission,
+            2,
+        );
+        close(TotalIssuance::<Test>::get(), emission, 2);
+    });
+}
+
+// Test the maximum subnet emission cap.
+// This test verifies that:
+// - A dominant subnet is capped at the maximum subnet emission
+// - The excess is redistributed to the other subnets by their share
+// - The total TAO emitted is unchanged
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_coinbase_max_subnet_emission --exact --show-output --nocapture
+#[test]
+fn test_coinbase_max_subnet_emission() {
+    new_test_ext(1).execute_with(|| {
+        let netuid1: u16 = 1;
+        let netuid2: u16 = 2;
+        let netuid3: u16 = 3;
+        let emission: u64 = 100_000_000;
+        let cap: u64 = 50_000_000;
+        add_network(netuid1, 1, 0);
+        add_network(netuid2, 1, 0);
+        add_network(netuid3, 1, 0);
+        // Make subnets dynamic.
+        SubnetMechanism::<Test>::insert(netuid1, 1);
+        SubnetMechanism::<Test>::insert(netuid2, 1);
+        SubnetMechanism::<Test>::insert(netuid3, 1);
+        // Prices would split the emission 80/15/5.
+        SubnetMovingPrice::<Test>::insert(netuid1, I96F32::from_num(16));
+        SubnetMovingPrice::<Test>::insert(netuid2, I96F32::from_num(3));
+        SubnetMovingPrice::<Test>::insert(netuid3, I96F32::from_num(1));
+        SubtensorModule::set_max_subnet_emission(cap);
+        // Run the coinbase with the emission amount.
+        SubtensorModule::run_coinbase(I96F32::from_num(emission));
+        // The dominant subnet is capped and its 30M excess is split 3:1.
+        assert_eq!(SubnetTaoInEmission::<Test>::get(netuid1), cap);
+        close(SubnetTaoInEmission::<Test>::get(netuid2), 37_500_000, 2);
+        close(SubnetTaoInEmission::<Test>::get(netuid3), 12_500_000, 2);
+        close(TotalIssuance::<Test>::get(), emission, 2);
+    });
+}
+
 // Test moving price updates with different alpha values.
 // This test verifies that:
 // - Moving price stays constant when alpha is 1.0
@@ -473,6 +550,114 @@ fn test_pending_swapped() {
     });
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_pending_swapped_follows_validator_emission_ratio --exact --show-output --nocapture
+#[test]
+fn test_pending_swapped_follows_validator_emission_ratio() {
+    for (ratio, swapped) in [(0, 0), (u16::MAX, 250000000)] {
+        new_test_ext(1).execute_with(|| {
+            let netuid: u16 = 1;
+            add_network(netuid, 1, 0);
+            SubtensorModule::set_validator_emission_ratio(netuid, ratio);
+            SubtensorModule::run_coinbase(I96F32::from_num(0));
+            SubnetTAO::<Test>::insert(0, 1_000_000_000); // Add root weight.
+            SubtensorModule::run_coinbase(I96F32::from_num(0));
+            SubtensorModule::set_tempo(netuid, 10000); // Large number (dont drain)
+            SubtensorModule::set_tao_weight(u64::MAX); // Set TAO weight to 1.0
+            SubtensorModule::run_coinbase(I96F32::from_num(0));
+            // 1 TAO / ( 1 + 3 ) = 0.25 * 1 * validator share
+            assert_eq!(PendingAlphaSwapped::<Test>::get(netuid), swapped);
+            assert_eq!(
+                PendingEmission::<Test>::get(netuid),
+                1_000_000_000 - swapped
+            );
+            assert_eq!(PendingRootDivs::<Test>::get(netuid), swapped);
+        });
+    }
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_drain_pays_out_swapped_tao_at_any_validator_emission_ratio --exact --show-output --nocapture
+#[test]
+fn test_drain_pays_out_swapped_tao_at_any_validator_emission_ratio() {
+    for ratio in [0, u16::MAX] {
+        new_test_ext(1).execute_with(|| {
+            let root: u16 = 0;
+            let netuid: u16 = 1;
+            add_network(netuid, 1, 0);
+            let hotkeys = [U256::from(1), U256::from(2)];
+            let coldkey = U256::from(3);
+            let stake_before: u64 = 1_000_000_000;
+            for hotkey in hotkeys {
+                register_ok_neuron(netuid, hotkey, coldkey, 0);
+                Delegates::<Test>::insert(hotkey, 0);
+                for subnet in [netuid, root] {
+                    SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                        &hotkey,
+                        &coldkey,
+                        subnet,
+                        stake_before,
+                    );
+                }
+            }
+            SubnetTAO::<Test>::insert(root, 2 * stake_before);
+            SubtensorModule::set_tao_weight(u64::MAX); // Set TAO weight to 1.0
+            SubtensorModule::set_validator_emission_ratio(netuid, ratio);
+
+            let issuance_before = TotalIssuance::<Test>::get();
+            let subnet_tao_before = SubnetTAO::<Test>::get(netuid);
+            for block in 1..=5 {
+                System::set_block_number(block);
+                SubtensorModule::run_coinbase(I96F32::from_num(0));
+            }
+            assert_eq!(PendingRootDivs::<Test>::get(netuid), 0);
+
+            // The TAO bought with root's alpha ends up with the root stakers, none of it is lost.
+            let root_paid: u64 = hotkeys
+                .iter()
+                .map(|hotkey| SubtensorModule::get_stake_for_hotkey_on_subnet(hotkey, root))
+                .sum::<u64>()
+                - 2 * stake_before;
+            close(
+                TotalIssuance::<Test>::get() - issuance_before,
+                SubnetTAO::<Test>::get(netuid) - subnet_tao_before + root_paid,
+                10,
+            );
+            // Without a validator share root stakers aren't paid at all.
+            if ratio == 0 {
+                assert_eq!(root_paid, 0);
+            }
+        });
+    }
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_pending_emission_drains_on_subnet_tempo --exact --show-output --nocapture
+#[test]
+#[allow(clippy::arithmetic_side_effects)]
+fn test_pending_emission_drains_on_subnet_tempo() {
+    new_test_ext(1).execute_with(|| {
+        let subnets: [(u16, u16); 2] = [(1, 2), (2, 5)];
+        for (netuid, tempo) in subnets {
+            add_network(netuid, tempo, 0);
+        }
+
+        for block in 1..=30_u64 {
+            System::set_block_number(block);
+            SubtensorModule::run_coinbase(I96F32::from_num(0));
+
+            // Each subnet drains on the blocks of its own tempo only.
+            for (netuid, tempo) in subnets {
+                let drains = (block + netuid as u64 + 1) % (tempo as u64 + 1) == 0;
+                assert_eq!(
+                    PendingEmission::<Test>::get(netuid) == 0,
+                    drains,
+                    "netuid {} at block {}",
+                    netuid,
+                    block
+                );
+            }
+        }
+    });
+}
+
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_drain_base --exact --show-output --nocapture
 #[test]
 fn test_drain_base() {
@@ -1488,3 +1673,480 @@ fn test_incentive_to_subnet_owner_is_burned() {
         assert!(other_stake_after > 0);
     });
 }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_emission_smoothing_spreads_drain_over_blocks --exact --show-output --nocapture
+#[test]
+fn test_emission_smoothing_spreads_drain_over_blocks() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_ck = U256::from(0);
+        let subnet_owner_hk = U256::from(1);
+        let netuid = add_dynamic_network(&subnet_owner_hk, &subnet_owner_ck);
+        let owner_cut: u64 = 1_003;
+
+        SubtensorModule::set_emission_smoothing_blocks(netuid, 4);
+        SubtensorModule::drain_pending_emission(netuid, 0, 0, 0, owner_cut);
+
+        // The drain block pays out the first share, each following block the next one.
+        for paid_out in [250, 501, 752] {
+            assert_eq!(
+                SubtensorModule::get_stake_for_hotkey_on_subnet(&subnet_owner_hk, netuid),
+                paid_out
+            );
+            assert!(PendingSmoothedEmission::<Test>::contains_key(netuid));
+            SubtensorModule::distribute_smoothed_emission(netuid);
+        }
+
+        // The last block pays out the rounding remainder.
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_on_subnet(&subnet_owner_hk, netuid),
+            owner_cut
+        );
+        assert!(!PendingSmoothedEmission::<Test>::contains_key(netuid));
+
+        // Nothing is left to pay out.
+        SubtensorModule::distribute_smoothed_emission(netuid);
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_on_subnet(&subnet_owner_hk, netuid),
+            owner_cut
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_emission_smoothing_flushes_on_next_drain --exact --show-output --nocapture
+#[test]
+fn test_emission_smoothing_flushes_on_next_drain() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_ck = U256::from(0);
+        let subnet_owner_hk = U256::from(1);
+        let netuid = add_dynamic_network(&subnet_owner_hk, &subnet_owner_ck);
+
+        SubtensorModule::set_emission_smoothing_blocks(netuid, 10);
+        SubtensorModule::drain_pending_emission(netuid, 0, 0, 0, 1_000);
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_on_subnet(&subnet_owner_hk, netuid),
+            100
+        );
+
+        // A new drain pays out what's left of the previous one before smoothing its own.
+        SubtensorModule::drain_pending_emission(netuid, 0, 0, 0, 1_000);
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_on_subnet(&subnet_owner_hk, netuid),
+            1_100
+        );
+        assert_eq!(
+            PendingSmoothedEmission::<Test>::get(netuid).map(|s| s.owner_cut),
+            Some(900)
+        );
+
+        // Dissolving the subnet drops the setting, so a reused netuid doesn't inherit it.
+        SubtensorModule::remove_network(netuid);
+        assert!(!EmissionSmoothingBlocks::<Test>::contains_key(netuid));
+        assert!(PendingSmoothedEmission::<Test>::get(netuid).is_none());
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_subnet_owner_cut_paid_event_matches_owner_cut --exact --show-output --nocapture
+#[test]
+fn test_subnet_owner_cut_paid_event_matches_owner_cut() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_ck = U256::from(0);
+        let subnet_owner_hk = U256::from(1);
+        let netuid = add_dynamic_network(&subnet_owner_hk, &subnet_owner_ck);
+        SubtensorModule::set_tempo(netuid, 10000); // Large number (dont drain)
+
+        // A quarter of the 1 alpha emitted per block goes to the owner.
+        SubtensorModule::set_subnet_owner_cut(u16::MAX / 4);
+        SubtensorModule::run_coinbase(I96F32::from_num(0));
+        let owner_cut = SubtensorModule::get_pending_owner_cut(netuid);
+        close(owner_cut, 250_000_000, 10);
+
+        System::reset_events();
+        SubtensorModule::drain_pending_emission(netuid, 0, 0, 0, owner_cut);
+        System::assert_has_event(
+            Event::SubnetOwnerCutPaid {
+                netuid,
+                coldkey: subnet_owner_ck,
+                amount: owner_cut,
+            }
+            .into(),
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_on_subnet(&subnet_owner_hk, netuid),
+            owner_cut
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_coinbase_stops_at_max_total_issuance --exact --show-output --nocapture
+#[test]
+fn test_coinbase_stops_at_max_total_issuance() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let emission: u64 = 1_000_000_000;
+        add_network(netuid, 1, 0);
+        let issuance_before = TotalIssuance::<Test>::get();
+        let cap = issuance_before + emission + emission / 2;
+        SubtensorModule::set_max_total_issuance(cap);
+
+        // Below the cap the full emission goes out.
+        SubtensorModule::run_coinbase(I96F32::from_num(emission));
+        assert_eq!(TotalIssuance::<Test>::get(), issuance_before + emission);
+
+        // The block crossing the cap is cut down to land exactly on it.
+        SubtensorModule::run_coinbase(I96F32::from_num(emission));
+        assert_eq!(TotalIssuance::<Test>::get(), cap);
+        assert_eq!(SubnetTAO::<Test>::get(netuid), cap - issuance_before);
+
+        // At the cap nothing more is emitted.
+        SubtensorModule::run_coinbase(I96F32::from_num(emission));
+        assert_eq!(TotalIssuance::<Test>::get(), cap);
+        assert_eq!(SubnetTAO::<Test>::get(netuid), cap - issuance_before);
+        assert_eq!(SubnetTaoInEmission::<Test>::get(netuid), 0);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_get_remaining_issuance --exact --show-output --nocapture
+#[test]
+fn test_get_remaining_issuance() {
+    new_test_ext(1).execute_with(|| {
+        TotalIssuance::<Test>::put(1_000_000);
+        SubtensorModule::set_max_total_issuance(1_500_000);
+        assert_eq!(SubtensorModule::get_remaining_issuance(), 500_000);
+
+        // Issuance already above a lowered cap leaves nothing to emit.
+        SubtensorModule::set_max_total_issuance(800_000);
+        assert_eq!(SubtensorModule::get_remaining_issuance(), 0);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_force_epoch_mid_tempo --exact --show-output --nocapture
+#[test]
+fn test_force_epoch_mid_tempo() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 100, 0);
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let stake_before: u64 = 1_000_000_000;
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey,
+            &coldkey,
+            netuid,
+            stake_before,
+        );
+        let pending_alpha: u64 = 1_000_000_000;
+        PendingEmission::<Test>::insert(netuid, pending_alpha);
+        BlocksSinceLastStep::<Test>::insert(netuid, 10);
+        let current_block = SubtensorModule::get_current_block_as_u64();
+        assert!(!SubtensorModule::should_run_epoch(netuid, current_block));
+
+        // Only root can force an epoch, and never on the root subnet.
+        assert_noop!(
+            SubtensorModule::force_epoch(RuntimeOrigin::signed(coldkey), netuid),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            SubtensorModule::force_epoch(RuntimeOrigin::root(), 0),
+            Error::<Test>::CannotForceRootEpoch
+        );
+        assert_noop!(
+            SubtensorModule::force_epoch(RuntimeOrigin::root(), 2),
+            Error::<Test>::SubnetNotExists
+        );
+
+        assert_ok!(SubtensorModule::force_epoch(RuntimeOrigin::root(), netuid));
+        System::assert_last_event(RuntimeEvent::SubtensorModule(Event::EpochForced(netuid)));
+
+        // The pending emission was distributed and the step counters restarted.
+        assert_eq!(PendingEmission::<Test>::get(netuid), 0);
+        assert_eq!(BlocksSinceLastStep::<Test>::get(netuid), 0);
+        assert_eq!(LastMechansimStepBlock::<Test>::get(netuid), current_block);
+        let stake_after =
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
+        close(stake_before + pending_alpha, stake_after, 10);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_is_epoch_block --exact --show-output --nocapture
+#[test]
+fn test_is_epoch_block() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 10;
+        add_network(netuid, tempo, 0);
+
+        // Subnets run their epoch when (block + netuid + 1) % (tempo + 1) == 0.
+        let epoch_blocks: Vec<u64> = (0..50)
+            .filter(|block| SubtensorModule::is_epoch_block(netuid, *block))
+            .collect();
+        assert_eq!(epoch_blocks, vec![9, 20, 31, 42]);
+
+        // The root subnet and missing subnets never run an epoch.
+        add_network(0, tempo, 0);
+        assert!(!(0..50).any(|block| SubtensorModule::is_epoch_block(0, block)));
+        assert!(!(0..50).any(|block| SubtensorModule::is_epoch_block(2, block)));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_epoch_offset_staggers_epochs --exact --show-output --nocapture
+#[test]
+fn test_epoch_offset_staggers_epochs() {
+    new_test_ext(1).execute_with(|| {
+        let tempo: u16 = 10;
+        add_network(1, tempo, 0);
+        add_network(2, tempo, 0);
+        let epoch_blocks = |netuid: u16| -> Vec<u64> {
+            (0..50)
+                .filter(|block| SubtensorModule::should_run_epoch(netuid, *block))
+                .collect()
+        };
+
+        // Offsets that line the two subnets up make them run on the same blocks.
+        SubtensorModule::set_epoch_offset(1, 1);
+        assert_eq!(epoch_blocks(1), epoch_blocks(2));
+
+        // Different offsets run them on different blocks, shifted by the offset difference.
+        SubtensorModule::set_epoch_offset(1, 0);
+        SubtensorModule::set_epoch_offset(2, 4);
+        assert_eq!(epoch_blocks(1), vec![9, 20, 31, 42]);
+        assert_eq!(epoch_blocks(2), vec![4, 15, 26, 37, 48]);
+
+        // The epoch index rolls over on the shifted boundary.
+        assert_eq!(
+            SubtensorModule::get_epoch_index(2, 4),
+            SubtensorModule::get_epoch_index(2, 3) + 1
+        );
+
+        // Reveal windows follow the shifted epochs.
+        let reveal_epoch =
+            SubtensorModule::get_epoch_index(2, 4) + SubtensorModule::get_reveal_period(2);
+        let (first_reveal_block, last_reveal_block) = SubtensorModule::get_reveal_blocks(2, 4);
+        assert_eq!(
+            SubtensorModule::get_epoch_index(2, first_reveal_block),
+            reveal_epoch
+        );
+        assert_eq!(
+            SubtensorModule::get_epoch_index(2, first_reveal_block - 1),
+            reveal_epoch - 1
+        );
+        assert_eq!(
+            SubtensorModule::get_epoch_index(2, last_reveal_block),
+            reveal_epoch
+        );
+        assert_eq!(
+            SubtensorModule::get_epoch_index(2, last_reveal_block + 1),
+            reveal_epoch + 1
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_get_upcoming_epochs --exact --show-output --nocapture
+#[test]
+fn test_get_upcoming_epochs() {
+    new_test_ext(1).execute_with(|| {
+        add_network(0, 10, 0);
+        add_network(1, 10, 0);
+        add_network(2, 4, 0);
+        SubtensorModule::set_epoch_offset(2, 1);
+        System::set_block_number(10);
+
+        // The schedule matches the per-block epoch check for every subnet but root.
+        let upcoming = SubtensorModule::get_upcoming_epochs(20);
+        let mut expected: Vec<(u16, u64)> = Vec::new();
+        for block in 11..=30 {
+            for netuid in [1, 2] {
+                if SubtensorModule::should_run_epoch(netuid, block) {
+                    expected.push((netuid, block));
+                }
+            }
+        }
+        assert_eq!(upcoming, expected);
+        assert_eq!(upcoming, vec![(2, 11), (2, 16), (1, 20), (2, 21), (2, 26)]);
+
+        // The current block is excluded, and an empty window has no epochs.
+        assert!(SubtensorModule::get_upcoming_epochs(0).is_empty());
+
+        // The window is capped.
+        let capped = SubtensorModule::get_upcoming_epochs(u64::MAX);
+        assert_eq!(
+            capped
+                .last()
+                .map(|(_, block)| *block <= 10 + MAX_UPCOMING_EPOCHS_WINDOW),
+            Some(true)
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_force_drain_pending_emission --exact --show-output --nocapture
+#[test]
+fn test_force_drain_pending_emission() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 100, 0);
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let other_hotkey = U256::from(3);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        register_ok_neuron(netuid, other_hotkey, U256::from(4), 100_000);
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey,
+            &coldkey,
+            netuid,
+            1_000_000_000,
+        );
+        let incentive: u64 = 1_000_000;
+        let dividend: u64 = 2_000_000;
+        PendingSmoothedEmission::<Test>::insert(
+            netuid,
+            SmoothedEmission {
+                blocks_remaining: 10,
+                pending_tao: 0,
+                owner_cut: 0,
+                incentives: vec![(hotkey, incentive)],
+                dividends: vec![(hotkey, dividend), (other_hotkey, dividend)],
+            },
+        );
+        let stake_before = SubtensorModule::get_stake_for_hotkey_on_subnet(&hotkey, netuid);
+
+        // Only root can drain.
+        assert_noop!(
+            SubtensorModule::force_drain_pending_emission(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                netuid
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(SubtensorModule::force_drain_pending_emission(
+            RuntimeOrigin::root(),
+            hotkey,
+            netuid
+        ));
+        System::assert_last_event(RuntimeEvent::SubtensorModule(
+            Event::PendingEmissionDrained(hotkey, netuid),
+        ));
+
+        // The hotkey's pending emission was paid out in full and nothing is left for it.
+        assert_abs_diff_eq!(
+            SubtensorModule::get_stake_for_hotkey_on_subnet(&hotkey, netuid),
+            stake_before + incentive + dividend,
+            epsilon = 2
+        );
+        let smoothed = PendingSmoothedEmission::<Test>::get(netuid).unwrap();
+        assert!(smoothed.incentives.is_empty());
+        assert_eq!(smoothed.dividends, vec![(other_hotkey, dividend)]);
+        assert_eq!(smoothed.blocks_remaining, 10);
+        assert_noop!(
+            SubtensorModule::force_drain_pending_emission(RuntimeOrigin::root(), hotkey, netuid),
+            Error::<Test>::NoPendingEmission
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_preview_nominator_emission --exact --show-output --nocapture
+#[test]
+fn test_preview_nominator_emission() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let netuid: u16 = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        let hotkey = U256::from(1);
+        let owner = U256::from(2);
+        let adder = U256::from(3);
+        let remover = U256::from(4);
+        let amount = DefaultMinStake::<Test>::get() * 10;
+        register_ok_neuron(netuid, hotkey, owner, 0);
+        Delegates::<Test>::insert(hotkey, u16::MAX / 10);
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey,
+            &owner,
+            netuid,
+            1_000_000_000,
+        );
+        for coldkey in [adder, remover] {
+            SubtensorModule::add_balance_to_coldkey_account(
+                &coldkey,
+                amount + ExistentialDeposit::get(),
+            );
+        }
+
+        // The remover's stake was in place at the last drain and it has unstaked some since.
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(remover),
+            hotkey,
+            netuid,
+            amount
+        ));
+        SubtensorModule::drain_pending_emission(netuid, 0, 0, 0, 0);
+        let remover_alpha =
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &remover, netuid);
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(remover),
+            hotkey,
+            netuid,
+            remover_alpha / 3
+        ));
+
+        // The adder staked after the last drain.
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(adder),
+            hotkey,
+            netuid,
+            amount
+        ));
+
+        PendingSmoothedEmission::<Test>::insert(
+            netuid,
+            SmoothedEmission {
+                blocks_remaining: 10,
+                pending_tao: 0,
+                owner_cut: 0,
+                incentives: vec![(hotkey, 1_000_000)],
+                dividends: vec![(hotkey, 2_000_000)],
+            },
+        );
+        let coldkeys = [owner, adder, remover];
+        let previews: Vec<u64> = coldkeys
+            .iter()
+            .map(|coldkey| SubtensorModule::preview_nominator_emission(&hotkey, coldkey, netuid))
+            .collect();
+        let stakes_before: Vec<u64> = coldkeys
+            .iter()
+            .map(|coldkey| {
+                SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                    &hotkey, coldkey, netuid,
+                )
+            })
+            .collect();
+        assert!(previews.iter().all(|preview| *preview > 0));
+        // The owner is paid the incentive and the take on top of its share of the dividends.
+        assert!(previews[0] > 1_000_000);
+        // Nobody else has pending emission on the hotkey.
+        assert_eq!(
+            SubtensorModule::preview_nominator_emission(&U256::from(5), &adder, netuid),
+            0
+        );
+
+        // The preview matches what the drain pays out.
+        assert_ok!(SubtensorModule::force_drain_pending_emission(
+            RuntimeOrigin::root(),
+            hotkey,
+            netuid
+        ));
+        for ((coldkey, preview), stake_before) in coldkeys.iter().zip(previews).zip(stakes_before) {
+            assert_abs_diff_eq!(
+                SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                    &hotkey, coldkey, netuid
+                ),
+                stake_before + preview,
+                epsilon = 2
+            );
+        }
+        assert_eq!(
+            SubtensorModule::preview_nominator_emission(&hotkey, &adder, netuid),
+            0
+        );
+    });
+}
@@ -1,5 +1,6 @@
 #![allow(clippy::unwrap_used)]
 
+use approx::assert_abs_diff_eq;
 use sp_core::U256;
 
 use super::mock::*;
@@ -170,3 +171,107 @@ fn test_registration_difficulty_adjustment() {
         assert_eq!(SubtensorModule::get_difficulty_as_u64(netuid), 5833); // Difficulty unchanged
     });
 }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::difficulty::test_difficulty_algo_adjustment_curves --exact --show-output --nocapture
+#[test]
+fn test_difficulty_algo_adjustment_curves() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+        let current_difficulty: u64 = 1_000_000;
+        let target: u16 = 4;
+        SubtensorModule::set_adjustment_alpha(netuid, 0);
+        SubtensorModule::set_min_difficulty(netuid, 1);
+        SubtensorModule::set_max_difficulty(netuid, u64::MAX);
+
+        // Linear is the default.
+        assert_eq!(
+            SubtensorModule::get_difficulty_algo(netuid),
+            crate::DIFFICULTY_ALGO_LINEAR
+        );
+        let linear: Vec<u64> = [0, 2, 4, 8, 12]
+            .iter()
+            .map(|registrations| {
+                SubtensorModule::upgraded_difficulty(
+                    netuid,
+                    current_difficulty,
+                    *registrations,
+                    target,
+                )
+            })
+            .collect();
+        assert_eq!(
+            linear,
+            vec![500_000, 750_000, 1_000_000, 1_500_000, 2_000_000]
+        );
+
+        SubtensorModule::set_difficulty_algo(netuid, crate::DIFFICULTY_ALGO_EXPONENTIAL);
+        let exponential: Vec<u64> = [0, 2, 4, 8, 12]
+            .iter()
+            .map(|registrations| {
+                SubtensorModule::upgraded_difficulty(
+                    netuid,
+                    current_difficulty,
+                    *registrations,
+                    target,
+                )
+            })
+            .collect();
+        // exp(-1), exp(-0.5), exp(0), exp(1), exp(2)
+        for (actual, expected) in exponential
+            .iter()
+            .zip([367_879, 606_531, 1_000_000, 2_718_282, 7_389_056])
+        {
+            assert_abs_diff_eq!(*actual, expected, epsilon = 100);
+        }
+
+        // Both agree on target, the exponential curve responds harder on both sides.
+        for (i, (exp, lin)) in exponential.iter().zip(linear.iter()).enumerate() {
+            match i {
+                0 | 1 => assert!(exp < lin),
+                2 => assert_eq!(exp, lin),
+                _ => assert!(exp > lin),
+            }
+        }
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::difficulty::test_get_difficulty_info_after_registration_burst --exact --show-output --nocapture
+#[test]
+fn test_get_difficulty_info_after_registration_burst() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+        SubtensorModule::set_min_difficulty(netuid, 10_000);
+        SubtensorModule::set_difficulty(netuid, 20_000);
+        SubtensorModule::set_adjustment_alpha(netuid, 0);
+        SubtensorModule::set_adjustment_interval(netuid, 1);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 1);
+        SubtensorModule::set_max_registrations_per_block(netuid, 3);
+        SubtensorModule::set_max_allowed_uids(netuid, 3);
+
+        // Nothing has been adjusted yet.
+        assert_eq!(SubtensorModule::get_difficulty_info(netuid), (20_000, 0, 0));
+
+        // A burst of 3 registrations against a target of 1.
+        register_ok_neuron(netuid, U256::from(0), U256::from(0), 39420842);
+        register_ok_neuron(netuid, U256::from(100), U256::from(1000), 12412392);
+        register_ok_neuron(netuid, U256::from(2000), U256::from(20000), 21813123);
+        step_block(1);
+
+        // 20_000 * ( 3 + 1 ) / ( 1 + 1 ) = 40_000
+        let adjusted_at = SubtensorModule::get_current_block_as_u64();
+        assert_eq!(
+            SubtensorModule::get_difficulty_info(netuid),
+            (40_000, adjusted_at, 20_000)
+        );
+
+        // No registrations in the next interval brings it back down.
+        step_block(1);
+        // 40_000 * ( 0 + 1 ) / ( 1 + 1 ) = 20_000
+        assert_eq!(
+            SubtensorModule::get_difficulty_info(netuid),
+            (20_000, adjusted_at + 1, -20_000)
+        );
+    });
+}
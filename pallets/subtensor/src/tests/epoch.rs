@@ -7,6 +7,7 @@
 use super::mock::*;
 use crate::epoch::math::safe_exp;
 use crate::*;
+use approx::assert_abs_diff_eq;
 
 use frame_support::{assert_err, assert_ok};
 
@@ -2973,3 +2974,164 @@ fn assert_approx_eq_vec_of_vec(
         }
     }
 }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::epoch::test_max_weight_age_excludes_stale_weights --exact --show-output --nocapture
+#[test]
+fn test_max_weight_age_excludes_stale_weights() {
+    for sparse in [true, false] {
+        new_test_ext(1).execute_with(|| {
+            let n: u16 = 4;
+            let netuid: u16 = 1;
+            let tempo: u16 = u16::MAX - 1; // high tempo to skip automatic epochs in on_initialize, use manual epochs instead
+            let stake: u64 = 1;
+            let max_weight_age: u64 = 10;
+            add_network(netuid, tempo, 0);
+            SubtensorModule::set_max_allowed_uids(netuid, n);
+            SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+            SubtensorModule::set_max_registrations_per_block(netuid, n);
+            SubtensorModule::set_target_registrations_per_interval(netuid, n);
+            SubtensorModule::set_min_allowed_weights(netuid, 0);
+            SubtensorModule::set_max_weight_limit(netuid, u16::MAX);
+
+            // === Register [validator1, validator2, server1, server2], only validators hold stake
+            for key in 0..n as u64 {
+                register_ok_neuron(netuid, U256::from(key), U256::from(key), key * 1_000_000);
+            }
+            for key in 0..(n / 2) as u64 {
+                SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                    &U256::from(key),
+                    &U256::from(key),
+                    netuid,
+                    stake,
+                );
+            }
+            SubtensorModule::set_max_allowed_validators(netuid, n);
+            SubtensorModule::epoch(netuid, 1_000_000_000); // run first epoch to set allowed validators
+            next_block(); // run to next block to ensure weights are set on nodes after their registration block
+
+            // === Set weights [val1->(srv1, srv2), val2->(srv1, srv2)]
+            for validator in 0..(n / 2) as u64 {
+                assert_ok!(SubtensorModule::set_weights(
+                    RuntimeOrigin::signed(U256::from(validator)),
+                    netuid,
+                    ((n / 2)..n).collect(),
+                    vec![u16::MAX, u16::MAX],
+                    0
+                ));
+            }
+            SubtensorModule::set_max_weight_age(netuid, max_weight_age);
+            let run_epoch = || {
+                if sparse {
+                    SubtensorModule::epoch(netuid, 1_000_000_000);
+                } else {
+                    SubtensorModule::epoch_dense(netuid, 1_000_000_000);
+                }
+            };
+
+            // Both sets of weights are fresh, so both validators are trusted.
+            run_epoch();
+            assert!(SubtensorModule::get_validator_trust_for_uid(netuid, 0) > 0);
+            assert!(SubtensorModule::get_validator_trust_for_uid(netuid, 1) > 0);
+            let fresh_consensus = SubtensorModule::get_consensus(netuid);
+
+            // Once the max age passes, only validator1 refreshes its weights. Validator2 is
+            // still within the activity cutoff, but its stale weights no longer count.
+            System::set_block_number(System::block_number() + max_weight_age + 1);
+            assert_ok!(SubtensorModule::set_weights(
+                RuntimeOrigin::signed(U256::from(0)),
+                netuid,
+                ((n / 2)..n).collect(),
+                vec![u16::MAX, u16::MAX],
+                0
+            ));
+            run_epoch();
+            assert!(SubtensorModule::get_active_for_uid(netuid, 1));
+            assert_eq!(SubtensorModule::get_validator_trust_for_uid(netuid, 1), 0);
+
+            // Its stake is left out of consensus rather than voting for nothing, so consensus is
+            // still what the fresh weights agree on.
+            assert_eq!(SubtensorModule::get_consensus(netuid), fresh_consensus);
+
+            // Dissolving the subnet drops the setting.
+            SubtensorModule::remove_network(netuid);
+            assert!(!MaxWeightAge::<Test>::contains_key(netuid));
+        });
+    }
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::epoch::test_validator_emission_ratio_splits_emission --exact --show-output --nocapture
+#[test]
+fn test_validator_emission_ratio_splits_emission() {
+    for sparse in [true, false] {
+        new_test_ext(1).execute_with(|| {
+            let n: u16 = 4;
+            let netuid: u16 = 1;
+            let tempo: u16 = u16::MAX - 1; // high tempo to skip automatic epochs in on_initialize, use manual epochs instead
+            let rao_emission: u64 = 1_000_000_000;
+            add_network(netuid, tempo, 0);
+            SubtensorModule::set_max_allowed_uids(netuid, n);
+            SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+            SubtensorModule::set_max_registrations_per_block(netuid, n);
+            SubtensorModule::set_target_registrations_per_interval(netuid, n);
+            SubtensorModule::set_min_allowed_weights(netuid, 0);
+            SubtensorModule::set_max_weight_limit(netuid, u16::MAX);
+
+            // === Register [validator1, validator2, server1, server2], only validators hold stake
+            for key in 0..n as u64 {
+                register_ok_neuron(netuid, U256::from(key), U256::from(key), key * 1_000_000);
+            }
+            for key in 0..(n / 2) as u64 {
+                SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                    &U256::from(key),
+                    &U256::from(key),
+                    netuid,
+                    1,
+                );
+            }
+            SubtensorModule::set_max_allowed_validators(netuid, n);
+            SubtensorModule::epoch(netuid, rao_emission); // run first epoch to set allowed validators
+            next_block(); // run to next block to ensure weights are set on nodes after their registration block
+
+            // === Set weights [val1->(srv1, srv2), val2->(srv1, srv2)]
+            for validator in 0..(n / 2) as u64 {
+                assert_ok!(SubtensorModule::set_weights(
+                    RuntimeOrigin::signed(U256::from(validator)),
+                    netuid,
+                    ((n / 2)..n).collect(),
+                    vec![u16::MAX, u16::MAX],
+                    0
+                ));
+            }
+            // Returns the total (incentive, dividends) paid out by an epoch.
+            let run_epoch = || {
+                let emission = if sparse {
+                    SubtensorModule::epoch(netuid, rao_emission)
+                } else {
+                    SubtensorModule::epoch_dense(netuid, rao_emission)
+                };
+                emission
+                    .iter()
+                    .fold((0, 0), |(incentive, dividends), (_, i, d)| {
+                        (incentive + i, dividends + d)
+                    })
+            };
+
+            // Without a ratio, emission is split between miners and validators.
+            let (incentive, dividends) = run_epoch();
+            assert!(incentive > 0);
+            assert!(dividends > 0);
+
+            // A 0% validator ratio sends all emission to incentive.
+            SubtensorModule::set_validator_emission_ratio(netuid, 0);
+            let (incentive, dividends) = run_epoch();
+            assert_eq!(dividends, 0);
+            assert_abs_diff_eq!(incentive, rao_emission, epsilon = 10);
+
+            // A 100% validator ratio sends all emission to dividends.
+            SubtensorModule::set_validator_emission_ratio(netuid, u16::MAX);
+            let (incentive, dividends) = run_epoch();
+            assert_eq!(incentive, 0);
+            assert_abs_diff_eq!(dividends, rao_emission, epsilon = 10);
+        });
+    }
+}
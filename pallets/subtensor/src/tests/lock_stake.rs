@@ -0,0 +1,457 @@
+use super::mock::*;
+use crate::*;
+use approx::assert_abs_diff_eq;
+use frame_support::weights::Weight;
+use frame_support::{assert_noop, assert_ok};
+use sp_core::{Get, U256};
+
+// Sets up a subnet with `coldkey` staking on `hotkey` and returns the netuid and the alpha staked.
+fn setup_stake(coldkey: U256, hotkey: U256) -> (u16, u64) {
+    let subnet_owner_coldkey = U256::from(1001);
+    let subnet_owner_hotkey = U256::from(1002);
+    let netuid: u16 = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+    let stake_amount = DefaultMinStake::<Test>::get() * 10;
+    let fee = DefaultStakingFee::<Test>::get();
+
+    SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+    SubtensorModule::stake_into_subnet(&hotkey, &coldkey, netuid, stake_amount, fee);
+    let alpha =
+        SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
+
+    (netuid, alpha)
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::lock_stake::test_lock_stake_ok --exact --nocapture
+#[test]
+fn test_lock_stake_ok() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let (netuid, alpha) = setup_stake(coldkey, hotkey);
+        let alpha_locked = alpha / 2;
+        let until_block = SubtensorModule::get_current_block_as_u64() + 100;
+
+        assert_ok!(SubtensorModule::lock_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            alpha_locked,
+            until_block,
+        ));
+
+        assert_eq!(
+            SubtensorModule::get_stake_lock(&hotkey, &coldkey, netuid),
+            Some((alpha_locked, until_block))
+        );
+        assert_eq!(
+            SubtensorModule::get_locked_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey, &coldkey, netuid
+            ),
+            alpha_locked
+        );
+        assert_eq!(
+            SubtensorModule::get_unlocked_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey, &coldkey, netuid
+            ),
+            alpha - alpha_locked
+        );
+        System::assert_last_event(RuntimeEvent::SubtensorModule(Event::StakeLocked(
+            coldkey,
+            hotkey,
+            netuid,
+            alpha_locked,
+            until_block,
+        )));
+
+        // The lock is gone once the unlock block is reached.
+        System::set_block_number(until_block);
+        assert_eq!(
+            SubtensorModule::get_stake_lock(&hotkey, &coldkey, netuid),
+            None
+        );
+        assert_eq!(
+            SubtensorModule::get_unlocked_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey, &coldkey, netuid
+            ),
+            alpha
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::lock_stake::test_lock_stake_invalid_input --exact --nocapture
+#[test]
+fn test_lock_stake_invalid_input() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let (netuid, alpha) = setup_stake(coldkey, hotkey);
+        let current_block = SubtensorModule::get_current_block_as_u64();
+
+        assert_noop!(
+            SubtensorModule::lock_stake(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                netuid,
+                alpha,
+                current_block,
+            ),
+            Error::<Test>::InvalidStakeLockBlock
+        );
+        assert_noop!(
+            SubtensorModule::lock_stake(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                netuid,
+                alpha + 1,
+                current_block + 100,
+            ),
+            Error::<Test>::NotEnoughStakeToLock
+        );
+        assert_noop!(
+            SubtensorModule::lock_stake(
+                RuntimeOrigin::signed(coldkey),
+                U256::from(3),
+                netuid,
+                alpha,
+                current_block + 100,
+            ),
+            Error::<Test>::HotKeyAccountNotExists
+        );
+        assert_noop!(
+            SubtensorModule::lock_stake(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                netuid + 1,
+                alpha,
+                current_block + 100,
+            ),
+            Error::<Test>::SubnetNotExists
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::lock_stake::test_lock_stake_never_weakens_active_lock --exact --nocapture
+#[test]
+fn test_lock_stake_never_weakens_active_lock() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let (netuid, alpha) = setup_stake(coldkey, hotkey);
+        let current_block = SubtensorModule::get_current_block_as_u64();
+
+        assert_ok!(SubtensorModule::lock_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            alpha / 2,
+            current_block + 100,
+        ));
+
+        // A smaller, shorter lock keeps the existing one.
+        assert_ok!(SubtensorModule::lock_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            alpha / 4,
+            current_block + 50,
+        ));
+        assert_eq!(
+            SubtensorModule::get_stake_lock(&hotkey, &coldkey, netuid),
+            Some((alpha / 2, current_block + 100))
+        );
+
+        // A bigger, longer lock replaces it.
+        assert_ok!(SubtensorModule::lock_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            alpha,
+            current_block + 200,
+        ));
+        assert_eq!(
+            SubtensorModule::get_stake_lock(&hotkey, &coldkey, netuid),
+            Some((alpha, current_block + 200))
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::lock_stake::test_locked_stake_cannot_be_unstaked_until_unlock_block --exact --nocapture
+#[test]
+fn test_locked_stake_cannot_be_unstaked_until_unlock_block() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let (netuid, alpha) = setup_stake(coldkey, hotkey);
+        let alpha_locked = alpha / 2;
+        let alpha_free = alpha - alpha_locked;
+        let until_block = SubtensorModule::get_current_block_as_u64() + 100;
+
+        assert_ok!(SubtensorModule::lock_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            alpha_locked,
+            until_block,
+        ));
+
+        // The locked part can't be unstaked.
+        assert_noop!(
+            SubtensorModule::remove_stake(RuntimeOrigin::signed(coldkey), hotkey, netuid, alpha),
+            Error::<Test>::StakeIsLocked
+        );
+
+        // The free part can.
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            alpha_free
+        ));
+        let alpha_remaining =
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
+        assert_abs_diff_eq!(alpha_remaining, alpha_locked, epsilon = 1);
+
+        // Still locked right before the unlock block.
+        System::set_block_number(until_block - 1);
+        assert_noop!(
+            SubtensorModule::remove_stake(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                netuid,
+                alpha_remaining
+            ),
+            Error::<Test>::StakeIsLocked
+        );
+
+        // Unstakeable once the unlock block is reached.
+        System::set_block_number(until_block);
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            alpha_remaining
+        ));
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid),
+            0
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::lock_stake::test_locked_stake_cannot_be_moved --exact --nocapture
+#[test]
+fn test_locked_stake_cannot_be_moved() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let destination_hotkey = U256::from(3);
+        let (netuid, alpha) = setup_stake(coldkey, hotkey);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &destination_hotkey);
+
+        assert_ok!(SubtensorModule::lock_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            alpha,
+            SubtensorModule::get_current_block_as_u64() + 100,
+        ));
+
+        assert_noop!(
+            SubtensorModule::do_move_stake(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                destination_hotkey,
+                netuid,
+                netuid,
+                alpha,
+            ),
+            Error::<Test>::StakeIsLocked
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::lock_stake::test_unstake_all_keeps_locked_stake --exact --nocapture
+#[test]
+fn test_unstake_all_keeps_locked_stake() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let (netuid, alpha) = setup_stake(coldkey, hotkey);
+        let alpha_locked = alpha / 2;
+
+        assert_ok!(SubtensorModule::lock_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            alpha_locked,
+            SubtensorModule::get_current_block_as_u64() + 100,
+        ));
+
+        assert_ok!(SubtensorModule::unstake_all(
+            RuntimeOrigin::signed(coldkey),
+            hotkey
+        ));
+        assert_abs_diff_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid),
+            alpha_locked,
+            epsilon = 1
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::lock_stake::test_swap_hotkey_keeps_stake_lock --exact --nocapture
+#[test]
+fn test_swap_hotkey_keeps_stake_lock() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let new_hotkey = U256::from(3);
+        let (netuid, alpha) = setup_stake(coldkey, hotkey);
+        let until_block = SubtensorModule::get_current_block_as_u64() + 100;
+
+        assert_ok!(SubtensorModule::lock_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            alpha,
+            until_block,
+        ));
+
+        let mut weight = Weight::zero();
+        assert_ok!(SubtensorModule::perform_hotkey_swap(
+            &hotkey,
+            &new_hotkey,
+            &coldkey,
+            &mut weight
+        ));
+
+        assert_eq!(
+            SubtensorModule::get_stake_lock(&hotkey, &coldkey, netuid),
+            None
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_lock(&new_hotkey, &coldkey, netuid),
+            Some((alpha, until_block))
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::lock_stake::test_swap_hotkey_merges_stake_locks_like_lock_stake --exact --nocapture
+#[test]
+fn test_swap_hotkey_merges_stake_locks_like_lock_stake() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let new_hotkey = U256::from(3);
+        let (netuid, alpha) = setup_stake(coldkey, hotkey);
+        let current_block = SubtensorModule::get_current_block_as_u64();
+
+        assert_ok!(SubtensorModule::lock_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            alpha / 2,
+            current_block + 50,
+        ));
+        LockedStake::<Test>::insert(
+            (new_hotkey, coldkey, netuid),
+            (alpha / 4, current_block + 100),
+        );
+
+        let mut weight = Weight::zero();
+        assert_ok!(SubtensorModule::perform_hotkey_swap(
+            &hotkey,
+            &new_hotkey,
+            &coldkey,
+            &mut weight
+        ));
+
+        // The larger amount and the later unlock block are kept, the amounts aren't added up.
+        assert_eq!(
+            SubtensorModule::get_stake_lock(&new_hotkey, &coldkey, netuid),
+            Some((alpha / 2, current_block + 100))
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::lock_stake::test_stake_lock_is_pruned --exact --nocapture
+#[test]
+fn test_stake_lock_is_pruned() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let (netuid, alpha) = setup_stake(coldkey, hotkey);
+        let until_block = SubtensorModule::get_current_block_as_u64() + 100;
+
+        assert_ok!(SubtensorModule::lock_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            alpha / 2,
+            until_block,
+        ));
+
+        // Unstaking after the unlock block drops the expired lock.
+        System::set_block_number(until_block);
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            alpha / 4
+        ));
+        assert!(!LockedStake::<Test>::contains_key((
+            hotkey, coldkey, netuid
+        )));
+
+        // Dissolving the subnet drops the locks on its neurons.
+        SubtensorModule::append_neuron(netuid, &hotkey, until_block);
+        assert_ok!(SubtensorModule::lock_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            alpha / 4,
+            until_block + 100,
+        ));
+        SubtensorModule::remove_network(netuid);
+        assert!(!LockedStake::<Test>::contains_key((
+            hotkey, coldkey, netuid
+        )));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::lock_stake::test_get_stake_breakdown --exact --nocapture
+#[test]
+fn test_get_stake_breakdown() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let (netuid, alpha) = setup_stake(coldkey, hotkey);
+        let alpha_locked = alpha / 3;
+        let until_block = SubtensorModule::get_current_block_as_u64() + 100;
+
+        // Nothing is locked yet.
+        assert_eq!(
+            SubtensorModule::get_stake_breakdown(coldkey, hotkey, netuid),
+            (alpha, 0)
+        );
+
+        assert_ok!(SubtensorModule::lock_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            alpha_locked,
+            until_block,
+        ));
+        assert_eq!(
+            SubtensorModule::get_stake_breakdown(coldkey, hotkey, netuid),
+            (alpha - alpha_locked, alpha_locked)
+        );
+
+        // Everything is free again after the unlock block.
+        System::set_block_number(until_block);
+        assert_eq!(
+            SubtensorModule::get_stake_breakdown(coldkey, hotkey, netuid),
+            (alpha, 0)
+        );
+    });
+}
@@ -416,3 +416,239 @@ fn test_migrate_subnet_volume() {
         assert_eq!(new_value, Some(old_value as u128));
     });
 }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::migration::test_migrate_truncate_oversized_serving --exact --show-output
+#[test]
+fn test_migrate_truncate_oversized_serving() {
+    new_test_ext(1).execute_with(|| {
+        use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+        let netuid: u16 = 1;
+        let valid_hotkey = U256::from(1);
+        let oversized_hotkey = U256::from(2);
+        add_network(netuid, 1, 0);
+        StorageVersion::new(7).put::<Pallet<Test>>();
+
+        // A certificate within the bound.
+        let valid_certificate = NeuronCertificateOf::try_from(vec![1u8; 65]).unwrap();
+        NeuronCertificates::<Test>::insert(netuid, valid_hotkey, valid_certificate.clone());
+
+        // An oversized certificate written in the unbounded (public_key, algorithm) layout.
+        let oversized_key = NeuronCertificates::<Test>::hashed_key_for(netuid, oversized_hotkey);
+        put(&oversized_key, &(vec![7u8; 100], 1u8));
+        assert!(NeuronCertificates::<Test>::contains_key(
+            netuid,
+            oversized_hotkey
+        ));
+        assert!(NeuronCertificates::<Test>::try_get(netuid, oversized_hotkey).is_err());
+
+        // Run migration
+        let weight = crate::migrations::migrate_truncate_oversized_serving::migrate_truncate_oversized_serving::<Test>();
+        assert!(!weight.is_zero());
+
+        // The oversized certificate is gone, the valid one is untouched.
+        assert!(!NeuronCertificates::<Test>::contains_key(
+            netuid,
+            oversized_hotkey
+        ));
+        assert_eq!(
+            NeuronCertificates::<Test>::get(netuid, valid_hotkey),
+            Some(valid_certificate)
+        );
+        assert_eq!(
+            Pallet::<Test>::on_chain_storage_version(),
+            StorageVersion::new(8)
+        );
+
+        // Ensure the migration doesn't run again once the version is bumped.
+        put(&oversized_key, &(vec![7u8; 100], 1u8));
+        crate::migrations::migrate_truncate_oversized_serving::migrate_truncate_oversized_serving::<Test>();
+        assert!(NeuronCertificates::<Test>::contains_key(
+            netuid,
+            oversized_hotkey
+        ));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::migration::test_migrate_backfill_block_at_registration --exact --show-output
+#[test]
+fn test_migrate_backfill_block_at_registration() {
+    new_test_ext(1).execute_with(|| {
+        use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, U256::from(1), U256::from(2), 0);
+        register_ok_neuron(netuid, U256::from(3), U256::from(4), 0);
+        let registered_block = BlockAtRegistration::<Test>::get(netuid, 0);
+
+        // Seed a neuron appended without its registration block.
+        BlockAtRegistration::<Test>::remove(netuid, 1);
+        assert!(!BlockAtRegistration::<Test>::contains_key(netuid, 1));
+
+        run_to_block(10);
+        StorageVersion::new(8).put::<Pallet<Test>>();
+
+        // Run migration
+        let weight = crate::migrations::migrate_backfill_block_at_registration::migrate_backfill_block_at_registration::<Test>();
+        assert!(!weight.is_zero());
+
+        // The missing entry is set to the current block, the existing one is untouched.
+        assert_eq!(BlockAtRegistration::<Test>::get(netuid, 1), 10);
+        assert_eq!(
+            BlockAtRegistration::<Test>::get(netuid, 0),
+            registered_block
+        );
+        assert_eq!(
+            Pallet::<Test>::on_chain_storage_version(),
+            StorageVersion::new(9)
+        );
+
+        // Ensure the migration doesn't run again once the version is bumped.
+        BlockAtRegistration::<Test>::remove(netuid, 1);
+        crate::migrations::migrate_backfill_block_at_registration::migrate_backfill_block_at_registration::<Test>();
+        assert!(!BlockAtRegistration::<Test>::contains_key(netuid, 1));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::migration::test_migrate_remove_stranded_network_members --exact --show-output
+#[test]
+fn test_migrate_remove_stranded_network_members() {
+    new_test_ext(1).execute_with(|| {
+        use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let stranded_hotkey = U256::from(2);
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(3), 0);
+
+        // Seed memberships with no uid behind them.
+        IsNetworkMember::<Test>::insert(stranded_hotkey, netuid, true);
+        IsNetworkMember::<Test>::insert(hotkey, netuid + 1, true);
+        assert!(SubtensorModule::is_hotkey_registered_on_any_network(
+            &stranded_hotkey
+        ));
+        StorageVersion::new(9).put::<Pallet<Test>>();
+
+        // Run migration
+        let weight = crate::migrations::migrate_remove_stranded_network_members::migrate_remove_stranded_network_members::<Test>();
+        assert!(!weight.is_zero());
+
+        assert!(!IsNetworkMember::<Test>::contains_key(
+            stranded_hotkey,
+            netuid
+        ));
+        assert!(!IsNetworkMember::<Test>::contains_key(hotkey, netuid + 1));
+        assert!(!SubtensorModule::is_hotkey_registered_on_any_network(
+            &stranded_hotkey
+        ));
+        // The backed membership is kept.
+        assert!(IsNetworkMember::<Test>::get(hotkey, netuid));
+        assert_eq!(
+            SubtensorModule::get_registered_networks_for_hotkey(&hotkey),
+            vec![netuid]
+        );
+        assert_eq!(
+            Pallet::<Test>::on_chain_storage_version(),
+            StorageVersion::new(10)
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::migration::test_migrate_add_stake_providers --exact --show-output
+#[test]
+fn test_migrate_add_stake_providers() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let staked_coldkey = U256::from(2);
+        let unstaked_coldkey = U256::from(3);
+        add_network(netuid, 1, 0);
+
+        // Stake written before the provider existed, and a coldkey whose stake is gone.
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey,
+            &staked_coldkey,
+            netuid,
+            1_000_000,
+        );
+        StakingHotkeys::<Test>::insert(staked_coldkey, vec![hotkey]);
+        StakingHotkeys::<Test>::insert(unstaked_coldkey, vec![hotkey]);
+        let providers = frame_system::Pallet::<Test>::providers(&staked_coldkey);
+        assert!(!StakeProviders::<Test>::get(staked_coldkey));
+
+        // Run migration
+        let weight =
+            crate::migrations::migrate_add_stake_providers::migrate_add_stake_providers::<Test>();
+        assert!(!weight.is_zero());
+
+        assert!(StakeProviders::<Test>::get(staked_coldkey));
+        assert_eq!(
+            frame_system::Pallet::<Test>::providers(&staked_coldkey),
+            providers + 1
+        );
+        assert!(!StakeProviders::<Test>::get(unstaked_coldkey));
+        assert!(HasMigrationRun::<Test>::get(
+            b"migrate_add_stake_providers".to_vec()
+        ));
+
+        // Running it again doesn't take a second reference.
+        crate::migrations::migrate_add_stake_providers::migrate_add_stake_providers::<Test>();
+        assert_eq!(
+            frame_system::Pallet::<Test>::providers(&staked_coldkey),
+            providers + 1
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::migration::test_migrate_backfill_pruned_hotkeys --exact --show-output
+#[test]
+fn test_migrate_backfill_pruned_hotkeys() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let registered_hotkey = U256::from(1);
+        let deregistered_hotkey = U256::from(2);
+        let unstaked_hotkey = U256::from(3);
+        let coldkey = U256::from(4);
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, registered_hotkey, coldkey, 0);
+
+        // Stake left on a hotkey that lost its uid before recycling existed.
+        for hotkey in [registered_hotkey, deregistered_hotkey] {
+            SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey, &coldkey, netuid, 1_000_000,
+            );
+        }
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &deregistered_hotkey,
+            &coldkey,
+            0,
+            1_000_000,
+        );
+
+        // Run migration
+        let weight =
+            crate::migrations::migrate_backfill_pruned_hotkeys::migrate_backfill_pruned_hotkeys::<
+                Test,
+            >();
+        assert!(!weight.is_zero());
+
+        assert_eq!(
+            PrunedHotkeys::<Test>::get(netuid, deregistered_hotkey),
+            Some(None)
+        );
+        assert!(!PrunedHotkeys::<Test>::contains_key(0, deregistered_hotkey));
+        assert!(!PrunedHotkeys::<Test>::contains_key(
+            netuid,
+            registered_hotkey
+        ));
+        assert!(!PrunedHotkeys::<Test>::contains_key(
+            netuid,
+            unstaked_hotkey
+        ));
+        assert!(HasMigrationRun::<Test>::get(
+            b"migrate_backfill_pruned_hotkeys".to_vec()
+        ));
+    });
+}
@@ -134,6 +134,7 @@ parameter_types! {
     pub const InitialRho: u16 = 30;
     pub const InitialKappa: u16 = 32_767;
     pub const InitialTempo: u16 = 360;
+    pub const InitialMinTempo: u16 = 10;
     pub const SelfOwnership: u64 = 2;
     pub const InitialImmunityPeriod: u16 = 2;
     pub const InitialMaxAllowedUids: u16 = 2;
@@ -203,7 +204,7 @@ use pallet_collective::{CanPropose, CanVote, GetVotingMembers};
 pub struct CanProposeToTriumvirate;
 impl CanPropose<AccountId> for CanProposeToTriumvirate {
     fn can_propose(account: &AccountId) -> bool {
-        Triumvirate::is_member(account)
+        Triumvirate::is_member(account) && SubtensorModule::can_submit_proposal(account)
     }
 }
 
@@ -360,6 +361,7 @@ impl crate::Config for Test {
     type InitialEmissionValue = InitialEmissionValue;
     type InitialMaxWeightsLimit = InitialMaxWeightsLimit;
     type InitialTempo = InitialTempo;
+    type InitialMinTempo = InitialMinTempo;
     type InitialDifficulty = InitialDifficulty;
     type InitialAdjustmentInterval = InitialAdjustmentInterval;
     type InitialAdjustmentAlpha = InitialAdjustmentAlpha;
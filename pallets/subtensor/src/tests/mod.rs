@@ -5,6 +5,7 @@ mod delegate_info;
 mod difficulty;
 mod emission;
 mod epoch;
+mod lock_stake;
 mod math;
 mod migration;
 mod mock;
@@ -1,6 +1,6 @@
 use super::mock::*;
 use crate::*;
-use frame_support::assert_ok;
+use frame_support::{assert_noop, assert_ok};
 use frame_system::Config;
 use sp_core::U256;
 
@@ -41,6 +41,33 @@ fn test_registration_ok() {
     })
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::networks::test_dissolve_owned_network --exact --show-output --nocapture
+#[test]
+fn test_dissolve_owned_network() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 2;
+        let owner_coldkey = U256::from(1);
+        let other_coldkey = U256::from(2);
+        add_network(netuid, 13, 0);
+        SubnetOwner::<Test>::insert(netuid, owner_coldkey);
+
+        assert_noop!(
+            SubtensorModule::dissolve_owned_network(
+                <<Test as Config>::RuntimeOrigin>::signed(other_coldkey),
+                netuid
+            ),
+            Error::<Test>::NotSubnetOwner
+        );
+        assert!(SubtensorModule::if_subnet_exist(netuid));
+
+        assert_ok!(SubtensorModule::dissolve_owned_network(
+            <<Test as Config>::RuntimeOrigin>::signed(owner_coldkey),
+            netuid
+        ));
+        assert!(!SubtensorModule::if_subnet_exist(netuid));
+    })
+}
+
 // #[test]
 // fn test_schedule_dissolve_network_execution() {
 //     new_test_ext(1).execute_with(|| {
@@ -342,3 +369,219 @@ fn test_tempo_greater_than_weight_set_rate_limit() {
         assert!(tempo as u64 >= weights_set_rate_limit);
     })
 }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::networks::test_network_lock_cost_never_decays_below_floor --exact --show-output --nocapture
+#[test]
+fn test_network_lock_cost_never_decays_below_floor() {
+    new_test_ext(1).execute_with(|| {
+        let floor: u64 = 500_000_000_000;
+        SubtensorModule::set_network_min_lock(floor);
+
+        // Register once so the lock cost doubles and then starts decaying.
+        let subnet_owner_coldkey = U256::from(1);
+        let subnet_owner_hotkey = U256::from(2);
+        add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        assert!(SubtensorModule::get_network_lock_cost() > floor);
+
+        // Decay for many lock reduction intervals.
+        let start_block = System::block_number();
+        for step in 1..=50 {
+            System::set_block_number(start_block + step * 10);
+            assert!(SubtensorModule::get_network_lock_cost() >= floor);
+        }
+        assert_eq!(SubtensorModule::get_network_lock_cost(), floor);
+
+        // Registering at the floor locks exactly the floor.
+        let netuid: u16 = add_dynamic_network(&U256::from(4), &U256::from(3));
+        assert_eq!(SubnetTAO::<Test>::get(netuid), floor);
+    })
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::networks::test_raising_network_min_lock_updates_quote --exact --show-output --nocapture
+#[test]
+fn test_raising_network_min_lock_updates_quote() {
+    new_test_ext(1).execute_with(|| {
+        let quote_before = SubtensorModule::get_network_lock_cost();
+
+        // Raising the min lock above the current quote takes effect immediately.
+        let min_lock: u64 = quote_before + 1_000_000_000;
+        SubtensorModule::set_network_min_lock(min_lock);
+        System::assert_last_event(RuntimeEvent::SubtensorModule(Event::NetworkMinLockCostSet(
+            min_lock,
+        )));
+        assert_eq!(SubtensorModule::get_network_lock_cost(), min_lock);
+
+        let netuid: u16 = add_dynamic_network(&U256::from(2), &U256::from(1));
+        assert_eq!(SubtensorModule::get_network_last_lock(), min_lock);
+        assert_eq!(SubnetTAO::<Test>::get(netuid), min_lock);
+    })
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::networks::test_get_next_netuid_predicts_registration --exact --show-output --nocapture
+#[test]
+fn test_get_next_netuid_predicts_registration() {
+    new_test_ext(1).execute_with(|| {
+        let owner_coldkey = U256::from(1);
+        let register = |hotkey: U256| {
+            SubtensorModule::add_balance_to_coldkey_account(
+                &owner_coldkey,
+                SubtensorModule::get_network_lock_cost(),
+            );
+            assert_ok!(SubtensorModule::register_network(
+                RuntimeOrigin::signed(owner_coldkey),
+                hotkey
+            ));
+        };
+
+        // The predicted netuid is the one the registration gets.
+        let first = SubtensorModule::get_next_netuid();
+        assert!(!SubtensorModule::if_subnet_exist(first));
+        register(U256::from(2));
+        assert!(SubtensorModule::if_subnet_exist(first));
+        assert_eq!(SubnetOwnerHotkey::<Test>::get(first), U256::from(2));
+
+        let second = SubtensorModule::get_next_netuid();
+        assert_ne!(second, first);
+        register(U256::from(3));
+        assert_eq!(SubnetOwnerHotkey::<Test>::get(second), U256::from(3));
+
+        // A dissolved subnet's netuid is reused by the next registration.
+        assert_ok!(SubtensorModule::user_remove_network(owner_coldkey, first));
+        assert_eq!(SubtensorModule::get_next_netuid(), first);
+        register(U256::from(4));
+        assert!(SubtensorModule::if_subnet_exist(first));
+        assert_eq!(SubnetOwnerHotkey::<Test>::get(first), U256::from(4));
+    })
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::networks::test_register_network_sets_epoch_offset --exact --show-output --nocapture
+#[test]
+fn test_register_network_sets_epoch_offset() {
+    new_test_ext(1).execute_with(|| {
+        let owner_coldkey = U256::from(1);
+        let register = |hotkey: U256| {
+            SubtensorModule::add_balance_to_coldkey_account(
+                &owner_coldkey,
+                SubtensorModule::get_network_lock_cost(),
+            );
+            assert_ok!(SubtensorModule::register_network(
+                RuntimeOrigin::signed(owner_coldkey),
+                hotkey
+            ));
+        };
+        let tempo = DefaultTempo::<Test>::get();
+
+        // Subnets whose epochs don't collide keep the netuid-based schedule.
+        let first = SubtensorModule::get_next_netuid();
+        register(U256::from(2));
+        assert_eq!(SubtensorModule::get_epoch_offset(first), 0);
+
+        // Shift the first subnet onto the blocks the next one would use, so the next one has to
+        // take the following free offset.
+        let second = SubtensorModule::get_next_netuid();
+        SubtensorModule::set_epoch_offset(first, (second - first) as u64);
+        assert_eq!(SubtensorModule::get_free_epoch_offset(second, tempo), 1);
+        register(U256::from(3));
+        assert_eq!(SubtensorModule::get_epoch_offset(second), 1);
+        let epoch_blocks = |netuid: u16| -> Vec<u64> {
+            (0..1_000)
+                .filter(|block| SubtensorModule::should_run_epoch(netuid, *block))
+                .collect()
+        };
+        assert!(
+            epoch_blocks(first)
+                .iter()
+                .all(|block| !epoch_blocks(second).contains(block))
+        );
+
+        // Dissolving the subnet clears its offset.
+        assert_ok!(SubtensorModule::user_remove_network(owner_coldkey, first));
+        assert!(!EpochOffset::<Test>::contains_key(first));
+    })
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::networks::test_register_network_weight_covers_epoch_offset_scan --exact --show-output --nocapture
+#[test]
+fn test_register_network_weight_covers_epoch_offset_scan() {
+    new_test_ext(1).execute_with(|| {
+        let call = RuntimeCall::SubtensorModule(SubtensorCall::register_network {
+            hotkey: U256::from(1),
+        });
+        let weight_before =
+            frame_support::dispatch::GetDispatchInfo::get_dispatch_info(&call).weight;
+
+        // Picking the epoch offset reads every subnet, so each one adds to the weight.
+        add_network(1, 10, 0);
+        add_network(2, 10, 0);
+        let weight_after =
+            frame_support::dispatch::GetDispatchInfo::get_dispatch_info(&call).weight;
+        assert_eq!(
+            weight_after.saturating_sub(weight_before),
+            <Test as Config>::DbWeight::get().reads(6)
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::networks::test_register_network_respects_min_tempo --exact --show-output --nocapture
+#[test]
+fn test_register_network_respects_min_tempo() {
+    new_test_ext(1).execute_with(|| {
+        let owner_coldkey = U256::from(1);
+        let min_tempo = DefaultTempo::<Test>::get() + 1;
+        SubtensorModule::set_min_tempo(min_tempo);
+
+        // A default tempo below the floor is raised to it.
+        let netuid = SubtensorModule::get_next_netuid();
+        SubtensorModule::add_balance_to_coldkey_account(
+            &owner_coldkey,
+            SubtensorModule::get_network_lock_cost(),
+        );
+        assert_ok!(SubtensorModule::register_network(
+            RuntimeOrigin::signed(owner_coldkey),
+            U256::from(2)
+        ));
+        assert_eq!(SubtensorModule::get_tempo(netuid), min_tempo);
+    })
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::networks::test_emit_subnet_state_hash --exact --show-output --nocapture
+#[test]
+fn test_emit_subnet_state_hash() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        add_network(netuid, 10, 0);
+        add_network(netuid + 1, 10, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        register_ok_neuron(netuid, U256::from(3), U256::from(4), 100_000);
+        assert_eq!(
+            SubtensorModule::emit_subnet_state_hash(RuntimeOrigin::signed(coldkey), 99),
+            Err(Error::<Test>::SubNetworkDoesNotExist.into())
+        );
+
+        // The same state yields the same hash, and the event carries it.
+        let state_hash = SubtensorModule::get_subnet_state_hash(netuid);
+        assert_eq!(SubtensorModule::get_subnet_state_hash(netuid), state_hash);
+        assert_ok!(SubtensorModule::emit_subnet_state_hash(
+            RuntimeOrigin::signed(coldkey),
+            netuid
+        ));
+        System::assert_last_event(RuntimeEvent::SubtensorModule(Event::SubnetStateHash(
+            netuid, state_hash,
+        )));
+        assert_ne!(
+            SubtensorModule::get_subnet_state_hash(netuid + 1),
+            state_hash
+        );
+
+        // Changes to stake or weights change the hash.
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey, &coldkey, netuid, 1_000,
+        );
+        let staked_hash = SubtensorModule::get_subnet_state_hash(netuid);
+        assert_ne!(staked_hash, state_hash);
+        Weights::<Test>::insert(netuid, 0, vec![(1, u16::MAX)]);
+        assert_ne!(SubtensorModule::get_subnet_state_hash(netuid), staked_hash);
+    });
+}
@@ -3,13 +3,16 @@
 use frame_support::traits::Currency;
 
 use super::mock::*;
-use crate::{AxonInfoOf, CustomTransactionError, Error, SubtensorSignedExtension};
+use crate::{
+    AxonInfoOf, BlockAtRegistration, CustomTransactionError, Error, PruneNewestOnTie,
+    SubtensorSignedExtension,
+};
 use frame_support::dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, Pays};
 use frame_support::sp_runtime::{DispatchError, transaction_validity::InvalidTransaction};
 use frame_support::{assert_err, assert_noop, assert_ok};
 use frame_system::Config;
 use sp_core::U256;
-use sp_runtime::traits::{DispatchInfoOf, SignedExtension};
+use sp_runtime::traits::{DispatchInfoOf, Dispatchable, SignedExtension};
 
 /********************************************
     subscribing::subscribe() tests
@@ -284,6 +287,62 @@ fn test_registration_rate_limit_exceeded() {
     });
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::registration::test_registration_global_block_limit_exceeded --exact --show-output --nocapture
+#[test]
+fn test_registration_global_block_limit_exceeded() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(667);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+        for netuid in 1..=3 {
+            add_network(netuid, 13, 0);
+            SubtensorModule::set_burn(netuid, 1000);
+            SubtensorModule::set_max_registrations_per_block(netuid, 16);
+            SubtensorModule::set_target_registrations_per_interval(netuid, 16);
+        }
+        SubtensorModule::set_max_total_registrations_per_block(2);
+
+        // Each subnet is well under its own limit, but the chain-wide cap is reached.
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            1,
+            U256::from(1)
+        ));
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            2,
+            U256::from(2)
+        ));
+        assert_eq!(SubtensorModule::get_total_registrations_this_block(), 2);
+        assert_noop!(
+            SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                3,
+                U256::from(3)
+            ),
+            Error::<Test>::TooManyTotalRegistrationsThisBlock
+        );
+        assert_noop!(
+            SubtensorModule::batch_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                3,
+                vec![U256::from(3)]
+            ),
+            Error::<Test>::TooManyTotalRegistrationsThisBlock
+        );
+        assert!(!SubtensorModule::checked_allowed_register(3));
+
+        // The counter is drained on the next block.
+        step_block(1);
+        assert_eq!(SubtensorModule::get_total_registrations_this_block(), 0);
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            3,
+            U256::from(3)
+        ));
+        assert_eq!(SubtensorModule::get_subnetwork_n(3), 1);
+    });
+}
+
 /********************************************
     registration::do_burned_registration tests
 *********************************************/
@@ -1302,6 +1361,81 @@ fn test_registration_get_uid_to_prune_none_in_immunity_period() {
     });
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::registration::test_get_neuron_to_prune_skips_immune_neurons --exact --show-output --nocapture
+#[test]
+fn test_get_neuron_to_prune_skips_immune_neurons() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+        SubtensorModule::set_immunity_period(netuid, 3);
+        register_ok_neuron(netuid, U256::from(0), U256::from(0), 39420842);
+        step_block(5);
+        register_ok_neuron(netuid, U256::from(1), U256::from(1), 12412392);
+
+        // uid 1 was just registered and scores worst, but it is still immune.
+        SubtensorModule::set_pruning_score_for_uid(netuid, 0, 100);
+        SubtensorModule::set_pruning_score_for_uid(netuid, 1, 10);
+        assert!(!SubtensorModule::get_neuron_is_immune(netuid, 0));
+        assert!(SubtensorModule::get_neuron_is_immune(netuid, 1));
+
+        assert_eq!(SubtensorModule::get_neuron_to_prune(netuid), 0);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::registration::test_get_neuron_to_prune_all_immune_falls_back_to_lowest_score --exact --show-output --nocapture
+#[test]
+fn test_get_neuron_to_prune_all_immune_falls_back_to_lowest_score() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+        SubtensorModule::set_immunity_period(netuid, 100);
+        register_ok_neuron(netuid, U256::from(0), U256::from(0), 39420842);
+        step_block(5);
+        register_ok_neuron(netuid, U256::from(1), U256::from(1), 12412392);
+
+        SubtensorModule::set_pruning_score_for_uid(netuid, 0, 100);
+        SubtensorModule::set_pruning_score_for_uid(netuid, 1, 10);
+        assert!(SubtensorModule::get_neuron_is_immune(netuid, 0));
+        assert!(SubtensorModule::get_neuron_is_immune(netuid, 1));
+
+        // Every candidate is immune, so the lowest pruning score is pruned anyway.
+        assert_eq!(SubtensorModule::get_neuron_to_prune(netuid), 1);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::registration::test_get_neuron_to_prune_tie_break_prefers_newest --exact --show-output --nocapture
+#[test]
+fn test_get_neuron_to_prune_tie_break_prefers_newest() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+        SubtensorModule::set_immunity_period(netuid, 0);
+        register_ok_neuron(netuid, U256::from(0), U256::from(0), 39420842);
+        register_ok_neuron(netuid, U256::from(1), U256::from(1), 12412392);
+        register_ok_neuron(netuid, U256::from(2), U256::from(2), 21813123);
+        step_block(30);
+
+        // Registration blocks deliberately out of uid order.
+        for (uid, block) in [(0, 20), (1, 30), (2, 10)] {
+            BlockAtRegistration::<Test>::insert(netuid, uid, block);
+            SubtensorModule::set_pruning_score_for_uid(netuid, uid, 0);
+        }
+
+        // By default the earliest registered uid is pruned.
+        assert!(!SubtensorModule::get_prune_newest_on_tie(netuid));
+        assert_eq!(SubtensorModule::get_neuron_to_prune(netuid), 2);
+
+        SubtensorModule::set_pruning_score_for_uid(netuid, 2, 0);
+        SubtensorModule::set_prune_newest_on_tie(netuid, true);
+        assert_eq!(SubtensorModule::get_neuron_to_prune(netuid), 1);
+
+        // Dissolving the subnet drops the setting, so a reused netuid starts with the default.
+        SubtensorModule::remove_network(netuid);
+        assert!(!PruneNewestOnTie::<Test>::contains_key(netuid));
+        assert!(!SubtensorModule::get_prune_newest_on_tie(netuid));
+    });
+}
+
 #[test]
 fn test_registration_pruning() {
     new_test_ext(1).execute_with(|| {
@@ -2172,3 +2306,139 @@ fn test_registration_disabled() {
 //         );
 //     });
 // }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::registration::test_batch_register_ok --exact --show-output --nocapture
+#[test]
+fn test_batch_register_ok() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(667);
+        let hotkeys: Vec<U256> = (1..=3).map(U256::from).collect();
+        let burn_cost = 1000;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, burn_cost);
+        SubtensorModule::set_max_allowed_uids(netuid, 4);
+        SubtensorModule::set_max_registrations_per_block(netuid, 16);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 16);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+
+        assert_ok!(SubtensorModule::batch_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            hotkeys.clone()
+        ));
+
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 3);
+        for (uid, hotkey) in hotkeys.iter().enumerate() {
+            assert_eq!(
+                SubtensorModule::get_uid_for_net_and_hotkey(netuid, hotkey),
+                Ok(uid as u16)
+            );
+            assert_eq!(
+                SubtensorModule::get_owning_coldkey_for_hotkey(hotkey),
+                coldkey
+            );
+        }
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            10_000 - 3 * burn_cost
+        );
+        assert_eq!(SubtensorModule::get_registrations_this_block(netuid), 3);
+        assert_eq!(SubtensorModule::get_registrations_this_interval(netuid), 3);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::registration::test_batch_register_overflowing_capacity_rolls_back --exact --show-output --nocapture
+#[test]
+fn test_batch_register_overflowing_capacity_rolls_back() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(667);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, 1000);
+        SubtensorModule::set_max_allowed_uids(netuid, 3);
+        SubtensorModule::set_max_registrations_per_block(netuid, 16);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 16);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+
+        // The fourth hotkey would have to prune one registered by the batch itself.
+        let call = RuntimeCall::SubtensorModule(crate::Call::batch_register {
+            netuid,
+            hotkeys: (1..=4).map(U256::from).collect(),
+        });
+        assert_noop!(
+            call.dispatch(<<Test as Config>::RuntimeOrigin>::signed(coldkey)),
+            Error::<Test>::BatchExceedsSubnetCapacity
+        );
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 0);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 10_000);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::registration::test_batch_register_invalid_size --exact --show-output --nocapture
+#[test]
+fn test_batch_register_invalid_size() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(667);
+        add_network(netuid, 13, 0);
+
+        assert_noop!(
+            SubtensorModule::batch_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                vec![]
+            ),
+            Error::<Test>::InvalidBatchRegistrationSize
+        );
+        assert_noop!(
+            SubtensorModule::batch_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                (0..=crate::MAX_BATCH_REGISTRATIONS as u64)
+                    .map(U256::from)
+                    .collect()
+            ),
+            Error::<Test>::InvalidBatchRegistrationSize
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::registration::test_get_registration_cost_quote --exact --show-output --nocapture
+#[test]
+fn test_get_registration_cost_quote() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+        SubtensorModule::set_burn(netuid, 1_000);
+        SubtensorModule::set_min_burn(netuid, 1_000);
+        SubtensorModule::set_max_burn(netuid, 1_000_000);
+        SubtensorModule::set_difficulty(netuid, 20_000);
+        SubtensorModule::set_network_registration_allowed(netuid, true);
+        assert_eq!(
+            SubtensorModule::get_registration_cost(netuid),
+            (1_000, 20_000, true)
+        );
+
+        // Toggling registration flips the flag without touching the costs.
+        SubtensorModule::set_network_registration_allowed(netuid, false);
+        assert_eq!(
+            SubtensorModule::get_registration_cost(netuid),
+            (1_000, 20_000, false)
+        );
+        SubtensorModule::set_network_registration_allowed(netuid, true);
+
+        // Raising the min burn raises the quoted burn at the next adjustment, even though no
+        // registrations would otherwise halve it.
+        SubtensorModule::set_min_burn(netuid, 5_000);
+        SubtensorModule::set_adjustment_alpha(netuid, 0);
+        SubtensorModule::set_adjustment_interval(netuid, 1);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 1);
+        step_block(1);
+        let (burn_cost, difficulty, registration_allowed) =
+            SubtensorModule::get_registration_cost(netuid);
+        assert_eq!(burn_cost, 5_000);
+        assert_eq!(difficulty, SubtensorModule::get_difficulty_as_u64(netuid));
+        assert!(registration_allowed);
+    });
+}
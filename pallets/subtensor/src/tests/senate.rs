@@ -848,3 +848,77 @@ fn test_adjust_senate_events() {
         );
     });
 }
+
+#[test]
+fn test_propose_requires_min_stake_for_non_senate_member() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 13;
+        let proposer = U256::from(1);
+        let coldkey = U256::from(667);
+        let min_stake: u64 = 1_000_000;
+        add_network(netuid, tempo, 0);
+
+        // The triumvirate member is no longer in the senate, so it has to meet the stake threshold.
+        assert_ok!(SenateMembers::remove_member(
+            RuntimeOrigin::root(),
+            proposer
+        ));
+        assert!(!SubtensorModule::is_senate_member(&proposer));
+        SubtensorModule::set_proposal_min_stake(min_stake);
+
+        // Below the threshold.
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &proposer,
+            &coldkey,
+            netuid,
+            min_stake - 1,
+        );
+        assert!(!SubtensorModule::can_submit_proposal(&proposer));
+        let proposal = make_proposal(42);
+        let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+        assert_noop!(
+            Triumvirate::propose(
+                RuntimeOrigin::signed(proposer),
+                Box::new(proposal.clone()),
+                proposal_len,
+                TryInto::<BlockNumberFor<Test>>::try_into(100u64)
+                    .expect("convert u64 to block number.")
+            ),
+            pallet_collective::Error::<Test, pallet_collective::Instance1>::NotMember
+        );
+
+        // At the threshold.
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &proposer, &coldkey, netuid, 1,
+        );
+        assert!(SubtensorModule::can_submit_proposal(&proposer));
+        assert_ok!(Triumvirate::propose(
+            RuntimeOrigin::signed(proposer),
+            Box::new(proposal),
+            proposal_len,
+            TryInto::<BlockNumberFor<Test>>::try_into(100u64)
+                .expect("convert u64 to block number.")
+        ));
+    });
+}
+
+#[test]
+fn test_propose_senate_member_ignores_min_stake() {
+    new_test_ext().execute_with(|| {
+        let proposer = U256::from(1);
+        assert!(SubtensorModule::is_senate_member(&proposer));
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&proposer), 0);
+        SubtensorModule::set_proposal_min_stake(1_000_000);
+
+        let proposal = make_proposal(42);
+        let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+        assert_ok!(Triumvirate::propose(
+            RuntimeOrigin::signed(proposer),
+            Box::new(proposal),
+            proposal_len,
+            TryInto::<BlockNumberFor<Test>>::try_into(100u64)
+                .expect("convert u64 to block number.")
+        ));
+    });
+}
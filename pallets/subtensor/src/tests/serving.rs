@@ -1316,3 +1316,218 @@ fn test_serve_axon_validate() {
         assert_ok!(result_ok);
     });
 }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::serving::test_rate_limit_info_counts_down_after_serving --exact --show-output --nocapture
+#[test]
+fn test_rate_limit_info_counts_down_after_serving() {
+    use crate::utils::rate_limiting::RateLimitedOperation;
+
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(1);
+        let netuid: u16 = 1;
+        let rate_limit: u64 = 5;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+        SubtensorModule::set_serving_rate_limit(netuid, rate_limit);
+
+        // Never served, so nothing to wait for.
+        assert_eq!(
+            SubtensorModule::get_rate_limit_info(
+                &hotkey_account_id,
+                netuid,
+                RateLimitedOperation::ServeAxon
+            ),
+            (0, rate_limit, 0)
+        );
+
+        run_to_block(1);
+        assert_ok!(SubtensorModule::serve_axon(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+            netuid,
+            2,
+            1676056785,
+            128,
+            4,
+            0,
+            0,
+            0
+        ));
+        let served_block = SubtensorModule::get_current_block_as_u64();
+
+        for elapsed in 0..=rate_limit {
+            run_to_block(served_block + elapsed);
+            assert_eq!(
+                SubtensorModule::get_rate_limit_info(
+                    &hotkey_account_id,
+                    netuid,
+                    RateLimitedOperation::ServeAxon
+                ),
+                (served_block, rate_limit, rate_limit - elapsed)
+            );
+            // The countdown reaching zero is exactly when serving is allowed again.
+            assert_eq!(
+                SubtensorModule::axon_passes_rate_limit(
+                    netuid,
+                    &SubtensorModule::get_axon_info(netuid, &hotkey_account_id),
+                    SubtensorModule::get_current_block_as_u64()
+                ),
+                elapsed == rate_limit
+            );
+        }
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::serving::test_set_neuron_certificate --exact --show-output --nocapture
+#[test]
+fn test_set_neuron_certificate() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(1);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+
+        // The hotkey has to hold a uid on the subnet.
+        assert_noop!(
+            SubtensorModule::set_neuron_certificate(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+                netuid,
+                vec![1, 2, 3]
+            ),
+            Error::<Test>::HotKeyNotRegisteredInSubNet
+        );
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+        assert_noop!(
+            SubtensorModule::set_neuron_certificate(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+                netuid + 1,
+                vec![1, 2, 3]
+            ),
+            Error::<Test>::SubNetworkDoesNotExist
+        );
+
+        // Empty and oversized certificates are rejected.
+        for certificate in [vec![], vec![7; 66]] {
+            assert_noop!(
+                SubtensorModule::set_neuron_certificate(
+                    <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+                    netuid,
+                    certificate
+                ),
+                Error::<Test>::InvalidNeuronCertificate
+            );
+        }
+        assert_eq!(
+            SubtensorModule::get_neuron_certificate(netuid, &hotkey_account_id),
+            Vec::<u8>::new()
+        );
+
+        // A set certificate round-trips through the getter, up to the maximum length.
+        for certificate in ["CERT".as_bytes().to_vec(), vec![7; 65]] {
+            assert_ok!(SubtensorModule::set_neuron_certificate(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+                netuid,
+                certificate.clone()
+            ));
+            assert_eq!(
+                SubtensorModule::get_neuron_certificate(netuid, &hotkey_account_id),
+                certificate
+            );
+        }
+        // The axon is left untouched.
+        assert!(Axons::<Test>::get(netuid, hotkey_account_id).is_none());
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::serving::test_set_neuron_certificate_rate_limited --exact --show-output --nocapture
+#[test]
+fn test_set_neuron_certificate_rate_limited() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(1);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+        SubtensorModule::set_serving_rate_limit(netuid, 2);
+
+        assert_ok!(SubtensorModule::set_neuron_certificate(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+            netuid,
+            "CERT".as_bytes().to_vec()
+        ));
+        run_to_block(2);
+        // Needs to be 2 blocks apart, we are only 1 block apart
+        assert_noop!(
+            SubtensorModule::set_neuron_certificate(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+                netuid,
+                "UPDATED_CERT".as_bytes().to_vec()
+            ),
+            Error::<Test>::ServingRateLimitExceeded
+        );
+        run_to_block(3);
+        assert_ok!(SubtensorModule::set_neuron_certificate(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+            netuid,
+            "UPDATED_CERT".as_bytes().to_vec()
+        ));
+        assert_eq!(
+            SubtensorModule::get_neuron_certificate(netuid, &hotkey_account_id),
+            "UPDATED_CERT".as_bytes().to_vec()
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::serving::test_get_registered_axon_info --exact --show-output --nocapture
+#[test]
+fn test_get_registered_axon_info() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(1);
+        let other_hotkey = U256::from(2);
+        let netuid: u16 = 1;
+        let version: u32 = 2;
+        let ip: u128 = 1676056785;
+        let port: u16 = 128;
+        let ip_type: u8 = 4;
+        let protocol: u8 = 0;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+
+        // Registered, but no axon served yet.
+        assert_eq!(
+            SubtensorModule::get_registered_axon_info(netuid, &hotkey_account_id),
+            None
+        );
+
+        assert_ok!(SubtensorModule::serve_axon(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+            netuid,
+            version,
+            ip,
+            port,
+            ip_type,
+            protocol,
+            0,
+            0
+        ));
+        let axon = SubtensorModule::get_registered_axon_info(netuid, &hotkey_account_id)
+            .expect("served axon is returned");
+        assert_eq!(
+            axon,
+            SubtensorModule::get_axon_info(netuid, &hotkey_account_id)
+        );
+        assert_eq!(axon.version, version);
+        assert_eq!(axon.ip, ip);
+        assert_eq!(axon.port, port);
+        assert_eq!(axon.ip_type, ip_type);
+        assert_eq!(axon.protocol, protocol);
+
+        // Unregistered hotkeys have no axon, even if one is left in storage.
+        Axons::<Test>::insert(netuid, other_hotkey, axon);
+        assert_eq!(
+            SubtensorModule::get_registered_axon_info(netuid, &other_hotkey),
+            None
+        );
+        assert_eq!(
+            SubtensorModule::get_registered_axon_info(netuid + 1, &hotkey_account_id),
+            None
+        );
+    });
+}
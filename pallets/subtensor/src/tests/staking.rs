@@ -89,6 +89,88 @@ fn test_add_stake_ok_no_emission() {
     });
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::staking::test_staked_coldkey_is_not_reaped --exact --show-output --nocapture
+#[test]
+fn test_staked_coldkey_is_not_reaped() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1);
+        let subnet_owner_hotkey = U256::from(2);
+        let hotkey_account_id = U256::from(533453);
+        let coldkey_account_id = U256::from(55453);
+        let amount = DefaultMinStake::<Test>::get() * 10;
+        let netuid: u16 = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        register_ok_neuron(netuid, hotkey_account_id, subnet_owner_coldkey, 192213123);
+
+        // Stake the whole balance, staking keeps the existential deposit.
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey_account_id, amount);
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey_account_id),
+            hotkey_account_id,
+            netuid,
+            amount
+        ));
+        assert!(StakeProviders::<Test>::get(coldkey_account_id));
+
+        // Moving the rest of the free balance out doesn't reap the account.
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(coldkey_account_id),
+            U256::from(3),
+            1
+        ));
+        assert_eq!(Balances::free_balance(coldkey_account_id), 0);
+        assert!(System::account_exists(&coldkey_account_id));
+        let alpha = SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey_account_id,
+            &coldkey_account_id,
+            netuid,
+        );
+        assert!(alpha > 0);
+
+        // Removing all of the stake credits the account and drops the reference.
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(coldkey_account_id),
+            hotkey_account_id,
+            netuid,
+            alpha
+        ));
+        assert!(!StakeProviders::<Test>::get(coldkey_account_id));
+        assert!(Balances::free_balance(coldkey_account_id) > 0);
+        assert_eq!(System::providers(&coldkey_account_id), 1);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::staking::test_stake_provider_released_when_subnet_removed --exact --show-output --nocapture
+#[test]
+fn test_stake_provider_released_when_subnet_removed() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1);
+        let subnet_owner_hotkey = U256::from(2);
+        let hotkey_account_id = U256::from(533453);
+        let coldkey_account_id = U256::from(55453);
+        let amount = DefaultMinStake::<Test>::get() * 10;
+        let netuid: u16 = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        register_ok_neuron(netuid, hotkey_account_id, subnet_owner_coldkey, 192213123);
+
+        SubtensorModule::add_balance_to_coldkey_account(
+            &coldkey_account_id,
+            amount + ExistentialDeposit::get(),
+        );
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey_account_id),
+            hotkey_account_id,
+            netuid,
+            amount
+        ));
+        assert!(StakeProviders::<Test>::get(coldkey_account_id));
+
+        // Stake on a removed subnet no longer keeps the coldkey alive.
+        SubtensorModule::remove_network(netuid);
+        assert!(!SubtensorModule::coldkey_has_stake(&coldkey_account_id));
+        assert!(!StakeProviders::<Test>::get(coldkey_account_id));
+        assert_eq!(System::providers(&coldkey_account_id), 1);
+    });
+}
+
 #[test]
 fn test_dividends_with_run_to_block() {
     new_test_ext(1).execute_with(|| {
@@ -3014,6 +3096,58 @@ fn test_max_amount_remove_dynamic() {
     });
 }
 
+// cargo test --package pallet-subtensor --lib -- tests::staking::test_max_stake_amount_monotonic --exact --show-output
+#[test]
+fn test_max_stake_amount_monotonic() {
+    new_test_ext(0).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+
+        // Price of 2.0
+        SubnetTAO::<Test>::insert(netuid, 200_000_000_000);
+        SubnetAlphaIn::<Test>::insert(netuid, 100_000_000_000);
+
+        let limit_prices: [u64; 8] = [
+            1,
+            500_000_000,
+            1_000_000_000,
+            1_999_999_999,
+            2_000_000_000,
+            2_000_000_001,
+            4_000_000_000,
+            u64::MAX,
+        ];
+
+        let mut last_add = 0;
+        let mut last_remove = u64::MAX;
+        for limit_price in limit_prices {
+            let max_add = SubtensorModule::get_max_stake_amount(netuid, limit_price, true);
+            let max_remove = SubtensorModule::get_max_stake_amount(netuid, limit_price, false);
+
+            // Matches the underlying calculations
+            assert_eq!(
+                max_add,
+                SubtensorModule::get_max_amount_add(netuid, limit_price)
+            );
+            assert_eq!(
+                max_remove,
+                SubtensorModule::get_max_amount_remove(netuid, limit_price)
+            );
+
+            // A higher limit price allows more stake and less unstake
+            assert!(max_add >= last_add);
+            assert!(max_remove <= last_remove);
+            last_add = max_add;
+            last_remove = max_remove;
+        }
+
+        // The quote is pure and leaves the reserves untouched
+        assert_eq!(SubnetTAO::<Test>::get(netuid), 200_000_000_000);
+        assert_eq!(SubnetAlphaIn::<Test>::get(netuid), 100_000_000_000);
+    });
+}
+
 // cargo test --package pallet-subtensor --lib -- tests::staking::test_max_amount_move_root_root --exact --show-output
 #[test]
 fn test_max_amount_move_root_root() {
@@ -4067,3 +4201,976 @@ fn test_move_stake_limit_partial() {
         assert_abs_diff_eq!(new_alpha, 149_000_000_000, epsilon = 100_000_000,);
     });
 }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::staking::test_remove_stake_partial_clamps_to_available_stake --exact --show-output --nocapture
+#[test]
+fn test_remove_stake_partial_clamps_to_available_stake() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let stake_amount = DefaultMinStake::<Test>::get() * 10;
+        let fee = DefaultStakingFee::<Test>::get();
+        let netuid: u16 = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        SubtensorModule::stake_into_subnet(&hotkey, &coldkey, netuid, stake_amount, fee);
+        let alpha =
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
+
+        // Request slightly more than the position holds.
+        let alpha_requested = alpha + 1_000;
+        assert_noop!(
+            SubtensorModule::remove_stake(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                netuid,
+                alpha_requested
+            ),
+            Error::<Test>::NotEnoughStakeToWithdraw
+        );
+        assert_ok!(SubtensorModule::remove_stake_partial(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            alpha_requested
+        ));
+
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid),
+            0
+        );
+        assert!(SubtensorModule::get_coldkey_balance(&coldkey) > 0);
+        System::assert_last_event(RuntimeEvent::SubtensorModule(Event::StakeRemovedPartial(
+            coldkey,
+            hotkey,
+            netuid,
+            alpha_requested,
+            alpha,
+        )));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::staking::test_remove_stake_partial_without_stake_fails --exact --show-output --nocapture
+#[test]
+fn test_remove_stake_partial_without_stake_fails() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let netuid: u16 = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+
+        assert_noop!(
+            SubtensorModule::remove_stake_partial(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                netuid,
+                DefaultMinStake::<Test>::get() * 10
+            ),
+            Error::<Test>::NotEnoughStakeToWithdraw
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::staking::test_clear_small_nomination_emits_event_and_credits_coldkey --exact --show-output --nocapture
+#[test]
+fn test_clear_small_nomination_emits_event_and_credits_coldkey() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(10);
+        let subnet_owner_hotkey = U256::from(20);
+        let hotkey = U256::from(1);
+        let owner_coldkey = U256::from(2);
+        let nominator = U256::from(3);
+        let netuid: u16 = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        let amount = DefaultMinStake::<Test>::get() * 10;
+        let fee: u64 = DefaultMinStake::<Test>::get();
+
+        register_ok_neuron(netuid, hotkey, owner_coldkey, 0);
+        SubtensorModule::set_nominator_min_required_stake(1000);
+
+        SubtensorModule::add_balance_to_coldkey_account(
+            &nominator,
+            amount + fee + ExistentialDeposit::get(),
+        );
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(nominator),
+            hotkey,
+            netuid,
+            amount + fee
+        ));
+        let balance_before = Balances::free_balance(nominator);
+
+        // Unstake all but a dust amount below the nominator minimum.
+        System::reset_events();
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(nominator),
+            hotkey,
+            netuid,
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey, &nominator, netuid
+            ) - 100
+        ));
+
+        // The dust nomination has been cleared.
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey, &nominator, netuid
+            ),
+            0
+        );
+        let returned_tao = System::events()
+            .iter()
+            .find_map(|record| match record.event {
+                RuntimeEvent::SubtensorModule(Event::NominationCleared {
+                    coldkey,
+                    hotkey: cleared_hotkey,
+                    netuid: cleared_netuid,
+                    returned_tao,
+                }) if coldkey == nominator
+                    && cleared_hotkey == hotkey
+                    && cleared_netuid == netuid =>
+                {
+                    Some(returned_tao)
+                }
+                _ => None,
+            })
+            .expect("NominationCleared should be emitted");
+        assert!(returned_tao > 0);
+
+        // Both the unstaked and the cleared TAO are credited to the nominator.
+        let unstaked_tao: u64 = System::events()
+            .iter()
+            .filter_map(|record| match record.event {
+                RuntimeEvent::SubtensorModule(Event::StakeRemoved(coldkey, _, tao, _, _))
+                    if coldkey == nominator =>
+                {
+                    Some(tao)
+                }
+                _ => None,
+            })
+            .sum();
+        assert_eq!(
+            Balances::free_balance(nominator),
+            balance_before + unstaked_tao
+        );
+        assert!(unstaked_tao > returned_tao);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::staking::test_check_stake_consistency_reports_injected_discrepancies --exact --show-output --nocapture
+#[test]
+fn test_check_stake_consistency_reports_injected_discrepancies() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let netuid: u16 = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        let amount = DefaultMinStake::<Test>::get() * 10;
+
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(
+            &coldkey,
+            amount + ExistentialDeposit::get(),
+        );
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            amount
+        ));
+        let stake =
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
+        assert!(stake > 0);
+        assert!(SubtensorModule::check_stake_consistency(netuid).is_empty());
+
+        // Drop the hotkey from the coldkey's staking index.
+        StakingHotkeys::<Test>::mutate(coldkey, |hotkeys| hotkeys.retain(|h| *h != hotkey));
+        assert_eq!(
+            SubtensorModule::check_stake_consistency(netuid),
+            vec![(hotkey, coldkey, stake as i64)]
+        );
+        StakingHotkeys::<Test>::mutate(coldkey, |hotkeys| hotkeys.push(hotkey));
+
+        // Inflate the hotkey's share total past what its stakers hold.
+        TotalHotkeyShares::<Test>::mutate(hotkey, netuid, |shares| {
+            *shares = shares.saturating_add(U64F64::saturating_from_num(1_000))
+        });
+        assert_eq!(
+            SubtensorModule::check_stake_consistency(netuid),
+            vec![(hotkey, coldkey, -1_000)]
+        );
+
+        // Other subnets are unaffected.
+        assert!(SubtensorModule::check_stake_consistency(netuid + 1).is_empty());
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::staking::test_subnet_volume_accumulates_over_trades --exact --show-output --nocapture
+#[test]
+fn test_subnet_volume_accumulates_over_trades() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let netuid: u16 = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        SubnetTAO::<Test>::insert(netuid, 100_000_000_000);
+        SubnetAlphaIn::<Test>::insert(netuid, 100_000_000_000);
+        let initial_volume = SubtensorModule::get_subnet_volume(netuid);
+
+        // Buys count the TAO paid in.
+        let tao_in_1: u64 = 1_000_000_000;
+        let alpha_1 = SubtensorModule::swap_tao_for_alpha(netuid, tao_in_1);
+        assert!(alpha_1 > 0);
+        assert_eq!(
+            SubtensorModule::get_subnet_volume(netuid),
+            initial_volume + tao_in_1 as u128
+        );
+
+        let tao_in_2: u64 = 2_500_000_000;
+        let alpha_2 = SubtensorModule::swap_tao_for_alpha(netuid, tao_in_2);
+        assert!(alpha_2 > 0);
+
+        // Sells count the TAO paid out.
+        let tao_out = SubtensorModule::swap_alpha_for_tao(netuid, alpha_1 + alpha_2);
+        assert!(tao_out > 0);
+
+        assert_eq!(
+            SubtensorModule::get_subnet_volume(netuid),
+            initial_volume + tao_in_1 as u128 + tao_in_2 as u128 + tao_out as u128
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::staking::test_staking_fee_drops_after_volume_threshold --exact --show-output --nocapture
+#[test]
+fn test_staking_fee_drops_after_volume_threshold() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        let default_fee = DefaultStakingFee::<Test>::get();
+        let discounted_fee = default_fee / 2;
+        let threshold: u128 = SubnetVolume::<Test>::get(netuid) + 1_000_000_000;
+        let add_fee = || {
+            SubtensorModule::calculate_staking_fee(
+                None,
+                &coldkey,
+                Some((&hotkey, netuid)),
+                &coldkey,
+                I96F32::saturating_from_num(0),
+            )
+        };
+
+        // Flat fee until tiers are configured.
+        assert_eq!(add_fee(), default_fee);
+
+        // Invalid schedules are rejected.
+        assert!(!SubtensorModule::are_staking_fee_volume_tiers_valid(&[(
+            threshold,
+            default_fee + 1
+        )]));
+        assert!(!SubtensorModule::are_staking_fee_volume_tiers_valid(&[
+            (threshold, discounted_fee),
+            (threshold, discounted_fee / 2)
+        ]));
+        assert!(!SubtensorModule::are_staking_fee_volume_tiers_valid(&[
+            (threshold, discounted_fee),
+            (threshold + 1, discounted_fee + 1)
+        ]));
+        let tiers = vec![(threshold, discounted_fee)];
+        assert!(SubtensorModule::are_staking_fee_volume_tiers_valid(&tiers));
+        SubtensorModule::set_staking_fee_volume_tiers(tiers);
+
+        // Below the threshold the default fee still applies.
+        let tao_to_stake = DefaultMinStake::<Test>::get() * 10;
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000_000_000);
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            tao_to_stake
+        ));
+        assert!(SubnetVolume::<Test>::get(netuid) < threshold);
+        assert_eq!(add_fee(), default_fee);
+
+        // Crossing the threshold lowers the fee.
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            1_000_000_000
+        ));
+        assert!(SubnetVolume::<Test>::get(netuid) >= threshold);
+        assert_eq!(
+            SubtensorModule::get_volume_staking_fee(netuid),
+            discounted_fee
+        );
+        assert_eq!(add_fee(), discounted_fee);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::staking::test_recent_volume_rolls_over_window --exact --show-output --nocapture
+#[test]
+fn test_recent_volume_rolls_over_window() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let netuid: u16 = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        SubnetTAO::<Test>::insert(netuid, 100_000_000_000);
+        SubnetAlphaIn::<Test>::insert(netuid, 100_000_000_000);
+        let window = RECENT_VOLUME_BUCKETS * RECENT_VOLUME_BUCKET_BLOCKS;
+        let start = System::block_number();
+        let cumulative_before = SubtensorModule::get_subnet_volume(netuid);
+
+        // Trades in the same bucket add up.
+        SubtensorModule::swap_tao_for_alpha(netuid, 1_000_000_000);
+        System::set_block_number(start + 10);
+        SubtensorModule::swap_tao_for_alpha(netuid, 500_000_000);
+        assert_eq!(SubtensorModule::get_recent_volume(netuid), 1_500_000_000);
+
+        // A trade in a later bucket is added to the window.
+        System::set_block_number(start + RECENT_VOLUME_BUCKET_BLOCKS);
+        SubtensorModule::swap_tao_for_alpha(netuid, 2_000_000_000);
+        assert_eq!(SubtensorModule::get_recent_volume(netuid), 3_500_000_000);
+
+        // Once the first bucket is out of the window only the later trade is counted.
+        System::set_block_number(start + window);
+        assert_eq!(SubtensorModule::get_recent_volume(netuid), 2_000_000_000);
+        SubtensorModule::swap_tao_for_alpha(netuid, 3_000_000_000);
+        assert_eq!(SubtensorModule::get_recent_volume(netuid), 5_000_000_000);
+        assert_eq!(SubnetRecentVolume::<Test>::get(netuid).len(), 2);
+
+        // Without trades the recent volume decays to zero, unlike the cumulative volume.
+        System::set_block_number(start + 3 * window);
+        assert_eq!(SubtensorModule::get_recent_volume(netuid), 0);
+        assert_eq!(
+            SubtensorModule::get_subnet_volume(netuid),
+            cumulative_before + 6_500_000_000
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::staking::test_subnet_reserves_price_the_next_stake --exact --show-output --nocapture
+#[test]
+fn test_subnet_reserves_price_the_next_stake() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let netuid: u16 = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        SubnetTAO::<Test>::insert(netuid, 150_000_000_000);
+        SubnetAlphaIn::<Test>::insert(netuid, 100_000_000_000);
+
+        // A small stake prices in along the constant product of the reported reserves.
+        let (tao_reserve, alpha_reserve) = SubtensorModule::get_subnet_reserves(netuid);
+        assert_eq!(
+            (tao_reserve, alpha_reserve),
+            (150_000_000_000, 100_000_000_000)
+        );
+        let tao: u64 = 1_000_000_000;
+        let expected_alpha = alpha_reserve as u128
+            - (tao_reserve as u128 * alpha_reserve as u128)
+                .div_ceil(tao_reserve as u128 + tao as u128);
+        let alpha = SubtensorModule::swap_tao_for_alpha(netuid, tao);
+        assert_abs_diff_eq!(alpha as u128, expected_alpha, epsilon = 1);
+
+        // The reserves reflect the stake right away.
+        assert_eq!(
+            SubtensorModule::get_subnet_reserves(netuid),
+            (tao_reserve + tao, alpha_reserve - alpha)
+        );
+
+        // Root and stable subnets swap 1:1 and report no reserves.
+        SubnetTAO::<Test>::insert(0, 1_000_000_000);
+        assert_eq!(SubtensorModule::get_subnet_reserves(0), (0, 0));
+        SubnetMechanism::<Test>::insert(netuid, 0);
+        assert_eq!(SubtensorModule::get_subnet_reserves(netuid), (0, 0));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::staking::test_recycle_deregistered_stake_returns_tao_to_nominators --exact --show-output --nocapture
+#[test]
+fn test_recycle_deregistered_stake_returns_tao_to_nominators() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let nominators = [U256::from(3), U256::from(4)];
+        let caller = U256::from(5);
+        let new_hotkey = U256::from(6);
+        let stake_amount = DefaultMinStake::<Test>::get() * 10;
+        let fee = DefaultStakingFee::<Test>::get();
+        let netuid: u16 = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        register_ok_neuron(netuid, hotkey, coldkey, 192213123);
+        for nominator in nominators {
+            SubtensorModule::stake_into_subnet(&hotkey, &nominator, netuid, stake_amount, fee);
+        }
+
+        // The stake can't be recycled while the hotkey holds its uid.
+        assert_noop!(
+            SubtensorModule::recycle_deregistered_stake(
+                RuntimeOrigin::signed(caller),
+                hotkey,
+                netuid
+            ),
+            Error::<Test>::HotKeyStillRegisteredOnSubnet
+        );
+
+        // Replace the neuron; its nominations are left staked to the old hotkey.
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+        SubtensorModule::replace_neuron(netuid, uid, &new_hotkey, 0);
+
+        assert_ok!(SubtensorModule::recycle_deregistered_stake(
+            RuntimeOrigin::signed(caller),
+            hotkey,
+            netuid
+        ));
+        for nominator in nominators {
+            assert_eq!(
+                SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                    &hotkey, &nominator, netuid
+                ),
+                0
+            );
+            assert!(SubtensorModule::get_coldkey_balance(&nominator) > 0);
+        }
+        System::assert_last_event(RuntimeEvent::SubtensorModule(
+            Event::DeregisteredStakeRecycled {
+                hotkey,
+                netuid,
+                nominations: 2,
+            },
+        ));
+
+        // Nothing is left to recycle, and the hotkey is no longer tracked.
+        assert!(!PrunedHotkeys::<Test>::contains_key(netuid, hotkey));
+        assert_noop!(
+            SubtensorModule::recycle_deregistered_stake(
+                RuntimeOrigin::signed(caller),
+                hotkey,
+                netuid
+            ),
+            Error::<Test>::HotKeyNotPrunedFromSubnet
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::staking::test_recycle_deregistered_stake_requires_pruned_hotkey --exact --show-output --nocapture
+#[test]
+fn test_recycle_deregistered_stake_requires_pruned_hotkey() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let unregistered_hotkey = U256::from(3);
+        let child = U256::from(4);
+        let nominator = U256::from(5);
+        let caller = U256::from(6);
+        let new_hotkey = U256::from(7);
+        let stake_amount = DefaultMinStake::<Test>::get() * 10;
+        let fee = DefaultStakingFee::<Test>::get();
+        let netuid: u16 = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        SubtensorModule::set_max_allowed_uids(netuid, 16);
+        register_ok_neuron(netuid, hotkey, coldkey, 192213123);
+        SubtensorModule::stake_into_subnet(&hotkey, &nominator, netuid, stake_amount, fee);
+        SubtensorModule::stake_into_subnet(
+            &unregistered_hotkey,
+            &nominator,
+            netuid,
+            stake_amount,
+            fee,
+        );
+
+        // Stake on a hotkey that was never registered can't be recycled.
+        assert_noop!(
+            SubtensorModule::recycle_deregistered_stake(
+                RuntimeOrigin::signed(caller),
+                unregistered_hotkey,
+                netuid
+            ),
+            Error::<Test>::HotKeyNotPrunedFromSubnet
+        );
+
+        // Nor can the stake of a pruned hotkey with children.
+        ChildKeys::<Test>::insert(hotkey, netuid, vec![(u64::MAX, child)]);
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+        SubtensorModule::replace_neuron(netuid, uid, &new_hotkey, 0);
+        assert!(PrunedHotkeys::<Test>::contains_key(netuid, hotkey));
+        assert_noop!(
+            SubtensorModule::recycle_deregistered_stake(
+                RuntimeOrigin::signed(caller),
+                hotkey,
+                netuid
+            ),
+            Error::<Test>::HotKeyHasChildOrParentKeys
+        );
+
+        // Registering again keeps the stake in place.
+        ChildKeys::<Test>::remove(hotkey, netuid);
+        register_ok_neuron(netuid, hotkey, coldkey, 39420842);
+        assert!(!PrunedHotkeys::<Test>::contains_key(netuid, hotkey));
+        assert_noop!(
+            SubtensorModule::recycle_deregistered_stake(
+                RuntimeOrigin::signed(caller),
+                hotkey,
+                netuid
+            ),
+            Error::<Test>::HotKeyStillRegisteredOnSubnet
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::staking::test_recycle_deregistered_stake_respects_slippage_limit --exact --show-output --nocapture
+#[test]
+fn test_recycle_deregistered_stake_respects_slippage_limit() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let nominators = [U256::from(3), U256::from(4)];
+        let caller = U256::from(5);
+        let new_hotkey = U256::from(6);
+        let stake_amount = DefaultMinStake::<Test>::get() * 10;
+        let fee = DefaultStakingFee::<Test>::get();
+        let netuid: u16 = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        register_ok_neuron(netuid, hotkey, coldkey, 192213123);
+        for nominator in nominators {
+            SubtensorModule::stake_into_subnet(&hotkey, &nominator, netuid, stake_amount, fee);
+        }
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+        SubtensorModule::replace_neuron(netuid, uid, &new_hotkey, 0);
+        let stakes_before = nominators.map(|nominator| {
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &nominator, netuid)
+        });
+
+        // A spot price already pushed below the moving price can't be recycled into.
+        let reserve: u64 = stakes_before.iter().sum();
+        SubnetTAO::<Test>::insert(netuid, reserve);
+        SubnetAlphaIn::<Test>::insert(netuid, reserve);
+        SubnetMovingPrice::<Test>::insert(netuid, I96F32::from_num(2));
+        assert_noop!(
+            SubtensorModule::recycle_deregistered_stake(
+                RuntimeOrigin::signed(caller),
+                hotkey,
+                netuid
+            ),
+            Error::<Test>::SlippageTooHigh
+        );
+
+        // Otherwise the unstakes stop at the slippage limit below the moving price.
+        SubnetMovingPrice::<Test>::insert(netuid, I96F32::from_num(1));
+        assert_ok!(SubtensorModule::recycle_deregistered_stake(
+            RuntimeOrigin::signed(caller),
+            hotkey,
+            netuid
+        ));
+        let limit_price = SubtensorModule::recycle_limit_price(netuid);
+        assert!(SubtensorModule::get_alpha_price_in_rao(netuid) >= limit_price - 1_000);
+        for nominator in nominators {
+            assert!(
+                SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                    &hotkey, &nominator, netuid
+                ) > 0
+            );
+        }
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::staking::test_add_stake_below_min_stake_amount --exact --show-output --nocapture
+#[test]
+fn test_add_stake_below_min_stake_amount() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let min_stake_amount = DefaultMinStake::<Test>::get() * 10;
+        let netuid: u16 = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, min_stake_amount * 2);
+        SubtensorModule::set_min_stake_amount(min_stake_amount);
+
+        assert_noop!(
+            SubtensorModule::add_stake(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                netuid,
+                min_stake_amount - 1
+            ),
+            Error::<Test>::AmountTooSmall
+        );
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            min_stake_amount
+        ));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::staking::test_remove_stake_below_min_stake_amount --exact --show-output --nocapture
+#[test]
+fn test_remove_stake_below_min_stake_amount() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let stake_amount = DefaultMinStake::<Test>::get() * 100;
+        let fee = DefaultStakingFee::<Test>::get();
+        let netuid: u16 = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        SubtensorModule::stake_into_subnet(&hotkey, &coldkey, netuid, stake_amount, fee);
+        let alpha =
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
+
+        // Unstakes are measured by the TAO their alpha swaps for.
+        let alpha_unstaked = alpha / 2;
+        let tao_equivalent =
+            SubtensorModule::sim_swap_alpha_for_tao(netuid, alpha_unstaked).unwrap();
+
+        SubtensorModule::set_min_stake_amount(tao_equivalent + 1);
+        assert_noop!(
+            SubtensorModule::remove_stake(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                netuid,
+                alpha_unstaked
+            ),
+            Error::<Test>::AmountTooSmall
+        );
+
+        SubtensorModule::set_min_stake_amount(tao_equivalent);
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            alpha_unstaked
+        ));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::staking::test_add_stake_rejects_nomination_below_min_required_stake --exact --show-output --nocapture
+#[test]
+fn test_add_stake_rejects_nomination_below_min_required_stake() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(10);
+        let subnet_owner_hotkey = U256::from(20);
+        let hotkey = U256::from(1);
+        let owner_coldkey = U256::from(2);
+        let nominator = U256::from(3);
+        let netuid: u16 = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        SubnetTAO::<Test>::insert(netuid, 1_000_000_000_000);
+        SubnetAlphaIn::<Test>::insert(netuid, 1_000_000_000_000);
+        register_ok_neuron(netuid, hotkey, owner_coldkey, 0);
+        SubtensorModule::set_nominator_min_required_stake(1_000_000_000);
+        SubtensorModule::add_balance_to_coldkey_account(&nominator, 10_000_000_000);
+        SubtensorModule::add_balance_to_coldkey_account(&owner_coldkey, 10_000_000_000);
+
+        // A nomination that would land below the minimum fails up front.
+        assert_noop!(
+            SubtensorModule::add_stake(
+                RuntimeOrigin::signed(nominator),
+                hotkey,
+                netuid,
+                500_000_000
+            ),
+            Error::<Test>::StakeAmountTooLow
+        );
+        assert_noop!(
+            SubtensorModule::add_stake_limit(
+                RuntimeOrigin::signed(nominator),
+                hotkey,
+                netuid,
+                500_000_000,
+                u64::MAX,
+                true
+            ),
+            Error::<Test>::StakeAmountTooLow
+        );
+
+        // The owner's own stake isn't a nomination and is never cleared.
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(owner_coldkey),
+            hotkey,
+            netuid,
+            500_000_000
+        ));
+
+        // A nomination above the minimum succeeds.
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(nominator),
+            hotkey,
+            netuid,
+            2_000_000_000
+        ));
+        assert!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey, &nominator, netuid
+            ) >= 1_000_000_000
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::staking::test_stake_whitelist_limits_stakers --exact --show-output --nocapture
+#[test]
+fn test_stake_whitelist_limits_stakers() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(10);
+        let subnet_owner_hotkey = U256::from(20);
+        let hotkey = U256::from(1);
+        let allowed_coldkey = U256::from(2);
+        let other_coldkey = U256::from(3);
+        let amount = 10_000_000_000;
+        let netuid: u16 = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        register_ok_neuron(netuid, hotkey, subnet_owner_coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&allowed_coldkey, amount);
+        SubtensorModule::add_balance_to_coldkey_account(&other_coldkey, amount);
+
+        SubtensorModule::set_stake_whitelist(netuid, true);
+        SubtensorModule::set_stake_allowed_coldkey(netuid, allowed_coldkey, true);
+
+        // Only whitelisted coldkeys can add stake.
+        assert_noop!(
+            SubtensorModule::add_stake(
+                RuntimeOrigin::signed(other_coldkey),
+                hotkey,
+                netuid,
+                amount
+            ),
+            Error::<Test>::NotWhitelisted
+        );
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(allowed_coldkey),
+            hotkey,
+            netuid,
+            amount
+        ));
+
+        // Stake can't be handed to a coldkey that isn't whitelisted either.
+        let alpha = SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey,
+            &allowed_coldkey,
+            netuid,
+        );
+        assert_noop!(
+            SubtensorModule::do_transfer_stake(
+                RuntimeOrigin::signed(allowed_coldkey),
+                other_coldkey,
+                hotkey,
+                netuid,
+                netuid,
+                alpha
+            ),
+            Error::<Test>::NotWhitelisted
+        );
+
+        // Once the whitelist is off anyone can stake again.
+        SubtensorModule::set_stake_whitelist(netuid, false);
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(other_coldkey),
+            hotkey,
+            netuid,
+            amount
+        ));
+
+        // Dissolving the subnet drops the whitelist, so a subnet on the reused netuid starts open.
+        SubtensorModule::set_stake_whitelist(netuid, true);
+        SubtensorModule::remove_network(netuid);
+        assert!(!StakeWhitelist::<Test>::contains_key(netuid));
+        assert!(!StakeAllowedColdkeys::<Test>::contains_key(
+            netuid,
+            allowed_coldkey
+        ));
+        assert!(SubtensorModule::is_coldkey_allowed_to_stake(
+            netuid,
+            &other_coldkey
+        ));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::staking::test_get_total_delegated_stake_for_coldkey --exact --show-output --nocapture
+#[test]
+fn test_get_total_delegated_stake_for_coldkey() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(10);
+        let subnet_owner_hotkey = U256::from(20);
+        let delegate_coldkey = U256::from(30);
+        let hotkey1 = U256::from(1);
+        let hotkey2 = U256::from(2);
+        let own_hotkey = U256::from(3);
+        let coldkey = U256::from(4);
+        let netuid1: u16 = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        let netuid2: u16 = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        SubnetTAO::<Test>::insert(netuid1, 1_000_000_000_000);
+        SubnetAlphaIn::<Test>::insert(netuid1, 1_000_000_000_000);
+        SubnetTAO::<Test>::insert(netuid2, 2_000_000_000_000);
+        SubnetAlphaIn::<Test>::insert(netuid2, 1_000_000_000_000);
+        SubtensorModule::create_account_if_non_existent(&delegate_coldkey, &hotkey1);
+        SubtensorModule::create_account_if_non_existent(&delegate_coldkey, &hotkey2);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &own_hotkey);
+
+        // Delegate to two hotkeys across two subnets, and stake to one owned hotkey.
+        SubtensorModule::stake_into_subnet(&hotkey1, &coldkey, netuid1, 1_000_000_000, 0);
+        SubtensorModule::stake_into_subnet(&hotkey1, &coldkey, netuid2, 2_000_000_000, 0);
+        SubtensorModule::stake_into_subnet(&hotkey2, &coldkey, netuid2, 3_000_000_000, 0);
+        SubtensorModule::stake_into_subnet(&own_hotkey, &coldkey, netuid1, 4_000_000_000, 0);
+
+        let tao_value = |hotkey: &U256, netuid: u16| -> u64 {
+            let alpha = SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                hotkey, &coldkey, netuid,
+            );
+            (I96F32::from_num(alpha) * SubtensorModule::get_alpha_price(netuid)).to_num::<u64>()
+        };
+        let expected = tao_value(&hotkey1, netuid1)
+            + tao_value(&hotkey1, netuid2)
+            + tao_value(&hotkey2, netuid2);
+        assert!(expected > 0);
+
+        // Stake to the coldkey's own hotkey isn't delegated.
+        assert_eq!(
+            SubtensorModule::get_total_delegated_stake_for_coldkey(&coldkey),
+            expected
+        );
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_coldkey(&coldkey),
+            expected + tao_value(&own_hotkey, netuid1)
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::staking::test_can_stake --exact --show-output --nocapture
+#[test]
+fn test_can_stake() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(10);
+        let subnet_owner_hotkey = U256::from(20);
+        let coldkey = U256::from(1);
+        let netuid: u16 = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+
+        assert!(SubtensorModule::can_stake(netuid, &coldkey));
+
+        // The subnet must exist.
+        assert!(!SubtensorModule::can_stake(netuid + 1, &coldkey));
+
+        // The coldkey must pass the stake whitelist.
+        SubtensorModule::set_stake_whitelist(netuid, true);
+        assert!(!SubtensorModule::can_stake(netuid, &coldkey));
+        SubtensorModule::set_stake_allowed_coldkey(netuid, coldkey, true);
+        assert!(SubtensorModule::can_stake(netuid, &coldkey));
+
+        // A coldkey with a scheduled swap can't stake.
+        ColdkeySwapScheduled::<Test>::insert(coldkey, ());
+        assert!(!SubtensorModule::can_stake(netuid, &coldkey));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::staking::test_alpha_price_in_rao_follows_stake --exact --show-output --nocapture
+#[test]
+fn test_alpha_price_in_rao_follows_stake() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let coldkey = U256::from(1003);
+        let netuid: u16 = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        SubnetTAO::<Test>::insert(netuid, 100_000_000_000);
+        SubnetAlphaIn::<Test>::insert(netuid, 100_000_000_000);
+
+        // Equal reserves price one alpha at one TAO.
+        let initial_price = SubtensorModule::get_alpha_price_in_rao(netuid);
+        assert_eq!(initial_price, 1_000_000_000);
+
+        // A large stake buys alpha out of the pool and raises the price.
+        let amount: u64 = 50_000_000_000;
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, amount);
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            subnet_owner_hotkey,
+            netuid,
+            amount
+        ));
+        let price_after_stake = SubtensorModule::get_alpha_price_in_rao(netuid);
+        assert!(price_after_stake > initial_price);
+        assert_eq!(
+            price_after_stake,
+            (SubtensorModule::get_alpha_price(netuid) * I96F32::from_num(1_000_000_000))
+                .to_num::<u64>()
+        );
+
+        // Selling the alpha back lowers it again.
+        let alpha = SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &subnet_owner_hotkey,
+            &coldkey,
+            netuid,
+        );
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(coldkey),
+            subnet_owner_hotkey,
+            netuid,
+            alpha
+        ));
+        assert!(SubtensorModule::get_alpha_price_in_rao(netuid) < price_after_stake);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::staking::test_get_hotkey_stake_breakdown --exact --show-output --nocapture
+#[test]
+fn test_get_hotkey_stake_breakdown() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let amount = DefaultMinStake::<Test>::get() * 10;
+
+        // Stake a different amount on three subnets, and nothing on a fourth.
+        let netuids: Vec<u16> = (0..4)
+            .map(|_| add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey))
+            .collect();
+        register_ok_neuron(netuids[0], hotkey, coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(
+            &coldkey,
+            amount * 6 + ExistentialDeposit::get(),
+        );
+        for (i, netuid) in netuids.iter().take(3).enumerate() {
+            assert_ok!(SubtensorModule::add_stake(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                *netuid,
+                amount * (i as u64 + 1)
+            ));
+        }
+
+        let breakdown = SubtensorModule::get_hotkey_stake_breakdown(&hotkey);
+        assert_eq!(breakdown.len(), 3);
+        for ((netuid, alpha, tao_value), expected_netuid) in breakdown.iter().zip(netuids.iter()) {
+            assert_eq!(netuid, expected_netuid);
+            assert_eq!(
+                *alpha,
+                SubtensorModule::get_stake_for_hotkey_on_subnet(&hotkey, *netuid)
+            );
+            assert_eq!(
+                *tao_value,
+                (I96F32::from_num(*alpha) * SubtensorModule::get_alpha_price(*netuid))
+                    .to_num::<u64>()
+            );
+        }
+        // The larger stakes are worth more.
+        assert!(breakdown[0].2 < breakdown[1].2 && breakdown[1].2 < breakdown[2].2);
+        assert_eq!(
+            breakdown
+                .iter()
+                .map(|(_, _, tao_value)| tao_value)
+                .sum::<u64>(),
+            SubtensorModule::get_total_stake_for_hotkey(&hotkey)
+        );
+    });
+}
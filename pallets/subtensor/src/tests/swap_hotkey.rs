@@ -369,6 +369,48 @@ fn test_swap_loaded_emission() {
     });
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_pending_smoothed_emission --exact --nocapture
+#[test]
+fn test_swap_pending_smoothed_emission() {
+    new_test_ext(1).execute_with(|| {
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let other_hotkey = U256::from(4);
+        let netuid = 1u16;
+        let mut weight = Weight::zero();
+
+        add_network(netuid, 1, 1);
+        IsNetworkMember::<Test>::insert(old_hotkey, netuid, true);
+        PendingSmoothedEmission::<Test>::insert(
+            netuid,
+            SmoothedEmission {
+                blocks_remaining: 10,
+                pending_tao: 0,
+                owner_cut: 0,
+                incentives: vec![(old_hotkey, 1_000)],
+                dividends: vec![(old_hotkey, 2_000), (other_hotkey, 3_000)],
+            },
+        );
+
+        assert_ok!(SubtensorModule::perform_hotkey_swap(
+            &old_hotkey,
+            &new_hotkey,
+            &coldkey,
+            &mut weight
+        ));
+
+        // The rest of the schedule is paid to the new hotkey.
+        let smoothed = PendingSmoothedEmission::<Test>::get(netuid).unwrap();
+        assert_eq!(smoothed.blocks_remaining, 10);
+        assert_eq!(smoothed.incentives, vec![(new_hotkey, 1_000)]);
+        assert_eq!(
+            smoothed.dividends,
+            vec![(other_hotkey, 3_000), (new_hotkey, 2_000)]
+        );
+    });
+}
+
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_staking_hotkeys --exact --nocapture
 #[test]
 fn test_swap_staking_hotkeys() {
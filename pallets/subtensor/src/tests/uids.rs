@@ -1,6 +1,7 @@
 #![allow(clippy::unwrap_used)]
 
 use super::mock::*;
+use crate::rpc_info::show_subnet::SubnetNeuronState;
 use crate::*;
 use frame_support::{assert_err, assert_ok};
 use frame_system::Config;
@@ -469,3 +470,152 @@ fn test_get_neuron_to_prune_owner_pruned_if_not_in_sn_owner_hotkey_map() {
         );
     });
 }
+
+/********************************************
+    tests uids::get_available_slots()
+*********************************************/
+
+#[test]
+fn test_available_slots_empty_subnet() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, 4);
+
+        assert_eq!(SubtensorModule::get_available_slots(netuid), 4);
+        assert!(SubtensorModule::is_slot_available(netuid));
+    });
+}
+
+#[test]
+fn test_available_slots_partially_filled_subnet() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, 4);
+        SubtensorModule::set_max_registrations_per_block(netuid, 4);
+
+        register_ok_neuron(netuid, U256::from(1), U256::from(101), 0);
+        register_ok_neuron(netuid, U256::from(2), U256::from(102), 100_000);
+        register_ok_neuron(netuid, U256::from(3), U256::from(103), 200_000);
+
+        assert_eq!(SubtensorModule::get_available_slots(netuid), 1);
+        assert!(SubtensorModule::is_slot_available(netuid));
+    });
+}
+
+#[test]
+fn test_available_slots_full_subnet() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, 2);
+
+        register_ok_neuron(netuid, U256::from(1), U256::from(101), 0);
+        register_ok_neuron(netuid, U256::from(2), U256::from(102), 100_000);
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 2);
+
+        assert_eq!(SubtensorModule::get_available_slots(netuid), 0);
+        assert!(!SubtensorModule::is_slot_available(netuid));
+
+        // Lowering the max below the number of neurons doesn't underflow.
+        SubtensorModule::set_max_allowed_uids(netuid, 1);
+        assert_eq!(SubtensorModule::get_available_slots(netuid), 0);
+        assert!(!SubtensorModule::is_slot_available(netuid));
+    });
+}
+
+#[test]
+fn test_get_subnet_neuron_state_after_append() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let block_number: u64 = 5;
+        add_network(netuid, 13, 0);
+        assert_eq!(SubtensorModule::get_subnet_neuron_state(netuid + 1), None);
+
+        SubtensorModule::append_neuron(netuid, &U256::from(1), block_number);
+        SubtensorModule::append_neuron(netuid, &U256::from(2), block_number);
+
+        let expected = SubnetNeuronState {
+            netuid: netuid.into(),
+            active: vec![true, true],
+            validator_permit: vec![false, false],
+            last_update: vec![block_number.into(), block_number.into()],
+            pruning_scores: vec![0.into(), 0.into()],
+        };
+        assert_eq!(
+            SubtensorModule::get_subnet_neuron_state(netuid),
+            Some(expected.clone())
+        );
+
+        // Entries past the neuron count are left out of the response.
+        Active::<Test>::mutate(netuid, |v| v.push(false));
+        assert_eq!(
+            SubtensorModule::get_subnet_neuron_state(netuid),
+            Some(expected)
+        );
+    });
+}
+
+#[test]
+fn test_get_full_metagraph() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        assert_eq!(SubtensorModule::get_full_metagraph(netuid + 1), None);
+
+        for i in 0..3 {
+            register_ok_neuron(netuid, U256::from(i), U256::from(i + 100), 0);
+        }
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &U256::from(1),
+            &U256::from(101),
+            netuid,
+            1_000,
+        );
+        // A per-uid vector out of step with the neuron count is cut to it.
+        Active::<Test>::mutate(netuid, |v| v.push(false));
+
+        let n = usize::from(SubtensorModule::get_subnetwork_n(netuid));
+        let metagraph = SubtensorModule::get_full_metagraph(netuid).unwrap();
+        assert_eq!(n, 3);
+        for len in [
+            metagraph.keys.len(),
+            metagraph.stake.len(),
+            metagraph.rank.len(),
+            metagraph.trust.len(),
+            metagraph.consensus.len(),
+            metagraph.incentive.len(),
+            metagraph.dividends.len(),
+            metagraph.emission.len(),
+            metagraph.active.len(),
+            metagraph.validator_permit.len(),
+            metagraph.last_update.len(),
+            metagraph.axons.len(),
+        ] {
+            assert_eq!(len, n);
+        }
+        assert_eq!(
+            metagraph.keys,
+            vec![U256::from(0), U256::from(1), U256::from(2)]
+        );
+        assert_eq!(metagraph.stake, vec![0.into(), 1_000.into(), 0.into()]);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::uids::test_append_neuron_catches_inconsistent_vectors --exact --show-output --nocapture
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "Trust has 3 entries on subnet 1 with 2 uids")]
+fn test_append_neuron_catches_inconsistent_vectors() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, U256::from(1), U256::from(101), 0);
+        SubtensorModule::assert_subnet_vectors_consistent(netuid);
+
+        // Leave a stray entry behind, as a faulty migration would.
+        Trust::<Test>::mutate(netuid, |v| v.push(0));
+        register_ok_neuron(netuid, U256::from(2), U256::from(102), 0);
+    });
+}
@@ -4050,6 +4050,41 @@ fn test_highly_concurrent_commits_and_reveals_with_multiple_hotkeys() {
     })
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_reveal_period_shifts_reveal_window --exact --show-output --nocapture
+#[test]
+fn test_reveal_period_shifts_reveal_window() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 5;
+        add_network(netuid, tempo, 0);
+        System::set_block_number(0);
+
+        for reveal_period in [1, 2, 3] {
+            SubtensorModule::set_reveal_period(netuid, reveal_period);
+            assert_eq!(SubtensorModule::get_reveal_period(netuid), reveal_period);
+
+            // A commit at block 0 is in epoch 0, so each extra epoch of reveal period pushes the
+            // window back by one tempo.
+            let (first_reveal_block, last_reveal_block) =
+                SubtensorModule::get_reveal_blocks(netuid, 0);
+            assert_eq!(first_reveal_block, reveal_period * 6 - 2);
+            assert_eq!(last_reveal_block, first_reveal_block + u64::from(tempo));
+
+            System::set_block_number(first_reveal_block - 1);
+            assert!(!SubtensorModule::is_reveal_block_range(netuid, 0));
+            System::set_block_number(first_reveal_block);
+            assert!(SubtensorModule::is_reveal_block_range(netuid, 0));
+            System::set_block_number(last_reveal_block);
+            assert!(SubtensorModule::is_reveal_block_range(netuid, 0));
+            assert!(!SubtensorModule::is_commit_expired(netuid, 0));
+            System::set_block_number(last_reveal_block + 1);
+            assert!(!SubtensorModule::is_reveal_block_range(netuid, 0));
+            assert!(SubtensorModule::is_commit_expired(netuid, 0));
+            System::set_block_number(0);
+        }
+    });
+}
+
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_get_reveal_blocks --exact --show-output --nocapture
 #[test]
 fn test_get_reveal_blocks() {
@@ -5938,3 +5973,88 @@ fn test_reveal_crv3_commits_max_neurons() {
         );
     });
 }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_get_weight_ages --exact --show-output --nocapture
+#[test]
+fn test_get_weight_ages() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(55);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_min_allowed_weights(netuid, 0);
+        SubtensorModule::set_max_weight_limit(netuid, u16::MAX);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(66), 0);
+        register_ok_neuron(netuid, U256::from(1), U256::from(1), 65555);
+        let uid: u16 =
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).expect("Not registered.");
+        SubtensorModule::set_validator_permit_for_uid(netuid, uid, true);
+
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            vec![1],
+            vec![u16::MAX],
+            0
+        ));
+        // Only the validator is reported, and its weights were just set.
+        assert_eq!(SubtensorModule::get_weight_ages(netuid), vec![(uid, 0)]);
+
+        // The age grows with every block.
+        System::set_block_number(System::block_number() + 5);
+        assert_eq!(SubtensorModule::get_weight_ages(netuid), vec![(uid, 5)]);
+
+        // And resets when the validator sets weights again.
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            vec![1],
+            vec![u16::MAX],
+            0
+        ));
+        assert_eq!(SubtensorModule::get_weight_ages(netuid), vec![(uid, 0)]);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_get_last_weight_set_block --exact --show-output --nocapture
+#[test]
+fn test_get_last_weight_set_block() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(55);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_min_allowed_weights(netuid, 0);
+        SubtensorModule::set_max_weight_limit(netuid, u16::MAX);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(66), 0);
+        register_ok_neuron(netuid, U256::from(1), U256::from(1), 65555);
+        let uid: u16 =
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).expect("Not registered.");
+        SubtensorModule::set_validator_permit_for_uid(netuid, uid, true);
+
+        System::set_block_number(System::block_number() + 7);
+        let set_block = SubtensorModule::get_current_block_as_u64();
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            vec![1],
+            vec![u16::MAX],
+            0
+        ));
+        System::set_block_number(set_block + 3);
+        assert_eq!(
+            SubtensorModule::get_last_weight_set_block(netuid, &hotkey),
+            Some(set_block)
+        );
+
+        // Unregistered hotkeys, or hotkeys on another subnet, have no entry.
+        assert_eq!(
+            SubtensorModule::get_last_weight_set_block(netuid, &U256::from(77)),
+            None
+        );
+        assert_eq!(
+            SubtensorModule::get_last_weight_set_block(netuid + 1, &hotkey),
+            None
+        );
+    });
+}
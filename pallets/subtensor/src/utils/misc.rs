@@ -35,9 +35,13 @@ impl<T: Config> Pallet<T> {
     // ========================
     // ==== Global Setters ====
     // ========================
+    /// Sets the tempo of a subnet, clamping its epoch offset so it stays within the new tempo.
     pub fn set_tempo(netuid: u16, tempo: u16) {
         Tempo::<T>::insert(netuid, tempo);
         Self::deposit_event(Event::TempoSet(netuid, tempo));
+        if Self::get_epoch_offset(netuid) > u64::from(tempo) {
+            Self::set_epoch_offset(netuid, u64::from(tempo));
+        }
     }
     pub fn set_last_adjustment_block(netuid: u16, last_adjustment_block: u64) {
         LastAdjustmentBlock::<T>::insert(netuid, last_adjustment_block);
@@ -48,6 +52,9 @@ impl<T: Config> Pallet<T> {
     pub fn set_registrations_this_block(netuid: u16, registrations_this_block: u16) {
         RegistrationsThisBlock::<T>::insert(netuid, registrations_this_block);
     }
+    pub fn set_total_registrations_this_block(total_registrations_this_block: u16) {
+        TotalRegistrationsThisBlock::<T>::put(total_registrations_this_block);
+    }
     pub fn set_last_mechanism_step_block(netuid: u16, last_mechanism_step_block: u64) {
         LastMechansimStepBlock::<T>::insert(netuid, last_mechanism_step_block);
     }
@@ -213,6 +220,47 @@ impl<T: Config> Pallet<T> {
     pub fn get_tempo(netuid: u16) -> u16 {
         Tempo::<T>::get(netuid)
     }
+    pub fn get_epoch_offset(netuid: u16) -> u64 {
+        EpochOffset::<T>::get(netuid)
+    }
+    pub fn set_epoch_offset(netuid: u16, epoch_offset: u64) {
+        EpochOffset::<T>::insert(netuid, epoch_offset);
+        Self::deposit_event(Event::EpochOffsetSet(netuid, epoch_offset));
+    }
+    pub fn get_min_tempo() -> u16 {
+        MinTempo::<T>::get()
+    }
+    /// Returns whether a subnet can run at the tempo: at least `MinTempo`, or 0, which never runs
+    /// epochs.
+    pub fn is_tempo_allowed(tempo: u16) -> bool {
+        tempo == 0 || tempo >= Self::get_min_tempo()
+    }
+    pub fn set_min_tempo(min_tempo: u16) {
+        MinTempo::<T>::put(min_tempo);
+        Self::deposit_event(Event::MinTempoSet(min_tempo));
+    }
+    pub fn get_emission_smoothing_blocks(netuid: u16) -> u64 {
+        EmissionSmoothingBlocks::<T>::get(netuid)
+    }
+    pub fn set_emission_smoothing_blocks(netuid: u16, smoothing_blocks: u64) {
+        EmissionSmoothingBlocks::<T>::insert(netuid, smoothing_blocks);
+        Self::deposit_event(Event::EmissionSmoothingBlocksSet(netuid, smoothing_blocks));
+    }
+    /// Returns the total TAO bought and sold on the subnet since it was registered.
+    pub fn get_subnet_volume(netuid: u16) -> u128 {
+        SubnetVolume::<T>::get(netuid)
+    }
+    pub fn set_subnet_volume(netuid: u16, volume: u128) {
+        SubnetVolume::<T>::insert(netuid, volume);
+        Self::deposit_event(Event::SubnetVolumeSet(netuid, volume));
+    }
+    pub fn get_staking_fee_volume_tiers() -> Vec<(u128, u64)> {
+        StakingFeeVolumeTiers::<T>::get()
+    }
+    pub fn set_staking_fee_volume_tiers(tiers: Vec<(u128, u64)>) {
+        StakingFeeVolumeTiers::<T>::put(tiers.clone());
+        Self::deposit_event(Event::StakingFeeVolumeTiersSet(tiers));
+    }
     pub fn get_pending_emission(netuid: u16) -> u64 {
         PendingEmission::<T>::get(netuid)
     }
@@ -228,6 +276,9 @@ impl<T: Config> Pallet<T> {
     pub fn get_registrations_this_block(netuid: u16) -> u16 {
         RegistrationsThisBlock::<T>::get(netuid)
     }
+    pub fn get_total_registrations_this_block() -> u16 {
+        TotalRegistrationsThisBlock::<T>::get()
+    }
     pub fn get_last_mechanism_step_block(netuid: u16) -> u64 {
         LastMechansimStepBlock::<T>::get(netuid)
     }
@@ -411,6 +462,14 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::AdjustmentAlphaSet(netuid, adjustment_alpha));
     }
 
+    pub fn get_difficulty_algo(netuid: u16) -> u8 {
+        DifficultyAlgo::<T>::get(netuid)
+    }
+    pub fn set_difficulty_algo(netuid: u16, difficulty_algo: u8) {
+        DifficultyAlgo::<T>::insert(netuid, difficulty_algo);
+        Self::deposit_event(Event::DifficultyAlgoSet(netuid, difficulty_algo));
+    }
+
     pub fn set_validator_prune_len(netuid: u16, validator_prune_len: u64) {
         ValidatorPruneLen::<T>::insert(netuid, validator_prune_len);
         Self::deposit_event(Event::ValidatorPruneLenSet(netuid, validator_prune_len));
@@ -491,6 +550,29 @@ impl<T: Config> Pallet<T> {
         ActivityCutoff::<T>::insert(netuid, activity_cutoff);
         Self::deposit_event(Event::ActivityCutoffSet(netuid, activity_cutoff));
     }
+    pub fn get_max_weight_age(netuid: u16) -> u64 {
+        MaxWeightAge::<T>::get(netuid)
+    }
+    pub fn set_max_weight_age(netuid: u16, max_weight_age: u64) {
+        MaxWeightAge::<T>::insert(netuid, max_weight_age);
+        Self::deposit_event(Event::MaxWeightAgeSet(netuid, max_weight_age));
+    }
+    pub fn get_validator_emission_ratio(netuid: u16) -> Option<u16> {
+        ValidatorEmissionRatio::<T>::get(netuid)
+    }
+    /// Returns the share of the subnet's emission paid to validators, half when no ratio is set.
+    pub fn get_float_validator_emission_share(netuid: u16) -> I96F32 {
+        Self::get_validator_emission_ratio(netuid).map_or(
+            I96F32::saturating_from_num(0.5),
+            |ratio| {
+                I96F32::saturating_from_num(ratio).safe_div(I96F32::saturating_from_num(u16::MAX))
+            },
+        )
+    }
+    pub fn set_validator_emission_ratio(netuid: u16, ratio: u16) {
+        ValidatorEmissionRatio::<T>::insert(netuid, ratio);
+        Self::deposit_event(Event::ValidatorEmissionRatioSet(netuid, ratio));
+    }
 
     // Registration Toggle utils
     pub fn get_network_registration_allowed(netuid: u16) -> bool {
@@ -591,6 +673,31 @@ impl<T: Config> Pallet<T> {
             max_registrations_per_block,
         ));
     }
+    pub fn get_max_total_registrations_per_block() -> u16 {
+        MaxTotalRegistrationsPerBlock::<T>::get()
+    }
+    pub fn set_max_total_registrations_per_block(max_total_registrations_per_block: u16) {
+        MaxTotalRegistrationsPerBlock::<T>::put(max_total_registrations_per_block);
+        Self::deposit_event(Event::MaxTotalRegistrationsPerBlockSet(
+            max_total_registrations_per_block,
+        ));
+    }
+
+    pub fn get_min_subnet_emission() -> u64 {
+        MinSubnetEmission::<T>::get()
+    }
+    pub fn set_min_subnet_emission(min_subnet_emission: u64) {
+        MinSubnetEmission::<T>::put(min_subnet_emission);
+        Self::deposit_event(Event::MinSubnetEmissionSet(min_subnet_emission));
+    }
+
+    pub fn get_max_subnet_emission() -> u64 {
+        MaxSubnetEmission::<T>::get()
+    }
+    pub fn set_max_subnet_emission(max_subnet_emission: u64) {
+        MaxSubnetEmission::<T>::put(max_subnet_emission);
+        Self::deposit_event(Event::MaxSubnetEmissionSet(max_subnet_emission));
+    }
 
     pub fn get_subnet_owner(netuid: u16) -> T::AccountId {
         SubnetOwner::<T>::get(netuid)
@@ -602,10 +709,44 @@ impl<T: Config> Pallet<T> {
         I96F32::saturating_from_num(SubnetOwnerCut::<T>::get())
             .safe_div(I96F32::saturating_from_num(u16::MAX))
     }
+    /// Returns the owner cut accumulated on the subnet so far this tempo, which is paid out to
+    /// the subnet owner at the next drain.
+    pub fn get_pending_owner_cut(netuid: u16) -> u64 {
+        PendingOwnerCut::<T>::get(netuid)
+    }
     pub fn set_subnet_owner_cut(subnet_owner_cut: u16) {
         SubnetOwnerCut::<T>::set(subnet_owner_cut);
         Self::deposit_event(Event::SubnetOwnerCutSet(subnet_owner_cut));
     }
+    pub fn get_min_owner_cut() -> u16 {
+        MinOwnerCut::<T>::get()
+    }
+    pub fn set_min_owner_cut(min_owner_cut: u16) {
+        MinOwnerCut::<T>::put(min_owner_cut);
+        Self::deposit_event(Event::MinOwnerCutSet(min_owner_cut));
+    }
+    pub fn get_max_owner_cut() -> u16 {
+        MaxOwnerCut::<T>::get()
+    }
+    pub fn set_max_owner_cut(max_owner_cut: u16) {
+        MaxOwnerCut::<T>::put(max_owner_cut);
+        Self::deposit_event(Event::MaxOwnerCutSet(max_owner_cut));
+    }
+    pub fn get_max_total_issuance() -> u64 {
+        MaxTotalIssuance::<T>::get()
+    }
+    pub fn set_max_total_issuance(max_total_issuance: u64) {
+        MaxTotalIssuance::<T>::put(max_total_issuance);
+        Self::deposit_event(Event::MaxTotalIssuanceSet(max_total_issuance));
+    }
+
+    pub fn get_min_stake_amount() -> u64 {
+        MinStakeAmount::<T>::get()
+    }
+    pub fn set_min_stake_amount(min_stake_amount: u64) {
+        MinStakeAmount::<T>::put(min_stake_amount);
+        Self::deposit_event(Event::MinStakeAmountSet(min_stake_amount));
+    }
 
     pub fn get_owned_hotkeys(coldkey: &T::AccountId) -> Vec<T::AccountId> {
         OwnedHotkeys::<T>::get(coldkey)
@@ -639,6 +780,21 @@ impl<T: Config> Pallet<T> {
         T::SenateMembers::is_member(hotkey)
     }
 
+    pub fn get_proposal_min_stake() -> u64 {
+        ProposalMinStake::<T>::get()
+    }
+
+    pub fn set_proposal_min_stake(min_stake: u64) {
+        ProposalMinStake::<T>::put(min_stake);
+    }
+
+    /// Returns true if the account may submit a governance proposal: senate members always can,
+    /// anyone else needs at least `ProposalMinStake` staked on the account as a hotkey.
+    pub fn can_submit_proposal(account: &T::AccountId) -> bool {
+        Self::is_senate_member(account)
+            || Self::get_total_stake_for_hotkey(account) >= Self::get_proposal_min_stake()
+    }
+
     pub fn do_set_senate_required_stake_perc(
         origin: T::RuntimeOrigin,
         required_percent: u64,
@@ -688,6 +844,14 @@ impl<T: Config> Pallet<T> {
         LiquidAlphaOn::<T>::get(netuid)
     }
 
+    pub fn set_prune_newest_on_tie(netuid: u16, enabled: bool) {
+        PruneNewestOnTie::<T>::insert(netuid, enabled);
+    }
+
+    pub fn get_prune_newest_on_tie(netuid: u16) -> bool {
+        PruneNewestOnTie::<T>::get(netuid)
+    }
+
     /// Set the duration for coldkey swap
     ///
     /// # Arguments
@@ -8,6 +8,7 @@ pub enum TransactionType {
     Unknown,
     RegisterNetwork,
     SetWeightsVersionKey,
+    SetNeuronCertificate,
 }
 
 /// Implement conversion from TransactionType to u16
@@ -19,6 +20,7 @@ impl From<TransactionType> for u16 {
             TransactionType::Unknown => 2,
             TransactionType::RegisterNetwork => 3,
             TransactionType::SetWeightsVersionKey => 4,
+            TransactionType::SetNeuronCertificate => 5,
         }
     }
 }
@@ -31,10 +33,37 @@ impl From<u16> for TransactionType {
             1 => TransactionType::SetChildkeyTake,
             3 => TransactionType::RegisterNetwork,
             4 => TransactionType::SetWeightsVersionKey,
+            5 => TransactionType::SetNeuronCertificate,
             _ => TransactionType::Unknown,
         }
     }
 }
+/// Rate limited operations that can be inspected through the runtime API
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum RateLimitedOperation {
+    SetWeights,
+    ServeAxon,
+    ServePrometheus,
+    SetChildren,
+    SetChildkeyTake,
+}
+
+/// Implement conversion from u8 to RateLimitedOperation
+impl TryFrom<u8> for RateLimitedOperation {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(RateLimitedOperation::SetWeights),
+            1 => Ok(RateLimitedOperation::ServeAxon),
+            2 => Ok(RateLimitedOperation::ServePrometheus),
+            3 => Ok(RateLimitedOperation::SetChildren),
+            4 => Ok(RateLimitedOperation::SetChildkeyTake),
+            _ => Err(()),
+        }
+    }
+}
+
 impl<T: Config> Pallet<T> {
     // ========================
     // ==== Rate Limiting =====
@@ -56,6 +85,7 @@ impl<T: Config> Pallet<T> {
         match tx_type {
             TransactionType::SetWeightsVersionKey => (Tempo::<T>::get(netuid) as u64)
                 .saturating_mul(WeightsVersionKeyRateLimit::<T>::get()),
+            TransactionType::SetNeuronCertificate => Self::get_serving_rate_limit(netuid),
             _ => Self::get_rate_limit(tx_type),
         }
     }
@@ -133,6 +163,55 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Returns `(last_block, limit_blocks, blocks_remaining)` for the operation done by the hotkey
+    /// on the subnet, where `blocks_remaining` is 0 once the operation can be submitted again.
+    pub fn get_rate_limit_info(
+        hotkey: &T::AccountId,
+        netuid: u16,
+        operation: RateLimitedOperation,
+    ) -> (u64, u64, u64) {
+        let (last_block, limit) = match operation {
+            RateLimitedOperation::SetWeights => (
+                Self::get_uid_for_net_and_hotkey(netuid, hotkey)
+                    .map(|uid| Self::get_last_update_for_uid(netuid, uid))
+                    .unwrap_or_default(),
+                Self::get_weights_set_rate_limit(netuid),
+            ),
+            RateLimitedOperation::ServeAxon => (
+                Self::get_axon_info(netuid, hotkey).block,
+                Self::get_serving_rate_limit(netuid),
+            ),
+            RateLimitedOperation::ServePrometheus => (
+                Self::get_prometheus_info(netuid, hotkey).block,
+                Self::get_serving_rate_limit(netuid),
+            ),
+            RateLimitedOperation::SetChildren => {
+                let tx_type = TransactionType::SetChildren;
+                (
+                    Self::get_last_transaction_block_on_subnet(hotkey, netuid, &tx_type),
+                    Self::get_rate_limit_on_subnet(&tx_type, netuid),
+                )
+            }
+            RateLimitedOperation::SetChildkeyTake => {
+                let tx_type = TransactionType::SetChildkeyTake;
+                (
+                    Self::get_last_transaction_block_on_subnet(hotkey, netuid, &tx_type),
+                    Self::get_rate_limit_on_subnet(&tx_type, netuid),
+                )
+            }
+        };
+
+        // Never done before, so there's nothing to wait for.
+        let blocks_remaining = if last_block == 0 {
+            0
+        } else {
+            let elapsed = Self::get_current_block_as_u64().saturating_sub(last_block);
+            limit.saturating_sub(elapsed)
+        };
+
+        (last_block, limit, blocks_remaining)
+    }
+
     pub fn set_last_tx_block(key: &T::AccountId, block: u64) {
         LastTxBlock::<T>::insert(key, block)
     }
@@ -0,0 +1,95 @@
+use core::marker::PhantomData;
+
+use pallet_evm::PrecompileHandle;
+use precompile_utils::{EvmResult, prelude::Address};
+use sp_core::{H160, H256, crypto::AccountId32};
+
+use crate::{PrecompileExt, extensions::evm_to_substrate};
+
+pub(crate) struct AddressMappingPrecompile<R>(PhantomData<R>);
+
+impl<R> PrecompileExt<R::AccountId> for AddressMappingPrecompile<R>
+where
+    R: frame_system::Config,
+    R::AccountId: From<[u8; 32]>,
+{
+    const INDEX: u64 = 2054;
+}
+
+#[precompile_utils::precompile]
+impl<R> AddressMappingPrecompile<R>
+where
+    R: frame_system::Config,
+{
+    #[precompile::public("addressToAccount(address)")]
+    #[precompile::view]
+    fn address_to_account(_: &mut impl PrecompileHandle, address: Address) -> EvmResult<H256> {
+        let account_id: [u8; 32] = evm_to_substrate(address.0).into();
+        Ok(H256::from(account_id))
+    }
+
+    /// The hashed mapping is one-way, so the inverse can't be computed in general. This returns
+    /// the address the account is truncated to when it acts as an EVM origin (the first 20
+    /// bytes), and flags whether that address also maps back to the account.
+    #[precompile::public("accountToAddress(bytes32)")]
+    #[precompile::view]
+    fn account_to_address(
+        _: &mut impl PrecompileHandle,
+        account: H256,
+    ) -> EvmResult<(Address, bool)> {
+        let address = truncate_account(account);
+        let exact = evm_to_substrate(address) == AccountId32::from(account.0);
+        Ok((Address(address), exact))
+    }
+}
+
+fn truncate_account(account: H256) -> H160 {
+    let mut address = H160::zero();
+    address
+        .as_bytes_mut()
+        .iter_mut()
+        .zip(account.as_bytes())
+        .for_each(|(a, b)| *a = *b);
+    address
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use pallet_evm::{AddressMapping, HashedAddressMapping};
+    use sp_core::blake2_256;
+    use sp_runtime::traits::BlakeTwo256;
+
+    #[test]
+    fn address_to_account_matches_hashed_address_mapping() {
+        for address in [
+            H160::zero(),
+            H160::from_low_u64_be(2051),
+            H160::repeat_byte(0xab),
+        ] {
+            let mut data = b"evm:".to_vec();
+            data.extend_from_slice(address.as_bytes());
+
+            let account_id = evm_to_substrate(address);
+            assert_eq!(
+                account_id,
+                HashedAddressMapping::<BlakeTwo256>::into_account_id(address)
+            );
+            assert_eq!(<[u8; 32]>::from(account_id), blake2_256(&data));
+        }
+    }
+    #[test]
+    fn account_to_address_truncates_account() {
+        let mut account = [0xcd; 32];
+        account.iter_mut().take(20).for_each(|b| *b = 0xab);
+        let address = truncate_account(H256::from(account));
+        assert_eq!(address, H160::repeat_byte(0xab));
+
+        // The truncated address of a mapped account doesn't map back to it
+        let account = evm_to_substrate(H160::repeat_byte(0x11));
+        let address = truncate_account(H256::from(<[u8; 32]>::from(account.clone())));
+        assert_ne!(address, H160::repeat_byte(0x11));
+        assert_ne!(evm_to_substrate(address), account);
+    }
+}
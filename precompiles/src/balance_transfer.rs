@@ -1,8 +1,11 @@
 use core::marker::PhantomData;
 
-use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
+    traits::Get,
+};
 use frame_system::RawOrigin;
-use pallet_evm::PrecompileHandle;
+use pallet_evm::{AddressMapping, ExitError, PrecompileFailure, PrecompileHandle};
 use precompile_utils::EvmResult;
 use sp_core::{H256, U256};
 use sp_runtime::traits::{Dispatchable, StaticLookup, UniqueSaturatedInto};
@@ -22,6 +25,7 @@ where
         + Dispatchable<PostInfo = PostDispatchInfo>,
     <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
     <R as pallet_balances::Config>::Balance: TryFrom<U256>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
 {
     const INDEX: u64 = 2048;
 }
@@ -38,6 +42,7 @@ where
         + Dispatchable<PostInfo = PostDispatchInfo>,
     <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
     <R as pallet_balances::Config>::Balance: TryFrom<U256>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
 {
     #[precompile::public("transfer(bytes32)")]
     #[precompile::payable]
@@ -57,4 +62,21 @@ where
 
         handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(Self::account_id()))
     }
+
+    /// Same as `transfer`, but fails instead of reaping the caller. The value is moved out of
+    /// the caller before the precompile runs, so the check is done on what is left behind.
+    #[precompile::public("transferKeepAlive(bytes32)")]
+    #[precompile::payable]
+    fn transfer_keep_alive(handle: &mut impl PrecompileHandle, address: H256) -> EvmResult<()> {
+        let caller = handle.caller_account_id::<R>();
+        let existential_deposit = <R as pallet_balances::Config>::ExistentialDeposit::get();
+
+        if pallet_balances::Pallet::<R>::free_balance(&caller) < existential_deposit {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("transfer would kill the caller account".into()),
+            });
+        }
+
+        Self::transfer(handle, address)
+    }
 }
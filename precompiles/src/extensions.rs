@@ -2,16 +2,22 @@ extern crate alloc;
 
 use alloc::format;
 
-use frame_support::dispatch::{GetDispatchInfo, Pays, PostDispatchInfo};
+use frame_support::{
+    dispatch::{GetDispatchInfo, Pays, PostDispatchInfo},
+    traits::Get,
+};
 use frame_system::RawOrigin;
 use pallet_admin_utils::{PrecompileEnable, PrecompileEnum};
 use pallet_evm::{
-    AddressMapping, BalanceConverter, ExitError, GasWeightMapping, Precompile, PrecompileFailure,
-    PrecompileHandle, PrecompileResult,
+    AddressMapping, BalanceConverter, ExitError, ExitRevert, GasWeightMapping,
+    HashedAddressMapping, Precompile, PrecompileFailure, PrecompileHandle, PrecompileResult,
+};
+use precompile_utils::{EvmResult, solidity::codec::Writer};
+use sp_core::{H160, U256, crypto::AccountId32, keccak_256};
+use sp_runtime::{
+    DispatchError,
+    traits::{BlakeTwo256, Dispatchable, StaticLookup, UniqueSaturatedInto},
 };
-use precompile_utils::EvmResult;
-use sp_core::{H160, U256, blake2_256};
-use sp_runtime::traits::Dispatchable;
 use sp_std::vec::Vec;
 
 pub(crate) trait PrecompileHandleExt: PrecompileHandle {
@@ -37,6 +43,19 @@ pub(crate) trait PrecompileHandleExt: PrecompileHandle {
         )
     }
 
+    /// Records the gas cost of `num_reads` storage reads. Read-only methods don't go through
+    /// `try_dispatch_runtime_call`, so they have to meter themselves.
+    fn record_read_cost<R>(&mut self, num_reads: u64) -> EvmResult<()>
+    where
+        R: frame_system::Config + pallet_evm::Config,
+    {
+        let weight = <R as frame_system::Config>::DbWeight::get().reads(num_reads);
+        let cost = <R as pallet_evm::Config>::GasWeightMapping::weight_to_gas(weight);
+        self.record_cost(cost)?;
+
+        Ok(())
+    }
+
     /// Dispatches a runtime call, but also checks and records the gas costs.
     fn try_dispatch_runtime_call<R, Call>(
         &mut self,
@@ -97,6 +116,13 @@ pub(crate) trait PrecompileHandleExt: PrecompileHandle {
             }
             Err(e) => {
                 log::error!("Dispatch failed. Error: {:?}", e);
+                if let Some(output) = dispatch_error_revert_data(&e.error) {
+                    log::warn!("Returning error PrecompileFailure::Revert");
+                    return Err(PrecompileFailure::Revert {
+                        exit_status: ExitRevert::Reverted,
+                        output,
+                    });
+                }
                 log::warn!("Returning error PrecompileFailure::Error");
                 Err(PrecompileFailure::Error {
                     exit_status: ExitError::Other(
@@ -110,23 +136,78 @@ pub(crate) trait PrecompileHandleExt: PrecompileHandle {
 
 impl<T> PrecompileHandleExt for T where T: PrecompileHandle {}
 
+/// Signature of the Solidity custom error a failed pallet call reverts with, so contracts can
+/// decode the pallet and error index of the failure.
+const DISPATCH_ERROR_SIGNATURE: &[u8] = b"DispatchError(uint8,uint8)";
+
+/// Encodes a pallet error as `DispatchError(uint8 palletIndex, uint8 errorIndex)` revert data.
+/// Returns `None` for errors that don't come from a pallet.
+pub(crate) fn dispatch_error_revert_data(error: &DispatchError) -> Option<Vec<u8>> {
+    let DispatchError::Module(module_error) = error else {
+        return None;
+    };
+    let [a, b, c, d, ..] = keccak_256(DISPATCH_ERROR_SIGNATURE);
+    let error_index = module_error.error.first().copied().unwrap_or_default();
+
+    Some(
+        Writer::new_with_selector(u32::from_be_bytes([a, b, c, d]))
+            .write(module_error.index)
+            .write(error_index)
+            .build(),
+    )
+}
+
+/// Maps an EVM address to its substrate account, i.e. `blake2_256(b"evm:" ++ address)`.
+pub(crate) fn evm_to_substrate(address: H160) -> AccountId32 {
+    HashedAddressMapping::<BlakeTwo256>::into_account_id(address)
+}
+
 pub(crate) trait PrecompileExt<AccountId: From<[u8; 32]>>: Precompile {
     const INDEX: u64;
 
     // ss58 public key i.e., the contract sends funds it received to the destination address from
     // the method parameter.
     fn account_id() -> AccountId {
-        let hash = H160::from_low_u64_be(Self::INDEX);
-        let prefix = b"evm:";
+        let account_id: [u8; 32] = evm_to_substrate(H160::from_low_u64_be(Self::INDEX)).into();
+        account_id.into()
+    }
 
-        // Concatenate prefix and ethereum address
-        let mut combined = Vec::new();
-        combined.extend_from_slice(prefix);
-        combined.extend_from_slice(hash.as_bytes());
+    /// Transfers the value received by the precompile back to the caller, so that a call
+    /// dispatched with the caller's origin can spend it.
+    fn transfer_back_to_caller<R>(account_id: &AccountId, amount: U256) -> EvmResult<()>
+    where
+        R: frame_system::Config<AccountId = AccountId>
+            + pallet_evm::Config
+            + pallet_balances::Config,
+        R::RuntimeCall: From<pallet_balances::Call<R>>,
+        <R::Lookup as StaticLookup>::Source: From<AccountId>,
+        <R as pallet_balances::Config>::Balance: TryFrom<U256>,
+    {
+        let amount_sub =
+            <R as pallet_evm::Config>::BalanceConverter::into_substrate_balance(amount)
+                .ok_or(ExitError::OutOfFund)?;
+
+        // Create a transfer call from the smart contract to the caller
+        let transfer_call =
+            R::RuntimeCall::from(pallet_balances::Call::<R>::transfer_allow_death {
+                dest: account_id.clone().into(),
+                value: amount_sub.unique_saturated_into(),
+            });
 
-        let hash = blake2_256(&combined);
+        // Execute the transfer
+        let transfer_result = transfer_call.dispatch(RawOrigin::Signed(Self::account_id()).into());
 
-        hash.into()
+        if let Err(dispatch_error) = transfer_result {
+            log::error!(
+                "Transfer back to caller failed. Error: {:?}",
+                dispatch_error
+            );
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("Transfer back to caller failed".into()),
+            });
+        }
+
+        Ok(())
     }
 
     fn try_execute<R>(
@@ -155,7 +236,27 @@ pub(crate) trait PrecompileExt<AccountId: From<[u8; 32]>>: Precompile {
 mod test {
     use super::*;
 
-    use sp_core::crypto::AccountId32;
+    #[test]
+    fn dispatch_error_revert_data_encodes_pallet_error() {
+        use sp_core::H256;
+        use sp_runtime::ModuleError;
+
+        let error = DispatchError::Module(ModuleError {
+            index: 7,
+            error: [12, 0, 0, 0],
+            message: Some("NotEnoughStakeToWithdraw"),
+        });
+
+        let mut expected = keccak_256(b"DispatchError(uint8,uint8)")
+            .into_iter()
+            .take(4)
+            .collect::<Vec<u8>>();
+        expected.extend_from_slice(H256::from_low_u64_be(7).as_bytes());
+        expected.extend_from_slice(H256::from_low_u64_be(12).as_bytes());
+
+        assert_eq!(dispatch_error_revert_data(&error), Some(expected));
+        assert_eq!(dispatch_error_revert_data(&DispatchError::BadOrigin), None);
+    }
 
     #[test]
     fn ss58_address_from_index_works() {
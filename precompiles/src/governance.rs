@@ -0,0 +1,64 @@
+use core::marker::PhantomData;
+
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_system::RawOrigin;
+use pallet_evm::{AddressMapping, ExitError, PrecompileFailure, PrecompileHandle};
+use pallet_subtensor::MemberManagement;
+use precompile_utils::EvmResult;
+use sp_core::H256;
+use sp_runtime::traits::Dispatchable;
+
+use crate::{PrecompileExt, PrecompileHandleExt};
+
+pub struct GovernancePrecompile<R>(PhantomData<R>);
+
+impl<R> PrecompileExt<R::AccountId> for GovernancePrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+    R::AccountId: From<[u8; 32]>,
+    <R as frame_system::Config>::Hash: From<H256>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    const INDEX: u64 = 2055;
+}
+
+#[precompile_utils::precompile]
+impl<R> GovernancePrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+    R::AccountId: From<[u8; 32]>,
+    <R as frame_system::Config>::Hash: From<H256>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    /// Votes on a senate proposal. The caller's mapped account votes as both the coldkey and the
+    /// senate hotkey, so it has to be a senate member owning itself.
+    #[precompile::public("voteProposal(bytes32,uint32,bool)")]
+    fn vote_proposal(
+        handle: &mut impl PrecompileHandle,
+        proposal_hash: H256,
+        index: u32,
+        approve: bool,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        if !<R as pallet_subtensor::Config>::SenateMembers::is_member(&account_id) {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("caller is not a senate member".into()),
+            });
+        }
+
+        let call = pallet_subtensor::Call::<R>::vote {
+            hotkey: account_id.clone(),
+            proposal: proposal_hash.into(),
+            index,
+            approve,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+    }
+}
@@ -12,24 +12,28 @@ use pallet_evm::{
 use pallet_evm_precompile_modexp::Modexp;
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
-use sp_core::{H160, U256, crypto::ByteArray};
+use sp_core::{H160, H256, U256, crypto::ByteArray};
 use sp_runtime::traits::Dispatchable;
 use sp_runtime::traits::StaticLookup;
 use subtensor_runtime_common::ProxyType;
 
 use pallet_admin_utils::PrecompileEnum;
 
+use crate::address_mapping::*;
 use crate::balance_transfer::*;
 use crate::ed25519::*;
 use crate::extensions::*;
+use crate::governance::*;
 use crate::metagraph::*;
 use crate::neuron::*;
 use crate::staking::*;
 use crate::subnet::*;
 
+mod address_mapping;
 mod balance_transfer;
 mod ed25519;
 mod extensions;
+mod governance;
 mod metagraph;
 mod neuron;
 mod staking;
@@ -46,6 +50,7 @@ where
         + pallet_subtensor::Config
         + pallet_proxy::Config<ProxyType = ProxyType>,
     R::AccountId: From<[u8; 32]> + ByteArray,
+    <R as frame_system::Config>::Hash: From<H256>,
     <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
         + From<pallet_proxy::Call<R>>
         + From<pallet_balances::Call<R>>
@@ -70,6 +75,7 @@ where
         + pallet_subtensor::Config
         + pallet_proxy::Config<ProxyType = ProxyType>,
     R::AccountId: From<[u8; 32]> + ByteArray,
+    <R as frame_system::Config>::Hash: From<H256>,
     <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
         + From<pallet_proxy::Call<R>>
         + From<pallet_balances::Call<R>>
@@ -84,7 +90,7 @@ where
         Self(Default::default())
     }
 
-    pub fn used_addresses() -> [H160; 14] {
+    pub fn used_addresses() -> [H160; 16] {
         [
             hash(1),
             hash(2),
@@ -100,8 +106,17 @@ where
             hash(MetagraphPrecompile::<R>::INDEX),
             hash(NeuronPrecompile::<R>::INDEX),
             hash(StakingPrecompileV2::<R>::INDEX),
+            hash(AddressMappingPrecompile::<R>::INDEX),
+            hash(GovernancePrecompile::<R>::INDEX),
         ]
     }
+
+    /// Returns the precompile address that maps to the given substrate account, if any.
+    pub fn address_of(account_id: &R::AccountId) -> Option<H160> {
+        Self::used_addresses().into_iter().find(|address| {
+            &<R as pallet_evm::Config>::AddressMapping::into_account_id(*address) == account_id
+        })
+    }
 }
 impl<R> PrecompileSet for Precompiles<R>
 where
@@ -112,6 +127,7 @@ where
         + pallet_subtensor::Config
         + pallet_proxy::Config<ProxyType = ProxyType>,
     R::AccountId: From<[u8; 32]> + ByteArray,
+    <R as frame_system::Config>::Hash: From<H256>,
     <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
         + From<pallet_proxy::Call<R>>
         + From<pallet_balances::Call<R>>
@@ -158,6 +174,12 @@ where
             a if a == hash(NeuronPrecompile::<R>::INDEX) => {
                 NeuronPrecompile::<R>::try_execute::<R>(handle, PrecompileEnum::Neuron)
             }
+            a if a == hash(AddressMappingPrecompile::<R>::INDEX) => {
+                Some(AddressMappingPrecompile::<R>::execute(handle))
+            }
+            a if a == hash(GovernancePrecompile::<R>::INDEX) => {
+                GovernancePrecompile::<R>::try_execute::<R>(handle, PrecompileEnum::Governance)
+            }
             _ => None,
         }
     }
@@ -1,12 +1,14 @@
 use alloc::string::String;
+use alloc::vec::Vec;
 use core::marker::PhantomData;
+use core::ops::Range;
 
 use fp_evm::{ExitError, PrecompileFailure, PrecompileHandle};
 use pallet_subtensor::AxonInfo as SubtensorModuleAxonInfo;
 use precompile_utils::{EvmResult, solidity::Codec};
-use sp_core::{ByteArray, H256};
+use sp_core::{ByteArray, H256, U256};
 
-use crate::PrecompileExt;
+use crate::{PrecompileExt, PrecompileHandleExt};
 
 pub struct MetagraphPrecompile<R>(PhantomData<R>);
 
@@ -21,18 +23,24 @@ where
 #[precompile_utils::precompile]
 impl<R> MetagraphPrecompile<R>
 where
-    R: frame_system::Config + pallet_subtensor::Config,
-    R::AccountId: ByteArray,
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+    R::AccountId: From<[u8; 32]> + ByteArray,
 {
     #[precompile::public("getUidCount(uint16)")]
     #[precompile::view]
-    fn get_uid_count(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u16> {
+    fn get_uid_count(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u16> {
+        handle.record_read_cost::<R>(1)?;
+
         Ok(pallet_subtensor::SubnetworkN::<R>::get(netuid))
     }
 
     #[precompile::public("getStake(uint16,uint16)")]
     #[precompile::view]
-    fn get_stake(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u64> {
+    fn get_stake(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u64> {
+        // Keys, then the alpha, reserves and mechanism of every subnet
+        let num_subnets = u64::from(pallet_subtensor::TotalNetworks::<R>::get());
+        handle.record_read_cost::<R>(num_subnets.saturating_mul(4).saturating_add(2))?;
+
         let hotkey = pallet_subtensor::Pallet::<R>::get_hotkey_for_net_and_uid(netuid, uid)
             .map_err(|_| PrecompileFailure::Error {
                 exit_status: ExitError::InvalidRange,
@@ -43,15 +51,32 @@ where
         ))
     }
 
+    #[precompile::public("getNeuronStake(uint16,uint16)")]
+    #[precompile::view]
+    fn get_neuron_stake(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        uid: u16,
+    ) -> EvmResult<U256> {
+        // Keys, then the hotkey's alpha on the subnet
+        handle.record_read_cost::<R>(2)?;
+
+        Ok(pallet_subtensor::Pallet::<R>::get_stake_for_uid_and_subnetwork(netuid, uid).into())
+    }
+
     #[precompile::public("getRank(uint16,uint16)")]
     #[precompile::view]
-    fn get_rank(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+    fn get_rank(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+        handle.record_read_cost::<R>(1)?;
+
         Ok(pallet_subtensor::Pallet::<R>::get_rank_for_uid(netuid, uid))
     }
 
     #[precompile::public("getTrust(uint16,uint16)")]
     #[precompile::view]
-    fn get_trust(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+    fn get_trust(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+        handle.record_read_cost::<R>(1)?;
+
         Ok(pallet_subtensor::Pallet::<R>::get_trust_for_uid(
             netuid, uid,
         ))
@@ -59,7 +84,9 @@ where
 
     #[precompile::public("getConsensus(uint16,uint16)")]
     #[precompile::view]
-    fn get_consensus(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+    fn get_consensus(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+        handle.record_read_cost::<R>(1)?;
+
         Ok(pallet_subtensor::Pallet::<R>::get_consensus_for_uid(
             netuid, uid,
         ))
@@ -67,7 +94,9 @@ where
 
     #[precompile::public("getIncentive(uint16,uint16)")]
     #[precompile::view]
-    fn get_incentive(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+    fn get_incentive(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+        handle.record_read_cost::<R>(1)?;
+
         Ok(pallet_subtensor::Pallet::<R>::get_incentive_for_uid(
             netuid, uid,
         ))
@@ -75,7 +104,9 @@ where
 
     #[precompile::public("getDividends(uint16,uint16)")]
     #[precompile::view]
-    fn get_dividends(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+    fn get_dividends(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+        handle.record_read_cost::<R>(1)?;
+
         Ok(pallet_subtensor::Pallet::<R>::get_dividends_for_uid(
             netuid, uid,
         ))
@@ -83,7 +114,9 @@ where
 
     #[precompile::public("getEmission(uint16,uint16)")]
     #[precompile::view]
-    fn get_emission(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u64> {
+    fn get_emission(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u64> {
+        handle.record_read_cost::<R>(1)?;
+
         Ok(pallet_subtensor::Pallet::<R>::get_emission_for_uid(
             netuid, uid,
         ))
@@ -91,7 +124,9 @@ where
 
     #[precompile::public("getVtrust(uint16,uint16)")]
     #[precompile::view]
-    fn get_vtrust(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+    fn get_vtrust(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+        handle.record_read_cost::<R>(1)?;
+
         Ok(pallet_subtensor::Pallet::<R>::get_validator_trust_for_uid(
             netuid, uid,
         ))
@@ -100,10 +135,12 @@ where
     #[precompile::public("getValidatorStatus(uint16,uint16)")]
     #[precompile::view]
     fn get_validator_status(
-        _: &mut impl PrecompileHandle,
+        handle: &mut impl PrecompileHandle,
         netuid: u16,
         uid: u16,
     ) -> EvmResult<bool> {
+        handle.record_read_cost::<R>(1)?;
+
         Ok(pallet_subtensor::Pallet::<R>::get_validator_permit_for_uid(
             netuid, uid,
         ))
@@ -111,7 +148,13 @@ where
 
     #[precompile::public("getLastUpdate(uint16,uint16)")]
     #[precompile::view]
-    fn get_last_update(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u64> {
+    fn get_last_update(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        uid: u16,
+    ) -> EvmResult<u64> {
+        handle.record_read_cost::<R>(1)?;
+
         Ok(pallet_subtensor::Pallet::<R>::get_last_update_for_uid(
             netuid, uid,
         ))
@@ -119,7 +162,9 @@ where
 
     #[precompile::public("getIsActive(uint16,uint16)")]
     #[precompile::view]
-    fn get_is_active(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<bool> {
+    fn get_is_active(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<bool> {
+        handle.record_read_cost::<R>(1)?;
+
         Ok(pallet_subtensor::Pallet::<R>::get_active_for_uid(
             netuid, uid,
         ))
@@ -127,7 +172,9 @@ where
 
     #[precompile::public("getAxon(uint16,uint16)")]
     #[precompile::view]
-    fn get_axon(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<AxonInfo> {
+    fn get_axon(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<AxonInfo> {
+        handle.record_read_cost::<R>(2)?;
+
         let hotkey = pallet_subtensor::Pallet::<R>::get_hotkey_for_net_and_uid(netuid, uid)
             .map_err(|_| PrecompileFailure::Error {
                 exit_status: ExitError::Other("hotkey not found".into()),
@@ -136,9 +183,31 @@ where
         Ok(pallet_subtensor::Pallet::<R>::get_axon_info(netuid, &hotkey).into())
     }
 
+    /// Returns the axon a hotkey serves on a subnet, reverting if the hotkey is not registered on
+    /// the subnet or has not served an axon.
+    #[precompile::public("getAxon(uint16,bytes32)")]
+    #[precompile::view]
+    fn get_axon_by_hotkey(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        hotkey: H256,
+    ) -> EvmResult<AxonInfo> {
+        // Uids, then Axons
+        handle.record_read_cost::<R>(2)?;
+
+        let hotkey = R::AccountId::from(hotkey.0);
+        pallet_subtensor::Pallet::<R>::get_registered_axon_info(netuid, &hotkey)
+            .map(Into::into)
+            .ok_or(PrecompileFailure::Error {
+                exit_status: ExitError::Other("axon not found".into()),
+            })
+    }
+
     #[precompile::public("getHotkey(uint16,uint16)")]
     #[precompile::view]
-    fn get_hotkey(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<H256> {
+    fn get_hotkey(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<H256> {
+        handle.record_read_cost::<R>(1)?;
+
         pallet_subtensor::Pallet::<R>::get_hotkey_for_net_and_uid(netuid, uid)
             .map(|acc| H256::from_slice(acc.as_slice()))
             .map_err(|_| PrecompileFailure::Error {
@@ -148,7 +217,9 @@ where
 
     #[precompile::public("getColdkey(uint16,uint16)")]
     #[precompile::view]
-    fn get_coldkey(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<H256> {
+    fn get_coldkey(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<H256> {
+        handle.record_read_cost::<R>(2)?;
+
         let hotkey = pallet_subtensor::Pallet::<R>::get_hotkey_for_net_and_uid(netuid, uid)
             .map_err(|_| PrecompileFailure::Error {
                 exit_status: ExitError::InvalidRange,
@@ -157,6 +228,112 @@ where
 
         Ok(H256::from_slice(coldkey.as_slice()))
     }
+
+    /// Returns a neuron's state in a single call. The stake is the hotkey's total stake, the same
+    /// value `getStake` returns.
+    #[precompile::public("getNeuron(uint16,uint16)")]
+    #[precompile::view]
+    fn get_neuron(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        uid: u16,
+    ) -> EvmResult<NeuronInfo> {
+        // The number of subnets, then the neuron
+        handle.record_read_cost::<R>(Self::neuron_read_count().saturating_add(1))?;
+
+        Self::neuron_info(netuid, uid).ok_or(PrecompileFailure::Error {
+            exit_status: ExitError::InvalidRange,
+        })
+    }
+
+    /// Returns the neurons with uids `start..start + count` in a single call, or fewer if the
+    /// subnet ends before that. `count` is capped at `MAX_NEURONS_PAGE` and gas is charged per
+    /// returned neuron.
+    #[precompile::public("getNeuronsPaged(uint16,uint16,uint16)")]
+    #[precompile::view]
+    fn get_neurons_paged(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        start: u16,
+        count: u16,
+    ) -> EvmResult<Vec<NeuronInfo>> {
+        if count > MAX_NEURONS_PAGE {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("count exceeds the page limit".into()),
+            });
+        }
+
+        // The subnet size and the number of subnets
+        handle.record_read_cost::<R>(2)?;
+        let uids = neurons_page(
+            start,
+            count,
+            pallet_subtensor::SubnetworkN::<R>::get(netuid),
+        );
+        handle
+            .record_read_cost::<R>(Self::neuron_read_count().saturating_mul(uids.len() as u64))?;
+
+        Ok(uids
+            .filter_map(|uid| Self::neuron_info(netuid, uid))
+            .collect())
+    }
+}
+
+impl<R> MetagraphPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+    R::AccountId: ByteArray,
+{
+    /// Storage reads of assembling one neuron: the neuron info itself, then the alpha, reserves
+    /// and mechanism of every subnet for the hotkey's total stake.
+    fn neuron_read_count() -> u64 {
+        let num_subnets = u64::from(pallet_subtensor::TotalNetworks::<R>::get());
+        num_subnets.saturating_mul(4).saturating_add(17)
+    }
+
+    fn neuron_info(netuid: u16, uid: u16) -> Option<NeuronInfo> {
+        let neuron = pallet_subtensor::Pallet::<R>::get_neuron_lite(netuid, uid)?;
+        let stake = pallet_subtensor::Pallet::<R>::get_total_stake_for_hotkey(&neuron.hotkey);
+
+        Some(NeuronInfo {
+            hotkey: H256::from_slice(neuron.hotkey.as_slice()),
+            coldkey: H256::from_slice(neuron.coldkey.as_slice()),
+            stake,
+            rank: neuron.rank.0,
+            trust: neuron.trust.0,
+            consensus: neuron.consensus.0,
+            incentive: neuron.incentive.0,
+            dividends: neuron.dividends.0,
+            emission: neuron.emission.0,
+            active: neuron.active,
+            validator_permit: neuron.validator_permit,
+            last_update: neuron.last_update.0,
+        })
+    }
+}
+
+/// The most neurons `getNeuronsPaged` returns in one call, bounding its memory and gas.
+const MAX_NEURONS_PAGE: u16 = 64;
+
+/// Returns the uids of a page of `count` neurons starting at `start` on a subnet of `n` neurons.
+fn neurons_page(start: u16, count: u16, n: u16) -> Range<u16> {
+    start.min(n)..start.saturating_add(count).min(n)
+}
+
+#[derive(Codec)]
+struct NeuronInfo {
+    hotkey: H256,
+    coldkey: H256,
+    stake: u64,
+    rank: u16,
+    trust: u16,
+    consensus: u16,
+    incentive: u16,
+    dividends: u16,
+    emission: u64,
+    active: bool,
+    validator_permit: bool,
+    last_update: u64,
 }
 
 #[derive(Codec)]
@@ -181,3 +358,35 @@ impl From<SubtensorModuleAxonInfo> for AxonInfo {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn neurons_pages_reassemble_the_subnet() {
+        let n: u16 = 100;
+        for count in [1, 7, 64] {
+            let mut uids = Vec::new();
+            let mut start: u16 = 0;
+            loop {
+                let page = neurons_page(start, count, n);
+                assert!(page.len() <= count as usize);
+                if page.is_empty() {
+                    break;
+                }
+                start = page.end;
+                uids.extend(page);
+            }
+            assert_eq!(uids, (0..n).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn neurons_page_is_truncated_at_the_subnet_end() {
+        assert_eq!(neurons_page(90, 64, 100), 90..100);
+        assert_eq!(neurons_page(100, 64, 100), 100..100);
+        assert_eq!(neurons_page(200, 64, 100), 100..100);
+        assert_eq!(neurons_page(u16::MAX, 64, 100), 100..100);
+    }
+}
@@ -2,7 +2,7 @@ use core::marker::PhantomData;
 
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
 use frame_system::RawOrigin;
-use pallet_evm::{AddressMapping, PrecompileHandle};
+use pallet_evm::{AddressMapping, ExitError, PrecompileFailure, PrecompileHandle};
 use precompile_utils::{EvmResult, prelude::UnboundedBytes};
 use sp_core::H256;
 use sp_runtime::traits::Dispatchable;
@@ -62,7 +62,7 @@ where
         handle: &mut impl PrecompileHandle,
         netuid: u16,
         commit_hash: H256,
-    ) -> EvmResult<()> {
+    ) -> EvmResult<u64> {
         let call = pallet_subtensor::Call::<R>::commit_weights {
             netuid,
             commit_hash,
@@ -71,7 +71,14 @@ where
         handle.try_dispatch_runtime_call::<R, _>(
             call,
             RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        )?;
+
+        // Tell the caller from which block the commit can be revealed.
+        let commit_block = pallet_subtensor::Pallet::<R>::get_current_block_as_u64();
+        let (first_reveal_block, _) =
+            pallet_subtensor::Pallet::<R>::get_reveal_blocks(netuid, commit_block);
+
+        Ok(first_reveal_block)
     }
 
     #[precompile::public("revealWeights(uint16,uint16[],uint16[],uint16[],uint64)")]
@@ -84,6 +91,13 @@ where
         salt: Vec<u16>,
         version_key: u64,
     ) -> EvmResult<()> {
+        // Without this a length mismatch only shows up as a commit hash mismatch.
+        if uids.len() != values.len() {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("uids and values must have the same length".into()),
+            });
+        }
+
         let call = pallet_subtensor::Call::<R>::reveal_weights {
             netuid,
             uids,
@@ -202,4 +216,44 @@ where
             RawOrigin::Signed(handle.caller_account_id::<R>()),
         )
     }
+
+    #[precompile::public("isCommitRevealEnabled(uint16)")]
+    #[precompile::view]
+    fn is_commit_reveal_enabled(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+    ) -> EvmResult<bool> {
+        handle.record_read_cost::<R>(1)?;
+        Ok(pallet_subtensor::Pallet::<R>::get_commit_reveal_weights_enabled(netuid))
+    }
+
+    #[precompile::public("getNeuronCertificate(uint16,bytes32)")]
+    #[precompile::view]
+    fn get_neuron_certificate(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        hotkey: H256,
+    ) -> EvmResult<UnboundedBytes> {
+        handle.record_read_cost::<R>(1)?;
+        let hotkey = R::AccountId::from(hotkey.0);
+        Ok(pallet_subtensor::Pallet::<R>::get_neuron_certificate(netuid, &hotkey).into())
+    }
+
+    #[precompile::public("setNeuronCertificate(uint16,bytes)")]
+    #[precompile::payable]
+    fn set_neuron_certificate(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        certificate: UnboundedBytes,
+    ) -> EvmResult<()> {
+        let call = pallet_subtensor::Call::<R>::set_neuron_certificate {
+            netuid,
+            certificate: certificate.into(),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )
+    }
 }
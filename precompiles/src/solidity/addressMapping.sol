@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: GPL-3.0
+pragma solidity ^0.8.0;
+
+address constant IADDRESS_MAPPING_ADDRESS = 0x0000000000000000000000000000000000000806;
+
+interface IAddressMapping {
+    /**
+     * @dev Returns the ss58 public key (32 bytes) that the given EVM address maps to.
+     *
+     * The mapping is the Frontier HashedAddressMapping, i.e. blake2_256("evm:" ++ address), which is
+     * the substrate account that holds the balance and stake of the EVM address.
+     *
+     * @param evm The EVM (H160) address to map.
+     * @return bytes32 The mapped ss58 public key.
+     */
+    function addressToAccount(address evm) external view returns (bytes32);
+
+    /**
+     * @dev Returns the EVM address an ss58 account acts as when it interacts with the EVM.
+     *
+     * HashedAddressMapping is a one-way hash, so the exact inverse of `addressToAccount` can't be
+     * computed. Instead this returns the truncated address (the first 20 bytes of the public key)
+     * used for substrate-originated EVM calls and withdrawals.
+     *
+     * @param account The ss58 public key (32 bytes).
+     * @return evm The truncated EVM address.
+     * @return exact True if `addressToAccount(evm)` maps back to `account`.
+     */
+    function accountToAddress(bytes32 account) external view returns (address evm, bool exact);
+}
@@ -4,4 +4,6 @@ address constant ISUBTENSOR_BALANCE_TRANSFER_ADDRESS = 0x00000000000000000000000
 
 interface ISubtensorBalanceTransfer {
     function transfer(bytes32 data) external payable;
+
+    function transferKeepAlive(bytes32 data) external payable;
 }
\ No newline at end of file
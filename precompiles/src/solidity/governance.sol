@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: GPL-3.0
+pragma solidity ^0.8.0;
+
+address constant IGOVERNANCE_ADDRESS = 0x0000000000000000000000000000000000000807;
+
+interface IGovernance {
+    /**
+     * @dev Votes on a senate proposal as the ss58 mirror of the caller's H160 address.
+     *
+     * The mirror account votes as both the coldkey and the senate hotkey, so it must be a member
+     * of the senate and own itself as a hotkey. The call reverts for non-members.
+     *
+     * @param proposalHash The hash of the proposal to vote on (32 bytes).
+     * @param index The index of the proposal (uint32).
+     * @param approve True to vote aye, false to vote nay.
+     */
+    function voteProposal(
+        bytes32 proposalHash,
+        uint32 index,
+        bool approve
+    ) external;
+}
@@ -11,6 +11,21 @@ struct AxonInfo {
   uint8 protocol;
 }
 
+struct NeuronInfo {
+  bytes32 hotkey;
+  bytes32 coldkey;
+  uint64 stake;
+  uint16 rank;
+  uint16 trust;
+  uint16 consensus;
+  uint16 incentive;
+  uint16 dividends;
+  uint64 emission;
+  bool active;
+  bool validator_permit;
+  uint64 last_update;
+}
+
 interface IMetagraph {
   
   /**
@@ -28,6 +43,14 @@ interface IMetagraph {
    */
   function getStake(uint16 netuid, uint16 uid) external view returns (uint64);
 
+  /**
+   * @dev Retrieves the alpha staked on the neuron's hotkey on the given subnet.
+   * @param netuid The network identifier of the subnet.
+   * @param uid The unique identifier of the neuron.
+   * @return The neuron's stake on the subnet, or 0 if no neuron has the uid.
+   */
+  function getNeuronStake(uint16 netuid, uint16 uid) external view returns (uint256);
+
   /**
    * @dev Retrieves the rank of a node with a given network identifier (netuid) and unique identifier (uid).
    * @param netuid The network identifier for which to retrieve the rank.
@@ -116,6 +139,15 @@ interface IMetagraph {
    */
   function getAxon(uint16 netuid, uint16 uid) external view returns (AxonInfo memory);
 
+  /**
+   * @dev Retrieves the axon information a hotkey serves on a given network identifier (netuid).
+   * Reverts if the hotkey is not registered on the network or has not served an axon.
+   * @param netuid The network identifier for which to retrieve the axon information.
+   * @param hotkey The hotkey for which to retrieve the axon information.
+   * @return The axon information served by the hotkey on the specified netuid.
+   */
+  function getAxon(uint16 netuid, bytes32 hotkey) external view returns (AxonInfo memory);
+
   /**
    * @dev Retrieves the hotkey of a node with a given network identifier (netuid) and unique identifier (uid).
    * @param netuid The network identifier for which to retrieve the hotkey.
@@ -131,4 +163,22 @@ interface IMetagraph {
    * @return The coldkey of the node with the specified netuid and uid.
    */
   function getColdkey(uint16 netuid, uint16 uid) external view returns (bytes32);
+
+  /**
+   * @dev Retrieves the full state of a node with a given network identifier (netuid) and unique identifier (uid) in a single call.
+   * @param netuid The network identifier for which to retrieve the node.
+   * @param uid The unique identifier for which to retrieve the node.
+   * @return The hotkey, coldkey, stake, rank, trust, consensus, incentive, dividends, emission, active flag, validator permit and last update of the node.
+   */
+  function getNeuron(uint16 netuid, uint16 uid) external view returns (NeuronInfo memory);
+
+  /**
+   * @dev Retrieves the full state of up to `count` nodes of a network identifier (netuid), starting at unique identifier (uid) `start`.
+   * Fewer nodes are returned when the network ends before `start + count`. Reverts if `count` exceeds 64.
+   * @param netuid The network identifier for which to retrieve the nodes.
+   * @param start The unique identifier of the first node to retrieve.
+   * @param count The number of nodes to retrieve, at most 64.
+   * @return The nodes with unique identifiers from `start`, in order.
+   */
+  function getNeuronsPaged(uint16 netuid, uint16 start, uint16 count) external view returns (NeuronInfo[] memory);
 }
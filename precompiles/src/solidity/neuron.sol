@@ -100,8 +100,15 @@ interface INeuron {
      *
      * @param netuid The subnet to commit the weights for (uint16).
      * @param commitHash The commit hash for the weights (bytes32).
+     * @return firstRevealBlock The first block at which the commit can be revealed (uint64).
+     *
+     * Requirements:
+     * - Commits are subject to the subnet's weights rate limit.
      */
-    function commitWeights(uint16 netuid, bytes32 commitHash) external payable;
+    function commitWeights(
+        uint16 netuid,
+        bytes32 commitHash
+    ) external payable returns (uint64 firstRevealBlock);
 
     /**
      * @dev Reveals the weights for a neuron.
@@ -111,6 +118,11 @@ interface INeuron {
      * @param values The values of the weights (uint16[]).
      * @param salt The salt values for the weights (uint16[]).
      * @param versionKey The version key for the weights (uint64).
+     *
+     * Requirements:
+     * - `uids` and `values` must have the same length.
+     * - Reveals outside the reveal window revert with `DispatchError(uint8,uint8)`
+     *   carrying the subtensor pallet error.
      */
     function revealWeights(
         uint16 netuid,
@@ -119,4 +131,39 @@ interface INeuron {
         uint16[] memory salt,
         uint64 versionKey
     ) external payable;
+
+    /**
+     * @dev Returns whether the subnet uses commit-reveal for weights.
+     *
+     * @param netuid The subnet to check (uint16).
+     * @return Whether weights have to be committed and revealed rather than set directly (bool).
+     */
+    function isCommitRevealEnabled(uint16 netuid) external view returns (bool);
+
+    /**
+     * @dev Returns the TLS certificate of a hotkey on a subnet.
+     *
+     * @param netuid The subnet to query (uint16).
+     * @param hotkey The hotkey public key (32 bytes).
+     * @return The algorithm byte followed by the public key, empty if none is set (bytes).
+     */
+    function getNeuronCertificate(
+        uint16 netuid,
+        bytes32 hotkey
+    ) external view returns (bytes memory);
+
+    /**
+     * @dev Sets the TLS certificate of the caller's hotkey on a subnet, leaving its axon as is.
+     *
+     * @param netuid The subnet to set the certificate on (uint16).
+     * @param certificate The algorithm byte followed by the public key (bytes).
+     *
+     * Requirements:
+     * - The caller's hotkey must be registered on the subnet.
+     * - `certificate` must be between 1 and 65 bytes long.
+     */
+    function setNeuronCertificate(
+        uint16 netuid,
+        bytes memory certificate
+    ) external payable;
 }
@@ -3,6 +3,28 @@ pragma solidity ^0.8.0;
 address constant ISTAKING_ADDRESS = 0x0000000000000000000000000000000000000801;
 
 interface IStaking {
+    /**
+     * @dev Emitted after stake is added through `addStake`.
+     *
+     * @param hotkey The hotkey public key (32 bytes) the stake was added to.
+     * @param coldkey The coldkey public key (32 bytes) that staked, which is the hashed address
+     *   mapping of the sender.
+     * @param netuid The subnet the stake was added on.
+     * @param amount The amount staked in rao.
+     */
+    event StakeAdded(bytes32 indexed hotkey, bytes32 indexed coldkey, uint256 netuid, uint256 amount);
+
+    /**
+     * @dev Emitted after stake is removed through `removeStake`.
+     *
+     * @param hotkey The hotkey public key (32 bytes) the stake was removed from.
+     * @param coldkey The coldkey public key (32 bytes) that unstaked, which is the hashed address
+     *   mapping of the sender.
+     * @param netuid The subnet the stake was removed from.
+     * @param amount The amount unstaked in alpha.
+     */
+    event StakeRemoved(bytes32 indexed hotkey, bytes32 indexed coldkey, uint256 netuid, uint256 amount);
+
     /**
      * @dev Adds a subtensor stake corresponding to the value sent with the transaction, associated
      * with the `hotkey`.
@@ -3,6 +3,28 @@ pragma solidity ^0.8.0;
 address constant ISTAKING_ADDRESS = 0x0000000000000000000000000000000000000805;
 
 interface IStaking {
+    /**
+     * @dev Emitted after stake is added through `addStake`.
+     *
+     * @param hotkey The hotkey public key (32 bytes) the stake was added to.
+     * @param coldkey The coldkey public key (32 bytes) that staked, which is the hashed address
+     *   mapping of the sender.
+     * @param netuid The subnet the stake was added on.
+     * @param amount The amount staked in rao.
+     */
+    event StakeAdded(bytes32 indexed hotkey, bytes32 indexed coldkey, uint256 netuid, uint256 amount);
+
+    /**
+     * @dev Emitted after stake is removed through `removeStake`.
+     *
+     * @param hotkey The hotkey public key (32 bytes) the stake was removed from.
+     * @param coldkey The coldkey public key (32 bytes) that unstaked, which is the hashed address
+     *   mapping of the sender.
+     * @param netuid The subnet the stake was removed from.
+     * @param amount The amount unstaked in alpha.
+     */
+    event StakeRemoved(bytes32 indexed hotkey, bytes32 indexed coldkey, uint256 netuid, uint256 amount);
+
     /**
      * @dev Adds a subtensor stake `amount` associated with the `hotkey`.
      *
@@ -46,6 +68,46 @@ interface IStaking {
         uint256 netuid
     ) external;
 
+    /**
+     * @dev Adds stake to the `hotkey` on several subnets in one call, `amounts[i]` rao on
+     * `netuids[i]`.
+     *
+     * The batch is all-or-nothing: if any of the stakes fails, none of them is applied. Emits a
+     * `StakeAdded` event per subnet.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param netuids The subnets to stake to.
+     * @param amounts The amounts to stake in rao, one per subnet.
+     *
+     * Requirements:
+     * - `netuids` and `amounts` must have the same length, of at most 16.
+     */
+    function batchStake(
+        bytes32 hotkey,
+        uint16[] memory netuids,
+        uint256[] memory amounts
+    ) external;
+
+    /**
+     * @dev Removes stake from the `hotkey` on several subnets in one call, `amounts[i]` alpha on
+     * `netuids[i]`.
+     *
+     * The batch is all-or-nothing: if any of the unstakes fails, none of them is applied. Emits a
+     * `StakeRemoved` event per subnet.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param netuids The subnets to unstake from.
+     * @param amounts The amounts to unstake in alpha, one per subnet.
+     *
+     * Requirements:
+     * - `netuids` and `amounts` must have the same length, of at most 16.
+     */
+    function batchUnstake(
+        bytes32 hotkey,
+        uint16[] memory netuids,
+        uint256[] memory amounts
+    ) external;
+
     /**
      * @dev Returns the amount of RAO staked by the coldkey.
      *
@@ -100,4 +162,45 @@ interface IStaking {
      * @param delegate The public key (32 bytes) of the delegate.
      */
     function removeProxy(bytes32 delegate) external;
+
+    /**
+     * @dev Schedules a swap of the caller's coldkey to a new coldkey.
+     *
+     * The coldkey swap cost has to be attached as value. Once the schedule duration has passed,
+     * all stake of the caller's coldkey is moved to the new coldkey.
+     *
+     * @param newColdkey The public key (32 bytes) of the new coldkey, which must not be in use.
+     */
+    function swapColdkey(bytes32 newColdkey) external payable;
+
+    /**
+     * @dev Returns the take of a hotkey.
+     *
+     * @param hotkey The public key (32 bytes) of the hotkey.
+     * @return The take, where 65535 is 100%.
+     */
+    function getTake(bytes32 hotkey) external view returns (uint16);
+
+    /**
+     * @dev Sets the take of a hotkey owned by the caller.
+     *
+     * The take has to be within the min and max delegate take, and raising it is rate limited.
+     *
+     * @param hotkey The public key (32 bytes) of the hotkey.
+     * @param take The new take, where 65535 is 100%.
+     */
+    function setTake(bytes32 hotkey, uint16 take) external;
+
+    /**
+     * @dev Returns the smallest amounts accepted by addStake and removeStake.
+     *
+     * The removeStake minimum applies to the TAO the unstaked alpha would swap for.
+     *
+     * @return minStake The minimum amount of TAO (in RAO) to stake.
+     * @return minRemove The minimum TAO value (in RAO) of the alpha to unstake.
+     */
+    function getMinStakeAmounts()
+        external
+        view
+        returns (uint256 minStake, uint256 minRemove);
 }
@@ -3,8 +3,9 @@ pragma solidity ^0.8.0;
 address constant ISUBNET_ADDRESS = 0x0000000000000000000000000000000000000803;
 
 interface ISubnet {
-    /// Registers a new network without specifying details.
-    function registerNetwork(bytes32 hotkey) external payable;
+    /// Registers a new network without specifying details and returns its netuid. The attached
+    /// value must cover the network lock cost.
+    function registerNetwork(bytes32 hotkey) external payable returns (uint16);
     /// Registers a new network with specified subnet name, GitHub repository, and contact information.
     function registerNetwork(
         bytes32 hotkey,
@@ -17,8 +18,22 @@ interface ISubnet {
         string memory additional
     ) external payable;
 
+    /// Dissolves a network owned by the caller, removing all of its registrations.
+    function dissolveNetwork(uint16 netuid) external payable;
+
+    function getOwnerCut(uint16 netuid) external view returns (uint16);
+
+    /// Returns the TAO traded on the subnet over roughly the last 24 hours.
+    function getRecentVolume(uint16 netuid) external view returns (uint256);
+
     function getServingRateLimit(uint16 netuid) external view returns (uint64);
 
+    /// Returns the TAO and alpha reserves of the subnet pool. Both are zero for subnets that
+    /// price alpha 1:1 with TAO, such as root.
+    function getSubnetReserves(
+        uint16 netuid
+    ) external view returns (uint256 taoReserve, uint256 alphaReserve);
+
     function setServingRateLimit(
         uint16 netuid,
         uint64 servingRateLimit
@@ -25,20 +25,92 @@
 //   - Precompile checks the result of do_remove_stake and, in case of a failure, reverts the transaction.
 //
 
-use core::marker::PhantomData;
+use alloc::vec::Vec;
+use core::{cmp::Ordering, marker::PhantomData};
 
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_support::storage::{TransactionOutcome, with_transaction};
 use frame_system::RawOrigin;
 use pallet_evm::{
     AddressMapping, BalanceConverter, ExitError, PrecompileFailure, PrecompileHandle,
 };
 use precompile_utils::EvmResult;
-use sp_core::{H256, U256};
+use precompile_utils::prelude::{LogExt, keccak256, log3};
+use precompile_utils::solidity::encode_event_data;
+use sp_core::{ByteArray, H256, U256};
+use sp_runtime::DispatchError;
 use sp_runtime::traits::{Dispatchable, StaticLookup, UniqueSaturatedInto};
 use subtensor_runtime_common::ProxyType;
 
 use crate::{PrecompileExt, PrecompileHandleExt};
 
+/// Topic of the `StakeAdded(bytes32 indexed hotkey, bytes32 indexed coldkey, uint256 netuid,
+/// uint256 amount)` log emitted after a successful `addStake`.
+pub(crate) const SELECTOR_LOG_STAKE_ADDED: [u8; 32] =
+    keccak256!("StakeAdded(bytes32,bytes32,uint256,uint256)");
+
+/// Topic of the `StakeRemoved(bytes32 indexed hotkey, bytes32 indexed coldkey, uint256 netuid,
+/// uint256 amount)` log emitted after a successful `removeStake`.
+pub(crate) const SELECTOR_LOG_STAKE_REMOVED: [u8; 32] =
+    keccak256!("StakeRemoved(bytes32,bytes32,uint256,uint256)");
+
+/// Maximum number of sub-operations accepted by `batchStake` and `batchUnstake`.
+pub(crate) const MAX_STAKE_BATCH_SIZE: usize = 16;
+
+// Mirrors a stake change made through the precompile as an EVM log, so that contracts and
+// frontends can follow it with `eth_getLogs`. The amount is the one passed to the extrinsic: rao
+// for added stake, alpha for removed stake.
+fn log_stake_change<A: ByteArray>(
+    handle: &mut impl PrecompileHandle,
+    selector: [u8; 32],
+    hotkey: H256,
+    coldkey: &A,
+    netuid: u16,
+    amount: u64,
+) -> EvmResult<()> {
+    log3(
+        handle.context().address,
+        selector,
+        hotkey,
+        H256::from_slice(coldkey.as_slice()),
+        encode_event_data((U256::from(netuid), U256::from(amount))),
+    )
+    .record(handle)
+}
+
+// Checks the arguments of a batched stake operation before anything is dispatched.
+fn ensure_valid_batch(netuids: &[u16], amounts: &[U256]) -> EvmResult<()> {
+    if netuids.len() != amounts.len() {
+        return Err(PrecompileFailure::Error {
+            exit_status: ExitError::Other("netuids and amounts must have the same length".into()),
+        });
+    }
+    if netuids.len() > MAX_STAKE_BATCH_SIZE {
+        return Err(PrecompileFailure::Error {
+            exit_status: ExitError::Other("batch exceeds the maximum size".into()),
+        });
+    }
+
+    Ok(())
+}
+
+// Runs the sub-operations of a batch in a storage transaction, so the stake already moved by the
+// earlier ones is rolled back if a later one fails. Reverting the EVM frame alone wouldn't undo
+// runtime storage changes.
+fn with_batch_transaction(f: impl FnOnce() -> EvmResult<()>) -> EvmResult<()> {
+    with_transaction(|| {
+        let result = f();
+        if result.is_ok() {
+            TransactionOutcome::Commit(Ok::<_, DispatchError>(result))
+        } else {
+            TransactionOutcome::Rollback(Ok(result))
+        }
+    })
+    .map_err(|_| PrecompileFailure::Error {
+        exit_status: ExitError::Other("batch transaction failed".into()),
+    })?
+}
+
 // Old StakingPrecompile had ETH-precision in values, which was not alligned with Substrate API. So
 // it's kinda deprecated, but exists for backward compatibility. Eventually, we should remove it
 // to stop supporting both precompiles.
@@ -51,13 +123,16 @@ where
     R: frame_system::Config
         + pallet_evm::Config
         + pallet_subtensor::Config
-        + pallet_proxy::Config<ProxyType = ProxyType>,
-    R::AccountId: From<[u8; 32]>,
+        + pallet_proxy::Config<ProxyType = ProxyType>
+        + pallet_balances::Config,
+    R::AccountId: From<[u8; 32]> + ByteArray,
     <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
         + From<pallet_proxy::Call<R>>
+        + From<pallet_balances::Call<R>>
         + GetDispatchInfo
         + Dispatchable<PostInfo = PostDispatchInfo>,
     <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+    <R as pallet_balances::Config>::Balance: TryFrom<U256>,
     <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
 {
     const INDEX: u64 = 2053;
@@ -69,13 +144,16 @@ where
     R: frame_system::Config
         + pallet_evm::Config
         + pallet_subtensor::Config
-        + pallet_proxy::Config<ProxyType = ProxyType>,
-    R::AccountId: From<[u8; 32]>,
+        + pallet_proxy::Config<ProxyType = ProxyType>
+        + pallet_balances::Config,
+    R::AccountId: From<[u8; 32]> + ByteArray,
     <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
         + From<pallet_proxy::Call<R>>
+        + From<pallet_balances::Call<R>>
         + GetDispatchInfo
         + Dispatchable<PostInfo = PostDispatchInfo>,
     <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+    <R as pallet_balances::Config>::Balance: TryFrom<U256>,
     <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
 {
     #[precompile::public("addStake(bytes32,uint256,uint256)")]
@@ -96,7 +174,15 @@ where
             amount_staked,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id.clone()))?;
+        log_stake_change(
+            handle,
+            SELECTOR_LOG_STAKE_ADDED,
+            address,
+            &account_id,
+            netuid,
+            amount_staked,
+        )
     }
 
     #[precompile::public("removeStake(bytes32,uint256,uint256)")]
@@ -116,7 +202,93 @@ where
             amount_unstaked,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id.clone()))?;
+        log_stake_change(
+            handle,
+            SELECTOR_LOG_STAKE_REMOVED,
+            address,
+            &account_id,
+            netuid,
+            amount_unstaked,
+        )
+    }
+
+    /// Adds `amounts_rao[i]` of stake to `address` on `netuids[i]` for every `i`. Either all the
+    /// stakes are added or none of them.
+    #[precompile::public("batchStake(bytes32,uint16[],uint256[])")]
+    fn batch_stake(
+        handle: &mut impl PrecompileHandle,
+        address: H256,
+        netuids: Vec<u16>,
+        amounts_rao: Vec<U256>,
+    ) -> EvmResult<()> {
+        ensure_valid_batch(&netuids, &amounts_rao)?;
+        let account_id = handle.caller_account_id::<R>();
+
+        with_batch_transaction(|| {
+            for (netuid, amount_rao) in netuids.into_iter().zip(amounts_rao) {
+                let amount_staked = amount_rao.unique_saturated_into();
+                let call = pallet_subtensor::Call::<R>::add_stake {
+                    hotkey: R::AccountId::from(address.0),
+                    netuid,
+                    amount_staked,
+                };
+
+                handle.try_dispatch_runtime_call::<R, _>(
+                    call,
+                    RawOrigin::Signed(account_id.clone()),
+                )?;
+                log_stake_change(
+                    handle,
+                    SELECTOR_LOG_STAKE_ADDED,
+                    address,
+                    &account_id,
+                    netuid,
+                    amount_staked,
+                )?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Removes `amounts_alpha[i]` of stake from `address` on `netuids[i]` for every `i`. Either
+    /// all the stakes are removed or none of them.
+    #[precompile::public("batchUnstake(bytes32,uint16[],uint256[])")]
+    fn batch_unstake(
+        handle: &mut impl PrecompileHandle,
+        address: H256,
+        netuids: Vec<u16>,
+        amounts_alpha: Vec<U256>,
+    ) -> EvmResult<()> {
+        ensure_valid_batch(&netuids, &amounts_alpha)?;
+        let account_id = handle.caller_account_id::<R>();
+
+        with_batch_transaction(|| {
+            for (netuid, amount_alpha) in netuids.into_iter().zip(amounts_alpha) {
+                let amount_unstaked = amount_alpha.unique_saturated_into();
+                let call = pallet_subtensor::Call::<R>::remove_stake {
+                    hotkey: R::AccountId::from(address.0),
+                    netuid,
+                    amount_unstaked,
+                };
+
+                handle.try_dispatch_runtime_call::<R, _>(
+                    call,
+                    RawOrigin::Signed(account_id.clone()),
+                )?;
+                log_stake_change(
+                    handle,
+                    SELECTOR_LOG_STAKE_REMOVED,
+                    address,
+                    &account_id,
+                    netuid,
+                    amount_unstaked,
+                )?;
+            }
+
+            Ok(())
+        })
     }
 
     #[precompile::public("getTotalColdkeyStake(bytes32)")]
@@ -188,6 +360,86 @@ where
 
         handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
     }
+
+    /// Schedules a swap of the caller's coldkey to `new_coldkey`. The swap cost has to be attached
+    /// as value; it's handed back to the caller, whose balance pays for the swap.
+    ///
+    /// `swap_coldkey` itself can only be dispatched by root, so this goes through
+    /// `schedule_swap_coldkey` and the stake moves once the schedule duration has passed.
+    #[precompile::public("swapColdkey(bytes32)")]
+    #[precompile::payable]
+    fn swap_coldkey(handle: &mut impl PrecompileHandle, new_coldkey: H256) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let amount = handle.context().apparent_value;
+        let amount_sub = handle.try_convert_apparent_value::<R>()?;
+        if amount_sub < U256::from(pallet_subtensor::Pallet::<R>::get_key_swap_cost()) {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("value doesn't cover the coldkey swap cost".into()),
+            });
+        }
+
+        // Reject keys the swap would fail on when it executes, rather than scheduling it anyway.
+        handle.record_read_cost::<R>(2)?;
+        let new_coldkey = R::AccountId::from(new_coldkey.0);
+        if !pallet_subtensor::StakingHotkeys::<R>::get(&new_coldkey).is_empty()
+            || pallet_subtensor::Pallet::<R>::hotkey_account_exists(&new_coldkey)
+        {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("new coldkey is already in use".into()),
+            });
+        }
+
+        if !amount.is_zero() {
+            Self::transfer_back_to_caller::<R>(&account_id, amount)?;
+        }
+
+        let call = pallet_subtensor::Call::<R>::schedule_swap_coldkey { new_coldkey };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+    }
+
+    #[precompile::public("getTake(bytes32)")]
+    #[precompile::view]
+    fn get_take(_: &mut impl PrecompileHandle, hotkey: H256) -> EvmResult<u16> {
+        let hotkey = R::AccountId::from(hotkey.0);
+
+        Ok(pallet_subtensor::Pallet::<R>::get_hotkey_take(&hotkey))
+    }
+
+    /// Sets the take of a hotkey owned by the caller. Raising the take goes through the rate
+    /// limited `increase_take` and lowering it through `decrease_take`, so both keep enforcing the
+    /// min/max take bounds. Setting the current take is a no-op, but still only for the owner.
+    #[precompile::public("setTake(bytes32,uint16)")]
+    fn set_take(handle: &mut impl PrecompileHandle, hotkey: H256, take: u16) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let hotkey = R::AccountId::from(hotkey.0);
+
+        handle.record_read_cost::<R>(2)?;
+        if pallet_subtensor::Pallet::<R>::get_owning_coldkey_for_hotkey(&hotkey) != account_id {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("caller does not own the hotkey".into()),
+            });
+        }
+        let current_take = pallet_subtensor::Pallet::<R>::get_hotkey_take(&hotkey);
+        let call = match take.cmp(&current_take) {
+            Ordering::Equal => return Ok(()),
+            Ordering::Greater => pallet_subtensor::Call::<R>::increase_take { hotkey, take },
+            Ordering::Less => pallet_subtensor::Call::<R>::decrease_take { hotkey, take },
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+    }
+
+    /// Returns the smallest amounts `addStake` and `removeStake` accept, in RAO. The removal
+    /// minimum applies to the TAO the unstaked alpha would swap for.
+    #[precompile::public("getMinStakeAmounts()")]
+    #[precompile::view]
+    fn get_min_stake_amounts(_: &mut impl PrecompileHandle) -> EvmResult<(U256, U256)> {
+        Ok((
+            pallet_subtensor::Pallet::<R>::get_min_add_stake_amount().into(),
+            pallet_subtensor::Pallet::<R>::get_min_remove_stake_amount().into(),
+        ))
+    }
 }
 
 // Deprecated, exists for backward compatibility.
@@ -200,7 +452,7 @@ where
         + pallet_subtensor::Config
         + pallet_proxy::Config<ProxyType = ProxyType>
         + pallet_balances::Config,
-    R::AccountId: From<[u8; 32]>,
+    R::AccountId: From<[u8; 32]> + ByteArray,
     <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
         + From<pallet_proxy::Call<R>>
         + From<pallet_balances::Call<R>>
@@ -221,7 +473,7 @@ where
         + pallet_subtensor::Config
         + pallet_proxy::Config<ProxyType = ProxyType>
         + pallet_balances::Config,
-    R::AccountId: From<[u8; 32]>,
+    R::AccountId: From<[u8; 32]> + ByteArray,
     <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
         + From<pallet_proxy::Call<R>>
         + From<pallet_balances::Call<R>>
@@ -238,19 +490,28 @@ where
         let amount = handle.context().apparent_value;
 
         if !amount.is_zero() {
-            Self::transfer_back_to_caller(&account_id, amount)?;
+            Self::transfer_back_to_caller::<R>(&account_id, amount)?;
         }
 
         let amount_sub = handle.try_convert_apparent_value::<R>()?;
         let hotkey = R::AccountId::from(address.0);
         let netuid = try_u16_from_u256(netuid)?;
+        let amount_staked = amount_sub.unique_saturated_into();
         let call = pallet_subtensor::Call::<R>::add_stake {
             hotkey,
             netuid,
-            amount_staked: amount_sub.unique_saturated_into(),
+            amount_staked,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id.clone()))?;
+        log_stake_change(
+            handle,
+            SELECTOR_LOG_STAKE_ADDED,
+            address,
+            &account_id,
+            netuid,
+            amount_staked,
+        )
     }
 
     #[precompile::public("removeStake(bytes32,uint256,uint256)")]
@@ -273,7 +534,15 @@ where
             amount_unstaked,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id.clone()))?;
+        log_stake_change(
+            handle,
+            SELECTOR_LOG_STAKE_REMOVED,
+            address,
+            &account_id,
+            netuid,
+            amount_unstaked,
+        )
     }
 
     #[precompile::public("getTotalColdkeyStake(bytes32)")]
@@ -360,38 +629,6 @@ where
 
         handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
     }
-
-    fn transfer_back_to_caller(
-        account_id: &<R as frame_system::Config>::AccountId,
-        amount: U256,
-    ) -> Result<(), PrecompileFailure> {
-        let amount_sub =
-            <R as pallet_evm::Config>::BalanceConverter::into_substrate_balance(amount)
-                .ok_or(ExitError::OutOfFund)?;
-
-        // Create a transfer call from the smart contract to the caller
-        let transfer_call = <R as frame_system::Config>::RuntimeCall::from(
-            pallet_balances::Call::<R>::transfer_allow_death {
-                dest: account_id.clone().into(),
-                value: amount_sub.unique_saturated_into(),
-            },
-        );
-
-        // Execute the transfer
-        let transfer_result = transfer_call.dispatch(RawOrigin::Signed(Self::account_id()).into());
-
-        if let Err(dispatch_error) = transfer_result {
-            log::error!(
-                "Transfer back to caller failed. Error: {:?}",
-                dispatch_error
-            );
-            return Err(PrecompileFailure::Error {
-                exit_status: ExitError::Other("Transfer back to caller failed".into()),
-            });
-        }
-
-        Ok(())
-    }
 }
 
 fn try_u16_from_u256(value: U256) -> Result<u16, PrecompileFailure> {
@@ -3,10 +3,10 @@ use core::marker::PhantomData;
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
 use frame_support::traits::ConstU32;
 use frame_system::RawOrigin;
-use pallet_evm::{AddressMapping, PrecompileHandle};
+use pallet_evm::{AddressMapping, ExitError, PrecompileFailure, PrecompileHandle};
 use precompile_utils::{EvmResult, prelude::BoundedString};
-use sp_core::H256;
-use sp_runtime::traits::Dispatchable;
+use sp_core::{H256, U256};
+use sp_runtime::traits::{Dispatchable, StaticLookup};
 
 use crate::{PrecompileExt, PrecompileHandleExt};
 
@@ -17,13 +17,17 @@ where
     R: frame_system::Config
         + pallet_evm::Config
         + pallet_subtensor::Config
-        + pallet_admin_utils::Config,
+        + pallet_admin_utils::Config
+        + pallet_balances::Config,
     R::AccountId: From<[u8; 32]>,
     <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
         + From<pallet_admin_utils::Call<R>>
+        + From<pallet_balances::Call<R>>
         + GetDispatchInfo
         + Dispatchable<PostInfo = PostDispatchInfo>,
     <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+    <R as pallet_balances::Config>::Balance: TryFrom<U256>,
+    <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
 {
     const INDEX: u64 = 2051;
 }
@@ -34,27 +38,59 @@ where
     R: frame_system::Config
         + pallet_evm::Config
         + pallet_subtensor::Config
-        + pallet_admin_utils::Config,
+        + pallet_admin_utils::Config
+        + pallet_balances::Config,
     R::AccountId: From<[u8; 32]>,
     <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
         + From<pallet_admin_utils::Call<R>>
+        + From<pallet_balances::Call<R>>
         + GetDispatchInfo
         + Dispatchable<PostInfo = PostDispatchInfo>,
     <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+    <R as pallet_balances::Config>::Balance: TryFrom<U256>,
+    <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
 {
     #[precompile::public("registerNetwork(bytes32)")]
     #[precompile::payable]
-    fn register_network(handle: &mut impl PrecompileHandle, hotkey: H256) -> EvmResult<()> {
+    fn register_network(handle: &mut impl PrecompileHandle, hotkey: H256) -> EvmResult<u16> {
+        let account_id = handle.caller_account_id::<R>();
+        let amount = handle.context().apparent_value;
+        let amount_sub = handle.try_convert_apparent_value::<R>()?;
+
+        // The lock is paid out of the attached value, bail out before moving any funds if it
+        // doesn't cover the lock cost.
+        let lock_cost = pallet_subtensor::Pallet::<R>::get_network_lock_cost();
+        if amount_sub < U256::from(lock_cost) {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other(
+                    "attached value is less than the network lock cost".into(),
+                ),
+            });
+        }
+
+        if !amount.is_zero() {
+            Self::transfer_back_to_caller::<R>(&account_id, amount)?;
+        }
+
+        let netuid = pallet_subtensor::Pallet::<R>::get_next_netuid();
         let hotkey = R::AccountId::from(hotkey.0);
         let call = pallet_subtensor::Call::<R>::register_network_with_identity {
             hotkey,
             identity: None,
         };
 
-        handle.try_dispatch_runtime_call::<R, _>(
-            call,
-            RawOrigin::Signed(handle.caller_account_id::<R>()),
-        )
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))?;
+
+        Ok(netuid)
+    }
+
+    #[precompile::public("dissolveNetwork(uint16)")]
+    #[precompile::payable]
+    fn dissolve_network(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<()> {
+        let coldkey = handle.caller_account_id::<R>();
+        let call = pallet_subtensor::Call::<R>::dissolve_owned_network { netuid };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(coldkey))
     }
 
     #[precompile::public(
@@ -119,6 +155,30 @@ where
         )
     }
 
+    /// The owner cut is network-wide, so every subnet reports the same value.
+    #[precompile::public("getOwnerCut(uint16)")]
+    #[precompile::view]
+    fn get_owner_cut(_: &mut impl PrecompileHandle, _netuid: u16) -> EvmResult<u16> {
+        Ok(pallet_subtensor::SubnetOwnerCut::<R>::get())
+    }
+
+    /// Returns the TAO traded on the subnet over roughly the last 24 hours.
+    #[precompile::public("getRecentVolume(uint16)")]
+    #[precompile::view]
+    fn get_recent_volume(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<U256> {
+        Ok(pallet_subtensor::Pallet::<R>::get_recent_volume(netuid).into())
+    }
+
+    /// Returns the TAO and alpha reserves of the subnet pool, or zeros for subnets that price
+    /// alpha 1:1 with TAO.
+    #[precompile::public("getSubnetReserves(uint16)")]
+    #[precompile::view]
+    fn get_subnet_reserves(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<(U256, U256)> {
+        let (tao_reserve, alpha_reserve) =
+            pallet_subtensor::Pallet::<R>::get_subnet_reserves(netuid);
+        Ok((tao_reserve.into(), alpha_reserve.into()))
+    }
+
     #[precompile::public("getMinDifficulty(uint16)")]
     #[precompile::view]
     fn get_min_difficulty(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
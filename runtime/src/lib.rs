@@ -14,13 +14,15 @@ mod migrations;
 use codec::{Compact, Decode, Encode};
 use frame_support::traits::Imbalance;
 use frame_support::{
+    PalletId,
     dispatch::DispatchResultWithPostInfo,
     genesis_builder_helper::{build_state, get_preset},
     pallet_prelude::Get,
     traits::{
         Contains, LinearStoragePrice, OnUnbalanced,
         fungible::{
-            DecreaseIssuance, HoldConsideration, Imbalance as FungibleImbalance, IncreaseIssuance,
+            Balanced, Credit, DecreaseIssuance, HoldConsideration, Imbalance as FungibleImbalance,
+            IncreaseIssuance,
         },
     },
 };
@@ -33,12 +35,14 @@ use pallet_registry::CanRegisterIdentity;
 use pallet_subtensor::rpc_info::{
     delegate_info::DelegateInfo,
     dynamic_info::DynamicInfo,
-    metagraph::Metagraph,
+    metagraph::{FullMetagraph, Metagraph},
     neuron_info::{NeuronInfo, NeuronInfoLite},
-    show_subnet::SubnetState,
+    show_subnet::{SubnetNeuronState, SubnetState},
     stake_info::StakeInfo,
     subnet_info::{SubnetHyperparams, SubnetInfo, SubnetInfov2},
+    supply_info::SupplyInfo,
 };
+use pallet_subtensor::utils::rate_limiting::RateLimitedOperation;
 use smallvec::smallvec;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -51,8 +55,8 @@ use sp_runtime::{
     AccountId32, ApplyExtrinsicResult, ConsensusEngineId, create_runtime_str, generic,
     impl_opaque_keys,
     traits::{
-        AccountIdLookup, BlakeTwo256, Block as BlockT, DispatchInfoOf, Dispatchable, NumberFor,
-        One, PostDispatchInfoOf, UniqueSaturatedInto, Verify,
+        AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, DispatchInfoOf,
+        Dispatchable, NumberFor, One, PostDispatchInfoOf, UniqueSaturatedInto, Verify,
     },
     transaction_validity::{TransactionSource, TransactionValidity, TransactionValidityError},
 };
@@ -92,7 +96,7 @@ use core::marker::PhantomData;
 // Frontier
 use fp_rpc::TransactionStatus;
 use pallet_ethereum::{Call::transact, PostLogContent, Transaction as EthereumTransaction};
-use pallet_evm::{Account as EVMAccount, BalanceConverter, FeeCalculator, Runner};
+use pallet_evm::{Account as EVMAccount, AddressMapping, BalanceConverter, FeeCalculator, Runner};
 
 // Drand
 impl pallet_drand::Config for Runtime {
@@ -481,7 +485,7 @@ use pallet_collective::{CanPropose, CanVote, GetVotingMembers};
 pub struct CanProposeToTriumvirate;
 impl CanPropose<AccountId> for CanProposeToTriumvirate {
     fn can_propose(account: &AccountId) -> bool {
-        Triumvirate::is_member(account)
+        Triumvirate::is_member(account) && SubtensorModule::can_submit_proposal(account)
     }
 }
 
@@ -668,6 +672,9 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::add_stake { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::add_stake_limit { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::remove_stake { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::remove_stake_partial { .. }
+                    )
                     | RuntimeCall::SubtensorModule(
                         pallet_subtensor::Call::remove_stake_limit { .. }
                     )
@@ -680,12 +687,14 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::move_stake { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::transfer_stake { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::burned_register { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::batch_register { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::root_register { .. })
                     | RuntimeCall::SubtensorModule(
                         pallet_subtensor::Call::schedule_swap_coldkey { .. }
                     )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::swap_coldkey { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::swap_hotkey { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::lock_stake { .. })
             ),
             ProxyType::Transfer => matches!(
                 c,
@@ -712,8 +721,12 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
             ProxyType::NonCritical => !matches!(
                 c,
                 RuntimeCall::SubtensorModule(pallet_subtensor::Call::dissolve_network { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::dissolve_owned_network { .. }
+                    )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::root_register { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::burned_register { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::batch_register { .. })
                     | RuntimeCall::Triumvirate(..)
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::set_tao_weights { .. })
                     | RuntimeCall::Sudo(..)
@@ -733,6 +746,9 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                 c,
                 RuntimeCall::SubtensorModule(pallet_subtensor::Call::add_stake { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::remove_stake { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::remove_stake_partial { .. }
+                    )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::unstake_all { .. })
                     | RuntimeCall::SubtensorModule(
                         pallet_subtensor::Call::unstake_all_alpha { .. }
@@ -744,11 +760,13 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                     | RuntimeCall::SubtensorModule(
                         pallet_subtensor::Call::remove_stake_limit { .. }
                     )
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::lock_stake { .. })
             ),
             ProxyType::Registration => matches!(
                 c,
                 RuntimeCall::SubtensorModule(pallet_subtensor::Call::burned_register { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::register { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::batch_register { .. })
             ),
             ProxyType::RootWeights => matches!(
                 c,
@@ -956,6 +974,12 @@ pub const INITIAL_SUBNET_TEMPO: u16 = 360;
 #[cfg(feature = "fast-blocks")]
 pub const INITIAL_SUBNET_TEMPO: u16 = 10;
 
+#[cfg(not(feature = "fast-blocks"))]
+pub const INITIAL_MIN_SUBNET_TEMPO: u16 = 10;
+
+#[cfg(feature = "fast-blocks")]
+pub const INITIAL_MIN_SUBNET_TEMPO: u16 = 1;
+
 #[cfg(not(feature = "fast-blocks"))]
 pub const INITIAL_CHILDKEY_TAKE_RATELIMIT: u64 = 216000; // 30 days at 12 seconds per block
 
@@ -975,6 +999,7 @@ parameter_types! {
     pub const SubtensorInitialScalingLawPower: u16 = 50; // 0.5
     pub const SubtensorInitialMaxAllowedValidators: u16 = 128;
     pub const SubtensorInitialTempo: u16 = INITIAL_SUBNET_TEMPO;
+    pub const SubtensorInitialMinTempo: u16 = INITIAL_MIN_SUBNET_TEMPO;
     pub const SubtensorInitialDifficulty: u64 = 10_000_000;
     pub const SubtensorInitialAdjustmentInterval: u16 = 100;
     pub const SubtensorInitialAdjustmentAlpha: u64 = 0; // no weight to previous value.
@@ -1041,6 +1066,7 @@ impl pallet_subtensor::Config for Runtime {
     type InitialValidatorPruneLen = SubtensorInitialValidatorPruneLen;
     type InitialScalingLawPower = SubtensorInitialScalingLawPower;
     type InitialTempo = SubtensorInitialTempo;
+    type InitialMinTempo = SubtensorInitialMinTempo;
     type InitialDifficulty = SubtensorInitialDifficulty;
     type InitialAdjustmentInterval = SubtensorInitialAdjustmentInterval;
     type InitialAdjustmentAlpha = SubtensorInitialAdjustmentAlpha;
@@ -1222,6 +1248,39 @@ impl BalanceConverter for SubtensorEvmBalanceConverter {
     }
 }
 
+parameter_types! {
+    pub const SubtensorTreasuryPalletId: PalletId = PalletId(*b"subt/try");
+    /// Share of the EVM transaction fees routed to the treasury, the rest is burned.
+    /// Nothing can spend from the treasury account yet, so the whole fee is burned for now.
+    pub const EvmFeeTreasuryShare: Perbill = Perbill::zero();
+}
+
+/// Account collecting the treasury share of transaction fees.
+pub struct SubtensorTreasuryAccount;
+impl Get<AccountId> for SubtensorTreasuryAccount {
+    fn get() -> AccountId {
+        SubtensorTreasuryPalletId::get().into_account_truncating()
+    }
+}
+
+/// Routes `EvmFeeTreasuryShare` of the EVM transaction fees to the treasury and burns the rest
+/// the same way substrate transaction fees are burned.
+pub struct EvmFeeHandler;
+impl OnUnbalanced<Credit<AccountId, Balances>> for EvmFeeHandler {
+    fn on_nonzero_unbalanced(credit: Credit<AccountId, Balances>) {
+        let treasury_fee = EvmFeeTreasuryShare::get() * credit.peek();
+        let (treasury_credit, burn_credit) = credit.split(treasury_fee);
+
+        // The treasury share is burned as well if it can't be deposited, e.g. below ED.
+        if let Err(treasury_credit) =
+            Balances::resolve(&SubtensorTreasuryAccount::get(), treasury_credit)
+        {
+            TransactionFeeHandler::on_unbalanced(treasury_credit);
+        }
+        TransactionFeeHandler::on_unbalanced(burn_credit);
+    }
+}
+
 impl pallet_evm::Config for Runtime {
     type FeeCalculator = BaseFee;
     type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
@@ -1237,7 +1296,7 @@ impl pallet_evm::Config for Runtime {
     type ChainId = ConfigurableChainId;
     type BlockGasLimit = BlockGasLimit;
     type Runner = pallet_evm::runner::stack::Runner<Self>;
-    type OnChargeTransaction = ();
+    type OnChargeTransaction = pallet_evm::EVMFungibleAdapter<Balances, EvmFeeHandler>;
     type OnCreate = ();
     type FindAuthor = FindAuthorTruncated<Aura>;
     type GasLimitPovSizeRatio = GasLimitPovSizeRatio;
@@ -1262,6 +1321,9 @@ parameter_types! {
     pub BoundDivision: U256 = U256::from(1024);
 }
 
+// The EVM gas price is the base fee stored on chain by pallet_base_fee, not a node side
+// target gas price, so it can be changed without restarting nodes: root sets it with
+// `BaseFee::set_base_fee_per_gas` and it adjusts with block fullness within `DefaultElasticity`.
 parameter_types! {
     pub DefaultBaseFeePerGas: U256 = U256::from(20_000_000_000_u128);
     pub DefaultElasticity: Permill = Permill::from_parts(125_000);
@@ -2045,6 +2107,62 @@ impl_runtime_apis! {
             SubtensorModule::get_subnet_state(netuid)
         }
 
+        fn get_subnet_neuron_state(netuid: u16) -> Option<SubnetNeuronState> {
+            SubtensorModule::get_subnet_neuron_state(netuid)
+        }
+
+        fn get_full_metagraph(netuid: u16) -> Option<FullMetagraph<AccountId32>> {
+            SubtensorModule::get_full_metagraph(netuid)
+        }
+
+        fn get_difficulty_info(netuid: u16) -> (u64, u64, i64) {
+            SubtensorModule::get_difficulty_info(netuid)
+        }
+
+        fn get_registration_cost(netuid: u16) -> (u64, u64, bool) {
+            SubtensorModule::get_registration_cost(netuid)
+        }
+
+        fn get_available_slots(netuid: u16) -> u16 {
+            SubtensorModule::get_available_slots(netuid)
+        }
+
+        fn is_slot_available(netuid: u16) -> bool {
+            SubtensorModule::is_slot_available(netuid)
+        }
+
+        fn get_pending_owner_cut(netuid: u16) -> u64 {
+            SubtensorModule::get_pending_owner_cut(netuid)
+        }
+
+        fn get_subnet_volume(netuid: u16) -> u128 {
+            SubtensorModule::get_subnet_volume(netuid)
+        }
+
+        fn get_subnet_price(netuid: u16) -> u64 {
+            SubtensorModule::get_alpha_price_in_rao(netuid)
+        }
+
+        fn get_weight_ages(netuid: u16) -> Vec<(u16, u64)> {
+            SubtensorModule::get_weight_ages(netuid)
+        }
+
+        fn is_epoch_block(netuid: u16, block: u64) -> bool {
+            SubtensorModule::is_epoch_block(netuid, block)
+        }
+
+        fn get_last_weight_set_block(netuid: u16, hotkey: AccountId32) -> Option<u64> {
+            SubtensorModule::get_last_weight_set_block(netuid, &hotkey)
+        }
+
+        fn get_upcoming_epochs(blocks: u64) -> Vec<(u16, u64)> {
+            SubtensorModule::get_upcoming_epochs(blocks)
+        }
+
+        fn get_reveal_period(netuid: u16) -> u64 {
+            SubtensorModule::get_reveal_period(netuid)
+        }
+
         fn get_all_metagraphs() -> Vec<Option<Metagraph<AccountId32>>> {
             SubtensorModule::get_all_metagraphs()
         }
@@ -2070,12 +2188,111 @@ impl_runtime_apis! {
         fn get_stake_fee( origin: Option<(AccountId32, u16)>, origin_coldkey_account: AccountId32, destination: Option<(AccountId32, u16)>, destination_coldkey_account: AccountId32, amount: u64 ) -> u64 {
             SubtensorModule::get_stake_fee( origin, origin_coldkey_account, destination, destination_coldkey_account, amount )
         }
+
+        fn get_max_stake_amount( netuid: u16, limit_price: u64, is_add: bool ) -> u64 {
+            SubtensorModule::get_max_stake_amount( netuid, limit_price, is_add )
+        }
+
+        fn get_stake_breakdown( coldkey_account: AccountId32, hotkey_account: AccountId32, netuid: u16 ) -> (u64, u64) {
+            SubtensorModule::get_stake_breakdown( coldkey_account, hotkey_account, netuid )
+        }
+
+        fn check_stake_consistency( netuid: u16 ) -> Vec<(AccountId32, AccountId32, i64)> {
+            SubtensorModule::check_stake_consistency( netuid )
+        }
+
+        fn get_total_delegated_stake_for_coldkey( coldkey_account: AccountId32 ) -> u64 {
+            SubtensorModule::get_total_delegated_stake_for_coldkey( &coldkey_account )
+        }
+
+        fn can_stake( netuid: u16, coldkey_account: AccountId32 ) -> bool {
+            // Safe mode filters add_stake before it reaches the pallet.
+            !pallet_safe_mode::Pallet::<Runtime>::is_entered()
+                && SubtensorModule::can_stake( netuid, &coldkey_account )
+        }
+
+        fn preview_nominator_emission( hotkey_account: AccountId32, coldkey_account: AccountId32, netuid: u16 ) -> u64 {
+            SubtensorModule::preview_nominator_emission( &hotkey_account, &coldkey_account, netuid )
+        }
+
+        fn get_hotkey_stake_breakdown( hotkey_account: AccountId32 ) -> Vec<(u16, u64, u64)> {
+            SubtensorModule::get_hotkey_stake_breakdown( &hotkey_account )
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::SubnetRegistrationRuntimeApi<Block> for Runtime {
         fn get_network_registration_cost() -> u64 {
             SubtensorModule::get_network_lock_cost()
         }
+
+        fn get_next_netuid() -> u16 {
+            SubtensorModule::get_next_netuid()
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::SupplyInfoRuntimeApi<Block> for Runtime {
+        fn get_supply_info() -> SupplyInfo {
+            SubtensorModule::get_supply_info()
+        }
+
+        fn get_remaining_issuance() -> u64 {
+            SubtensorModule::get_remaining_issuance()
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::EvmInfoRuntimeApi<Block> for Runtime {
+        fn get_evm_chain_id() -> u64 {
+            <Runtime as pallet_evm::Config>::ChainId::get()
+        }
+
+        fn evm_to_substrate(address: H160) -> AccountId32 {
+            <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(address)
+        }
+
+        fn ss58_to_evm(account: AccountId32) -> Option<H160> {
+            Precompiles::<Runtime>::address_of(&account)
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::SenateInfoRuntimeApi<Block> for Runtime {
+        fn get_senate_members() -> Vec<AccountId32> {
+            SubtensorModule::get_senate_members()
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::RateLimitInfoRuntimeApi<Block> for Runtime {
+        fn get_rate_limit_info(
+            hotkey: AccountId32,
+            netuid: u16,
+            operation: u8,
+        ) -> Option<(u64, u64, u64)> {
+            let operation = RateLimitedOperation::try_from(operation).ok()?;
+            Some(SubtensorModule::get_rate_limit_info(&hotkey, netuid, operation))
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::BatchRuntimeApi<Block, RuntimeCall> for Runtime {
+        fn simulate_batch(
+            account: AccountId32,
+            calls: Vec<RuntimeCall>,
+        ) -> Vec<Result<(), sp_runtime::DispatchError>> {
+            // Dispatch the calls one after another, like a batch would, so every call sees the
+            // effects of the ones before it, then roll everything back.
+            frame_support::storage::with_transaction(|| {
+                let results = calls
+                    .into_iter()
+                    .map(|call| {
+                        call.dispatch(RuntimeOrigin::signed(account.clone()))
+                            .map(|_| ())
+                            .map_err(|e| e.error)
+                    })
+                    .collect();
+                sp_runtime::TransactionOutcome::Rollback(Ok::<_, sp_runtime::DispatchError>(
+                    results,
+                ))
+            })
+            .unwrap_or_default()
+        }
     }
 }
 
@@ -2171,3 +2388,132 @@ fn test_into_evm_balance_overflow() {
     let result = SubtensorEvmBalanceConverter::into_evm_balance(substrate_balance);
     assert_eq!(result, Some(expected_evm_balance)); // Should return the scaled value
 }
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_get_evm_chain_id_matches_configured_chain_id() {
+    use subtensor_custom_rpc_runtime_api::runtime_decl_for_evm_info_runtime_api::EvmInfoRuntimeApiV1;
+
+    sp_io::TestExternalities::new(RuntimeGenesisConfig::default().build_storage().unwrap())
+        .execute_with(|| {
+            pallet_evm_chain_id::ChainId::<Runtime>::put(0x03C4);
+
+            let chain_id = <Runtime as EvmInfoRuntimeApiV1<Block>>::get_evm_chain_id();
+            assert_eq!(chain_id, 0x03C4);
+            assert_eq!(chain_id, <Runtime as pallet_evm::Config>::ChainId::get());
+        });
+}
+
+#[test]
+fn test_ss58_to_evm_resolves_precompile_accounts() {
+    use subtensor_custom_rpc_runtime_api::runtime_decl_for_evm_info_runtime_api::EvmInfoRuntimeApiV1;
+
+    for address in Precompiles::<Runtime>::used_addresses() {
+        let account = <Runtime as EvmInfoRuntimeApiV1<Block>>::evm_to_substrate(address);
+        assert_eq!(
+            <Runtime as EvmInfoRuntimeApiV1<Block>>::ss58_to_evm(account),
+            Some(address)
+        );
+    }
+
+    let account =
+        <Runtime as EvmInfoRuntimeApiV1<Block>>::evm_to_substrate(H160::repeat_byte(0x11));
+    assert_eq!(
+        <Runtime as EvmInfoRuntimeApiV1<Block>>::ss58_to_evm(account),
+        None
+    );
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_evm_fee_handler_routes_share_to_treasury() {
+    sp_io::TestExternalities::new(RuntimeGenesisConfig::default().build_storage().unwrap())
+        .execute_with(|| {
+            let fee: Balance = 1_000_000;
+            pallet_subtensor::TotalIssuance::<Runtime>::put(10_000_000);
+            let treasury_balance = Balances::free_balance(SubtensorTreasuryAccount::get());
+
+            EvmFeeHandler::on_unbalanced(Balances::issue(fee));
+
+            // Nothing is routed to the treasury until it can be spent from.
+            let treasury_fee = EvmFeeTreasuryShare::get() * fee;
+            assert_eq!(treasury_fee, 0);
+            assert_eq!(
+                Balances::free_balance(SubtensorTreasuryAccount::get()),
+                treasury_balance + treasury_fee
+            );
+            // Only the burned part leaves the issuance
+            assert_eq!(
+                pallet_subtensor::TotalIssuance::<Runtime>::get(),
+                10_000_000 - (fee - treasury_fee)
+            );
+        });
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_get_senate_members_matches_membership() {
+    use subtensor_custom_rpc_runtime_api::runtime_decl_for_senate_info_runtime_api::SenateInfoRuntimeApiV1;
+
+    sp_io::TestExternalities::new(RuntimeGenesisConfig::default().build_storage().unwrap())
+        .execute_with(|| {
+            for seed in [1u8, 2, 3] {
+                SenateMembers::add_member(
+                    RuntimeOrigin::root(),
+                    Address::Id(AccountId::from([seed; 32])),
+                )
+                .unwrap();
+            }
+            SenateMembers::remove_member(
+                RuntimeOrigin::root(),
+                Address::Id(AccountId::from([2u8; 32])),
+            )
+            .unwrap();
+
+            let members = <Runtime as SenateInfoRuntimeApiV1<Block>>::get_senate_members();
+            assert_eq!(members, SenateMembers::members().into_inner());
+            assert_eq!(
+                members,
+                vec![AccountId::from([1u8; 32]), AccountId::from([3u8; 32])]
+            );
+        });
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_simulate_batch_reports_per_call_results_without_committing() {
+    use subtensor_custom_rpc_runtime_api::runtime_decl_for_batch_runtime_api::BatchRuntimeApiV1;
+
+    sp_io::TestExternalities::new(RuntimeGenesisConfig::default().build_storage().unwrap())
+        .execute_with(|| {
+            let alice = AccountId::from([1u8; 32]);
+            let bob = AccountId::from([2u8; 32]);
+            Balances::force_set_balance(RuntimeOrigin::root(), alice.clone().into(), 1_000_000_000)
+                .unwrap();
+
+            let calls = vec![
+                RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+                    dest: bob.clone().into(),
+                    value: 500_000_000,
+                }),
+                // Only half of the balance is left after the first transfer.
+                RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+                    dest: bob.clone().into(),
+                    value: 600_000_000,
+                }),
+                RuntimeCall::System(frame_system::Call::remark {
+                    remark: b"batch".to_vec(),
+                }),
+            ];
+
+            let results = <Runtime as BatchRuntimeApiV1<Block, RuntimeCall>>::simulate_batch(
+                alice.clone(),
+                calls,
+            );
+            assert!(matches!(results.as_slice(), [Ok(()), Err(_), Ok(())]));
+
+            // Nothing has been committed.
+            assert_eq!(Balances::free_balance(&alice), 1_000_000_000);
+            assert_eq!(Balances::free_balance(&bob), 0);
+        });
+}
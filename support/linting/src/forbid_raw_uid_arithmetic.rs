@@ -0,0 +1,123 @@
+use super::*;
+use syn::{
+    BinOp, Expr, ExprBinary, ExprField, ExprPath, File, Member, spanned::Spanned, visit::Visit,
+};
+
+/// Flags raw `+`, `-` and `*` on uid and netuid counters, which are `u16` and wrap around at
+/// 65535. Types aren't available to syn, so operands are matched by name: any identifier or
+/// field with a `_`-separated segment that is `uid` or `netuid`, such as `neuron_uid`, but not
+/// `liquid`.
+pub struct ForbidRawUidArithmetic;
+
+impl Lint for ForbidRawUidArithmetic {
+    fn lint(source: &File) -> Result {
+        let mut visitor = RawUidArithmeticVisitor::default();
+        visitor.visit_file(source);
+
+        if visitor.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(visitor.errors)
+        }
+    }
+}
+
+#[derive(Default)]
+struct RawUidArithmeticVisitor {
+    errors: Vec<syn::Error>,
+}
+
+impl<'ast> Visit<'ast> for RawUidArithmeticVisitor {
+    fn visit_expr_binary(&mut self, node: &'ast ExprBinary) {
+        if let Some(saturating) = saturating_equivalent(&node.op) {
+            if is_uid_operand(&node.left) || is_uid_operand(&node.right) {
+                let msg = format!(
+                    "Raw arithmetic on uids and netuids can wrap around at u16::MAX. Use `{}` instead.",
+                    saturating
+                );
+                self.errors.push(syn::Error::new(node.op.span(), msg));
+            }
+        }
+
+        syn::visit::visit_expr_binary(self, node);
+    }
+}
+
+fn saturating_equivalent(op: &BinOp) -> Option<&'static str> {
+    match op {
+        BinOp::Add(_) | BinOp::AddAssign(_) => Some("saturating_add"),
+        BinOp::Sub(_) | BinOp::SubAssign(_) => Some("saturating_sub"),
+        BinOp::Mul(_) | BinOp::MulAssign(_) => Some("saturating_mul"),
+        _ => None,
+    }
+}
+
+fn is_uid_operand(expr: &Expr) -> bool {
+    match expr {
+        Expr::Path(ExprPath { path, .. }) => path
+            .get_ident()
+            .is_some_and(|ident| is_uid_name(&ident.to_string())),
+        Expr::Field(ExprField {
+            member: Member::Named(ident),
+            ..
+        }) => is_uid_name(&ident.to_string()),
+        Expr::Paren(paren) => is_uid_operand(&paren.expr),
+        Expr::Reference(reference) => is_uid_operand(&reference.expr),
+        Expr::Unary(unary) => is_uid_operand(&unary.expr),
+        _ => false,
+    }
+}
+
+fn is_uid_name(name: &str) -> bool {
+    name.to_lowercase()
+        .split('_')
+        .any(|segment| matches!(segment, "uid" | "uids" | "netuid" | "netuids"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    fn lint(input: proc_macro2::TokenStream) -> Result {
+        let mut visitor = RawUidArithmeticVisitor::default();
+        let expr: syn::Expr = syn::parse2(input).expect("should be a valid expression");
+        visitor.visit_expr(&expr);
+
+        if visitor.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(visitor.errors)
+        }
+    }
+
+    #[test]
+    fn test_raw_uid_arithmetic_forbidden() {
+        let input = quote! { next_uid + 1 };
+        assert!(lint(input).is_err());
+        let input = quote! { netuid - 1 };
+        assert!(lint(input).is_err());
+        let input = quote! { 2 * self.netuid };
+        assert!(lint(input).is_err());
+        let input = quote! { *neuron_uid += 1 };
+        assert!(lint(input).is_err());
+        let input = quote! { Self::get_subnetwork_n(netuid + 1) };
+        assert!(lint(input).is_err());
+    }
+
+    #[test]
+    fn test_saturating_uid_arithmetic_allowed() {
+        let input = quote! { next_uid.saturating_add(1) };
+        assert!(lint(input).is_ok());
+        let input = quote! { netuid.saturating_sub(1) };
+        assert!(lint(input).is_ok());
+        let input = quote! { netuid == other_netuid };
+        assert!(lint(input).is_ok());
+        let input = quote! { stake + alpha };
+        assert!(lint(input).is_ok());
+        let input = quote! { liquid + 1 };
+        assert!(lint(input).is_ok());
+        let input = quote! { self.fluid_amount - 1 };
+        assert!(lint(input).is_ok());
+    }
+}
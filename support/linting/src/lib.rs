@@ -3,12 +3,14 @@ pub use lint::*;
 
 mod forbid_as_primitive;
 mod forbid_keys_remove;
+mod forbid_raw_uid_arithmetic;
 mod forbid_saturating_math;
 mod pallet_index;
 mod require_freeze_struct;
 
 pub use forbid_as_primitive::ForbidAsPrimitiveConversion;
 pub use forbid_keys_remove::ForbidKeysRemoveCall;
+pub use forbid_raw_uid_arithmetic::ForbidRawUidArithmetic;
 pub use forbid_saturating_math::ForbidSaturatingMath;
 pub use pallet_index::RequireExplicitPalletIndex;
 pub use require_freeze_struct::RequireFreezeStruct;